@@ -81,9 +81,6 @@ export default class WorkingGroupsCreateOpening extends WorkingGroupsCommandBase
       wgOpeningJson.activateAt,
       createType('OpeningPolicyCommitment', {
         max_review_period_length: wgOpeningJson.maxReviewPeriodLength,
-        application_rationing_policy: wgOpeningJson.maxActiveApplicants
-          ? { max_active_applicants: wgOpeningJson.maxActiveApplicants }
-          : null,
         application_staking_policy: wgOpeningJson.applicationStake
           ? {
               amount: wgOpeningJson.applicationStake.value,
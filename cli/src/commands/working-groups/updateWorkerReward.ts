@@ -58,6 +58,7 @@ export default class WorkingGroupsUpdateWorkerReward extends WorkingGroupsComman
     await this.sendAndFollowNamedTx(account, apiModuleByGroup[this.group], 'updateRewardAmount', [
       workerId,
       newRewardValue,
+      null,
     ])
 
     const updatedGroupMember = await this.getApi().groupMember(this.group, workerId)
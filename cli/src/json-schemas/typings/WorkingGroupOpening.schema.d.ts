@@ -21,10 +21,6 @@ export interface WorkingGroupOpening {
     | {
         CurrentBlock: null
       }
-  /**
-   * Max. number of active applicants
-   */
-  maxActiveApplicants?: number
   /**
    * Max. review period length in blocks
    */
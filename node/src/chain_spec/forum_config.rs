@@ -1,24 +1,48 @@
-use codec::Decode;
+use codec::{Decode, Encode};
 use node_runtime::{
     common::constraints::InputValidationLengthConstraint,
     forum::{Category, CategoryId, Post, Thread},
     AccountId, BlockNumber, ForumConfig, Moment, PostId, ThreadId,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{fs, path::Path};
 
+/// Forum category as stored in the runtime `CategoryById` map
+pub type ForumCategory = Category<BlockNumber, Moment, AccountId>;
+
+/// Forum thread as stored in the runtime `ThreadById` map
+pub type ForumThread = Thread<BlockNumber, Moment, AccountId, ThreadId>;
+
+/// Forum post as stored in the runtime `PostById` map
+pub type ForumPost = Post<BlockNumber, Moment, AccountId, ThreadId, PostId>;
+
 fn new_validation(min: u16, max_min_diff: u16) -> InputValidationLengthConstraint {
     InputValidationLengthConstraint { min, max_min_diff }
 }
 
 #[derive(Decode)]
 struct ForumData {
-    categories: Vec<Category<BlockNumber, Moment, AccountId>>,
-    posts: Vec<Post<BlockNumber, Moment, AccountId, ThreadId, PostId>>,
-    threads: Vec<Thread<BlockNumber, Moment, AccountId, ThreadId>>,
+    categories: Vec<ForumCategory>,
+    posts: Vec<ForumPost>,
+    threads: Vec<ForumThread>,
+}
+
+impl ForumData {
+    fn encode(&self) -> EncodedForumData {
+        EncodedForumData {
+            categories: self.categories.iter().map(encode_hex).collect(),
+            posts: self.posts.iter().map(encode_hex).collect(),
+            threads: self.threads.iter().map(encode_hex).collect(),
+        }
+    }
 }
 
-#[derive(Deserialize)]
+// Same '0x' prefixed hex format the decoder below expects.
+fn encode_hex<T: Encode>(item: &T) -> String {
+    format!("0x{}", hex::encode(item.encode()))
+}
+
+#[derive(Serialize, Deserialize)]
 struct EncodedForumData {
     /// hex encoded categories
     categories: Vec<String>,
@@ -75,6 +99,27 @@ pub fn from_json(forum_sudo: AccountId, data_file: &Path) -> ForumConfig {
     create(forum_sudo, forum_data)
 }
 
+/// Serializes forum categories, threads and posts into the json format
+/// consumed by `from_json`. Items are ordered by their ids, as the
+/// genesis config requires ids to be contiguous and starting from 1.
+pub fn to_json(
+    mut categories: Vec<ForumCategory>,
+    mut threads: Vec<ForumThread>,
+    mut posts: Vec<ForumPost>,
+) -> String {
+    categories.sort_by_key(|category| category.id);
+    threads.sort_by_key(|thread| thread.id);
+    posts.sort_by_key(|post| post.id);
+
+    let forum_data = ForumData {
+        categories,
+        posts,
+        threads,
+    };
+
+    serde_json::to_string_pretty(&forum_data.encode()).expect("failed serializing forum data")
+}
+
 /// Generates a basic empty `ForumConfig` geneis config
 pub fn empty(forum_sudo: AccountId) -> ForumConfig {
     let forum_data = EncodedForumData {
@@ -147,3 +192,54 @@ fn create(forum_sudo: AccountId, forum_data: EncodedForumData) -> ForumConfig {
         post_moderation_rationale_constraint: new_validation(10, 290),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn category(id: CategoryId) -> ForumCategory {
+        let mut category = ForumCategory::default();
+        category.id = id;
+        category
+    }
+
+    fn thread(id: ThreadId) -> ForumThread {
+        let mut thread = ForumThread::default();
+        thread.id = id;
+        thread
+    }
+
+    fn post(id: PostId) -> ForumPost {
+        let mut post = ForumPost::default();
+        post.id = id;
+        post
+    }
+
+    #[test]
+    fn exported_forum_data_can_be_imported() {
+        let categories = vec![category(2), category(1)];
+        let threads = vec![thread(1), thread(3), thread(2)];
+        let posts = vec![post(1)];
+
+        let json = to_json(categories, threads, posts);
+
+        let mut data_file = tempfile::NamedTempFile::new().unwrap();
+        data_file.write_all(json.as_bytes()).unwrap();
+
+        let config = from_json(AccountId::default(), data_file.path());
+
+        assert_eq!(
+            config.category_by_id,
+            vec![(1, category(1)), (2, category(2))]
+        );
+        assert_eq!(
+            config.thread_by_id,
+            vec![(1, thread(1)), (2, thread(2)), (3, thread(3))]
+        );
+        assert_eq!(config.post_by_id, vec![(1, post(1))]);
+        assert_eq!(config.next_category_id, 3);
+        assert_eq!(config.next_thread_id, 4);
+        assert_eq!(config.next_post_id, 2);
+    }
+}
@@ -34,10 +34,10 @@ use node_runtime::{
     membership, wasm_binary_unwrap, AuthorityDiscoveryConfig, BabeConfig, Balance, BalancesConfig,
     ContentConfig, ContentDirectoryWorkingGroupConfig, CouncilConfig, CouncilElectionConfig,
     DataDirectoryConfig, DataObjectStorageRegistryConfig, DataObjectTypeRegistryConfig,
-    ElectionParameters, ForumConfig, GatewayWorkingGroupConfig, GrandpaConfig, ImOnlineConfig,
-    MembersConfig, Moment, OperationsWorkingGroupConfig, ProposalsCodexConfig, SessionConfig,
-    SessionKeys, Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig, SudoConfig,
-    SystemConfig, DAYS,
+    ElectionParameters, ElectionTallyMode, ForumConfig, GatewayWorkingGroupConfig, GrandpaConfig,
+    ImOnlineConfig, MembersConfig, Moment, OperationsWorkingGroupConfig, ProposalsCodexConfig,
+    SessionConfig, SessionKeys, Signature, StakerStatus, StakingConfig, StorageWorkingGroupConfig,
+    SudoConfig, SystemConfig, DAYS,
 };
 
 // Exported to be used by chain-spec-builder
@@ -296,6 +296,7 @@ pub fn testnet_genesis(
                 min_council_stake: 1_000,
                 new_term_duration: 1 * DAYS,
                 min_voting_stake: 100,
+                tally_mode: ElectionTallyMode::TopStaked,
             },
         }),
         membership: Some(MembersConfig {
@@ -402,6 +403,32 @@ pub fn testnet_genesis(
                 .terminate_working_group_leader_role_proposal_voting_period,
             terminate_working_group_leader_role_proposal_grace_period: cpcp
                 .terminate_working_group_leader_role_proposal_grace_period,
+            set_membership_invitation_budget_proposal_voting_period: cpcp
+                .set_membership_invitation_budget_proposal_voting_period,
+            set_membership_invitation_budget_proposal_grace_period: cpcp
+                .set_membership_invitation_budget_proposal_grace_period,
+            set_referral_cut_proposal_voting_period: cpcp.set_referral_cut_proposal_voting_period,
+            set_referral_cut_proposal_grace_period: cpcp.set_referral_cut_proposal_grace_period,
+            vote_of_no_confidence_proposal_voting_period: cpcp
+                .vote_of_no_confidence_proposal_voting_period,
+            vote_of_no_confidence_proposal_grace_period: cpcp
+                .vote_of_no_confidence_proposal_grace_period,
+            set_global_storage_voucher_limits_proposal_voting_period: cpcp
+                .set_global_storage_voucher_limits_proposal_voting_period,
+            set_global_storage_voucher_limits_proposal_grace_period: cpcp
+                .set_global_storage_voucher_limits_proposal_grace_period,
+            set_storage_voucher_limit_upper_bounds_proposal_voting_period: cpcp
+                .set_storage_voucher_limit_upper_bounds_proposal_voting_period,
+            set_storage_voucher_limit_upper_bounds_proposal_grace_period: cpcp
+                .set_storage_voucher_limit_upper_bounds_proposal_grace_period,
+            set_data_object_type_status_proposal_voting_period: cpcp
+                .set_data_object_type_status_proposal_voting_period,
+            set_data_object_type_status_proposal_grace_period: cpcp
+                .set_data_object_type_status_proposal_grace_period,
+            set_max_number_of_curators_per_group_proposal_voting_period: cpcp
+                .set_max_number_of_curators_per_group_proposal_voting_period,
+            set_max_number_of_curators_per_group_proposal_grace_period: cpcp
+                .set_max_number_of_curators_per_group_proposal_grace_period,
         }),
     }
 }
@@ -16,7 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use sc_cli::{KeySubcommand, RunCmd, SignCmd, VanityCmd, VerifyCmd};
+use sc_cli::{
+    BlockNumberOrHash, KeySubcommand, PruningParams, RunCmd, SharedParams, SignCmd, VanityCmd,
+    VerifyCmd,
+};
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 /// An overarching CLI command definition.
@@ -71,6 +75,9 @@ pub enum Subcommand {
     /// Export the state of a given block into a chain spec.
     ExportState(sc_cli::ExportStateCmd),
 
+    /// Export the forum state of a given block into a forum genesis data file.
+    ExportForum(ExportForumCmd),
+
     /// Import blocks.
     ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -80,3 +87,24 @@ pub enum Subcommand {
     /// Revert the chain to a previous state.
     Revert(sc_cli::RevertCmd),
 }
+
+/// The `export-forum` command used to dump the forum state of a synced
+/// database into the json format consumed by `forum_config::from_json`.
+#[derive(Debug, StructOpt)]
+pub struct ExportForumCmd {
+    /// Block hash or number to export the forum state at. Defaults to the best block.
+    #[structopt(long = "at", value_name = "HASH or NUMBER")]
+    pub at: Option<BlockNumberOrHash>,
+
+    /// Output file name or stdout if unspecified.
+    #[structopt(parse(from_os_str))]
+    pub output: Option<PathBuf>,
+
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[structopt(flatten)]
+    pub pruning_params: PruningParams,
+}
@@ -0,0 +1,83 @@
+// Copyright 2019 Joystream Contributors
+// This file is part of Joystream node.
+
+// Joystream node is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Joystream node is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Joystream node.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::chain_spec::forum_config;
+use crate::cli::ExportForumCmd;
+use codec::Decode;
+use node_runtime::opaque::Block;
+use sc_cli::{CliConfiguration, PruningParams, Result, SharedParams};
+use sc_client_api::{StorageProvider, UsageProvider};
+use sp_core::{hashing::twox_128, storage::StorageKey};
+use sp_runtime::generic::BlockId;
+use std::{fs, io::Write, sync::Arc};
+
+impl ExportForumCmd {
+    /// Run the export-forum command
+    pub fn run<BA, C>(&self, client: Arc<C>) -> Result<()>
+    where
+        BA: sc_client_api::Backend<Block>,
+        C: UsageProvider<Block> + StorageProvider<Block, BA>,
+    {
+        let block_id = match &self.at {
+            Some(at) => at.parse()?,
+            None => BlockId::Hash(client.usage_info().chain.best_hash),
+        };
+
+        let categories = read_map(client.as_ref(), &block_id, b"CategoryById")?;
+        let threads = read_map(client.as_ref(), &block_id, b"ThreadById")?;
+        let posts = read_map(client.as_ref(), &block_id, b"PostById")?;
+
+        let json = forum_config::to_json(categories, threads, posts);
+
+        match &self.output {
+            Some(path) => fs::write(path, json)?,
+            None => std::io::stdout().write_all(json.as_bytes())?,
+        }
+
+        Ok(())
+    }
+}
+
+// Reads and decodes all values of a storage map declared in the forum module.
+fn read_map<BA, C, V>(client: &C, block_id: &BlockId<Block>, storage_name: &[u8]) -> Result<Vec<V>>
+where
+    BA: sc_client_api::Backend<Block>,
+    C: StorageProvider<Block, BA>,
+    V: Decode,
+{
+    let mut prefix = twox_128(b"Forum").to_vec();
+    prefix.extend_from_slice(&twox_128(storage_name));
+
+    client
+        .storage_pairs(block_id, &StorageKey(prefix))
+        .map_err(|err| format!("Failed to read forum storage: {:?}", err))?
+        .into_iter()
+        .map(|(_, value)| {
+            V::decode(&mut value.0.as_slice())
+                .map_err(|err| format!("Failed to decode forum storage value: {:?}", err).into())
+        })
+        .collect()
+}
+
+impl CliConfiguration for ExportForumCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn pruning_params(&self) -> Option<&PruningParams> {
+        Some(&self.pruning_params)
+    }
+}
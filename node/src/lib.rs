@@ -3,5 +3,6 @@ pub mod cli;
 #[macro_use]
 pub mod service;
 pub mod command;
+pub mod forum_export;
 pub mod node_executor;
 pub mod node_rpc;
@@ -12,10 +12,10 @@ import TxButton from '@polkadot/joy-utils/react/components/TxButton';
 import InputStake from '@polkadot/joy-utils/react/components/InputStake';
 import { ElectionStake } from '@joystream/types/council';
 import { calcTotalStake, ZERO } from '@polkadot/joy-utils/functions/misc';
-import { MyAddressProps } from '@polkadot/joy-utils/react/hocs/accounts';
+import { MyAccountProps } from '@polkadot/joy-utils/react/hocs/accounts';
 import { withOnlyMembers } from '@polkadot/joy-utils/react/hocs/guards';
 
-type Props = ApiProps & I18nProps & MyAddressProps & {
+type Props = ApiProps & I18nProps & MyAccountProps & {
   minStake?: Balance;
   alreadyStaked?: ElectionStake;
 };
@@ -34,6 +34,7 @@ class ApplyForm extends React.PureComponent<Props, State> {
   state = DEFAULT_STATE;
 
   render () {
+    const { myMemberId } = this.props;
     const { stake, isStakeValid } = this.state;
     const hasAlreadyStakedEnough = this.alreadyStaked().gte(this.minStake());
     const minStake = hasAlreadyStakedEnough ? ZERO : this.minStake();
@@ -51,9 +52,9 @@ class ApplyForm extends React.PureComponent<Props, State> {
         <div style={{ marginTop: '.5rem' }}>
           <Labelled>
             <TxButton
-              isDisabled={!isStakeValid}
+              isDisabled={!isStakeValid || !myMemberId}
               label={buttonLabel}
-              params={[stake]}
+              params={[myMemberId, stake]}
               tx='councilElection.apply'
             />
           </Labelled>
@@ -88,6 +89,6 @@ export default withMulti(
     ['query.councilElection.minCouncilStake',
       { propName: 'minStake' }],
     ['query.councilElection.applicantStakes',
-      { paramName: 'myAddress', propName: 'alreadyStaked' }]
+      { paramName: 'myMemberId', propName: 'alreadyStaked' }]
   )
 );
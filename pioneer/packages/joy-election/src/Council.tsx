@@ -39,7 +39,7 @@ class Council extends React.PureComponent<Props, State> {
             <Table.Row key={index}>
               <Table.Cell>{index + 1}</Table.Cell>
               <Table.Cell>
-                <CouncilCandidate accountId={seat.member} />
+                <CouncilCandidate accountId={seat.account} />
               </Table.Cell>
               <Table.Cell>{formatBalance(seat.stake)}</Table.Cell>
               <Table.Cell>{formatBalance(calcBackersStake(seat.backers))}</Table.Cell>
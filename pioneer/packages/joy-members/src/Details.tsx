@@ -55,8 +55,8 @@ class Component extends React.PureComponent<Props> {
     const hasAvatar = avatar_uri && nonEmptyStr(avatar_uri.toString());
     const isMyProfile = myAddress && (myAddress === root_account.toString() || myAddress === controller_account.toString());
     const isCouncilor: boolean = (
-      (activeCouncil.find((x) => root_account.eq(x.member)) !== undefined) ||
-      (activeCouncil.find((x) => controller_account.eq(x.member)) !== undefined)
+      (activeCouncil.find((x) => root_account.eq(x.account)) !== undefined) ||
+      (activeCouncil.find((x) => controller_account.eq(x.account)) !== undefined)
     );
 
     return (
@@ -123,7 +123,7 @@ const InnerForm = (props: FormProps) => {
       return [memberId, ...userInfo];
     } else {
       // register as new member
-      return [paidTermId, ...userInfo];
+      return [paidTermId, ...userInfo, null];
     }
   };
 
@@ -41,7 +41,9 @@ function VoteButton ({ voteKind, proposalId, memberId, onSuccess }: VoteButtonPr
       params={[
         memberId,
         proposalId,
-        voteKind
+        voteKind,
+        // rationale - Pioneer votes don't carry one yet
+        null
       ]}
       tx={ 'proposalsEngine.vote' }
       onClick={ (sendTx) => sendTx() }
@@ -10,7 +10,7 @@ import { GenericWorkingGroupProposalForm,
   defaultValues as wgFromDefaultValues } from './GenericWorkingGroupProposalForm';
 import { FormField, InputFormField, TextareaFormField } from './FormFields';
 import { withFormContainer } from './FormContainer';
-import { ActivateOpeningAtKey, ActivateOpeningAtDef, StakingAmountLimitModeKeys, IApplicationRationingPolicy, IStakingPolicy } from '@joystream/types/hiring';
+import { ActivateOpeningAtKey, ActivateOpeningAtDef, StakingAmountLimitModeKeys, IStakingPolicy } from '@joystream/types/hiring';
 import { GenericJoyStreamRoleSchema } from '@joystream/types/hiring/schemas/role.schema.typings';
 import { Dropdown, Grid, Message, Checkbox } from 'semantic-ui-react';
 import { formatBalance } from '@polkadot/util';
@@ -27,8 +27,6 @@ export type FormValues = WGFormValues & {
   activateAt: ActivateOpeningAtKey;
   activateAtBlock: string;
   maxReviewPeriodLength: string;
-  applicationsLimited: boolean;
-  maxApplications: string;
   applicationStakeRequired: boolean;
   applicationStakeMode: StakingAmountLimitModeKeys;
   applicationStakeValue: string;
@@ -45,8 +43,6 @@ const defaultValues: FormValues = {
   activateAt: 'CurrentBlock',
   activateAtBlock: '',
   maxReviewPeriodLength: (14400 * 30).toString(), // 30 days
-  applicationsLimited: false,
-  maxApplications: '',
   applicationStakeRequired: false,
   applicationStakeMode: StakingAmountLimitModeKeys.Exact,
   applicationStakeValue: '',
@@ -157,14 +153,6 @@ const valuesToAddOpeningParams = (values: FormValues): SimplifiedTypeInterface<I
     commitment.exit_role_stake_unstaking_period = parseInt(values.leaveRoleUnstakingPeriod);
   }
 
-  if (values.applicationsLimited) {
-    const rationingPolicy: SimplifiedTypeInterface<IApplicationRationingPolicy> = {
-      max_active_applicants: parseInt(values.maxApplications)
-    };
-
-    commitment.application_rationing_policy = rationingPolicy;
-  }
-
   if (values.applicationStakeRequired) {
     const applicationStakingPolicy: SimplifiedTypeInterface<IStakingPolicy> = {
       amount: parseInt(values.applicationStakeValue),
@@ -259,28 +247,6 @@ const AddWorkingGroupOpeningForm: React.FunctionComponent<FormInnerProps> = (pro
           </Grid.Column>
         </Grid.Row>
       </Grid>
-      <Grid columns='4' doubling stackable verticalAlign='bottom'>
-        <Grid.Row>
-          <Grid.Column>
-            <FormField label='Applications limit'>
-              <Checkbox
-                toggle
-                onChange={(e, data) => { setFieldValue('applicationsLimited', data.checked); }}
-                label='Limit applications'
-                checked={values.applicationsLimited}/>
-            </FormField>
-            { values.applicationsLimited && (
-              <InputFormField
-                onChange={handleChange}
-                name='maxApplications'
-                error={errorLabelsProps.maxApplications}
-                value={values.maxApplications}
-                placeholder={'Max. number of applications'}
-              />
-            ) }
-          </Grid.Column>
-        </Grid.Row>
-      </Grid>
       <Grid columns='2' stackable style={{ marginBottom: 0 }}>
         <Grid.Row>
           <Grid.Column>
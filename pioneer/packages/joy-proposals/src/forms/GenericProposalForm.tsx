@@ -224,7 +224,9 @@ export const GenericProposalForm: React.FunctionComponent<GenericFormInnerProps>
                 requiredStake,
                 // submitParams is any[], but it's not much of an issue (params can vary a lot)
                 // eslint-disable-next-line @typescript-eslint/no-unsafe-assignment
-                ...(submitParams || [])
+                ...(submitParams || []),
+                // exactly_execute_at - the proposal is executed as soon as it is approved
+                null
               ]}
               tx={`proposalsCodex.${txMethod}`}
               txFailedCb={onTxFailed}
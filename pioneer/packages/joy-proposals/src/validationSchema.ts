@@ -63,8 +63,6 @@ const MIN_EXACT_BLOCK_MINUS_CURRENT = 14400 * 5; // ~5 days
 const MAX_EXACT_BLOCK_MINUS_CURRENT = 14400 * 60; // 2 months
 const MAX_REVIEW_PERIOD_LENGTH_MIN = 14400 * 5; // ~5 days
 const MAX_REVIEW_PERIOD_LENGTH_MAX = 14400 * 60; // 2 months
-const MAX_APPLICATIONS_MIN = 1;
-const MAX_APPLICATIONS_MAX = 1000;
 const APPLICATION_STAKE_VALUE_MIN = 1;
 const APPLICATION_STAKE_VALUE_MAX = 1000000;
 const ROLE_STAKE_VALUE_MIN = 1;
@@ -313,12 +311,6 @@ const Validation: ValidationType = {
         )
       }),
     maxReviewPeriodLength: minMaxInt(MAX_REVIEW_PERIOD_LENGTH_MIN, MAX_REVIEW_PERIOD_LENGTH_MAX, 'Max. review period length'),
-    applicationsLimited: Yup.boolean(),
-    maxApplications: Yup.number()
-      .when('applicationsLimited', {
-        is: true,
-        then: minMaxInt(MAX_APPLICATIONS_MIN, MAX_APPLICATIONS_MAX, 'Max. number of applications')
-      }),
     applicationStakeRequired: Yup.boolean(),
     applicationStakeMode: Yup.string(),
     applicationStakeValue: Yup.number()
@@ -2,7 +2,7 @@ import { ParsedMember } from '../types/members';
 import BaseTransport from './base';
 import { Seats, IElectionParameters } from '@joystream/types/council';
 import { MemberId, Membership } from '@joystream/types/members';
-import { u32, Vec } from '@polkadot/types/';
+import { u32 } from '@polkadot/types/';
 import { Balance, BlockNumber } from '@polkadot/types/interfaces';
 import { ApiPromise } from '@polkadot/api';
 import MembersTransport from './members';
@@ -35,12 +35,11 @@ export default class CouncilTransport extends BaseTransport {
 
     return Promise.all(
       council.map(async (seat) => {
-        const memberIds = (await this.members.memberIdsByControllerAccountId(seat.member)) as Vec<MemberId>;
-        const member = (await this.membersT.expectedMembership(memberIds[0])).toJSON() as ParsedMember;
+        const member = (await this.membersT.expectedMembership(seat.member)).toJSON() as ParsedMember;
 
         return {
           ...member,
-          memberId: memberIds[0]
+          memberId: seat.member
         };
       })
     );
@@ -53,10 +52,7 @@ export default class CouncilTransport extends BaseTransport {
     return (await this.membersT.allMembers())
       .filter(([memberId, member]) => (
         // Filter out council members
-        !activeCouncil.some((seat) =>
-          seat.member.eq(member.controller_account) ||
-            seat.member.eq(member.root_account)
-        )
+        !activeCouncil.some((seat) => seat.member.eq(memberId))
       ))
       .map(([memberId, member]) => (
         { id: memberId.toNumber(), profile: member }
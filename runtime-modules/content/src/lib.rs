@@ -19,7 +19,7 @@ use codec::{Decode, Encode};
 use frame_support::{
     decl_event, decl_module, decl_storage, dispatch::DispatchResult, ensure, traits::Get, Parameter,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_root, ensure_signed};
 #[cfg(feature = "std")]
 pub use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
@@ -110,7 +110,7 @@ pub trait Trait:
     /// Type of identifier for Channel transfer requests
     type ChannelOwnershipTransferRequestId: NumericIdentifier;
 
-    /// The maximum number of curators per group constraint
+    /// The default maximum number of curators per group constraint
     type MaxNumberOfCuratorsPerGroup: Get<MaxNumber>;
 
     // Type that handles asset uploads to storage frame_system
@@ -495,6 +495,9 @@ decl_storage! {
 
         /// Map, representing  CuratorGroupId -> CuratorGroup relation
         pub CuratorGroupById get(fn curator_group_by_id): map hasher(blake2_128_concat) T::CuratorGroupId => CuratorGroup<T>;
+
+        /// The maximum number of curators per group constraint. Can be changed by the root.
+        pub MaxCuratorsPerGroup get(fn max_number_of_curators_per_group): MaxNumber = T::MaxNumberOfCuratorsPerGroup::get();
     }
 }
 
@@ -506,7 +509,7 @@ decl_module! {
         /// Initializing events
         fn deposit_event() = default;
 
-        /// Exports const -  max number of curators per group
+        /// Exports const -  default max number of curators per group
         const MaxNumberOfCuratorsPerGroup: MaxNumber = T::MaxNumberOfCuratorsPerGroup::get();
 
         // ======
@@ -631,6 +634,24 @@ decl_module! {
             Self::deposit_event(RawEvent::CuratorRemoved(curator_group_id, curator_id));
         }
 
+        /// Set the max number of curators per group. Requires root origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_max_number_of_curators_per_group(
+            origin,
+            max_number_of_curators_per_group: MaxNumber,
+        ) {
+            ensure_root(origin)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            MaxCuratorsPerGroup::put(max_number_of_curators_per_group);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::MaxNumberOfCuratorsPerGroupSet(max_number_of_curators_per_group));
+        }
+
         // TODO: Add Option<reward_account> to ChannelCreationParameters ?
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn create_channel(
@@ -1409,6 +1430,7 @@ decl_event!(
         CuratorGroupStatusSet(CuratorGroupId, bool /* active status */),
         CuratorAdded(CuratorGroupId, CuratorId),
         CuratorRemoved(CuratorGroupId, CuratorId),
+        MaxNumberOfCuratorsPerGroupSet(MaxNumber),
 
         // Channels
         ChannelCreated(
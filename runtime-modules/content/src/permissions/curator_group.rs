@@ -50,7 +50,7 @@ impl<T: Trait> CuratorGroup<T> {
     /// Ensure `MaxNumberOfCuratorsPerGroup` constraint satisfied
     pub fn ensure_max_number_of_curators_limit_not_reached(&self) -> DispatchResult {
         ensure!(
-            self.curators.len() < T::MaxNumberOfCuratorsPerGroup::get() as usize,
+            self.curators.len() < Module::<T>::max_number_of_curators_per_group() as usize,
             Error::<T>::CuratorsPerGroupLimitReached
         );
         Ok(())
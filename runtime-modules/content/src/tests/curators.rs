@@ -3,6 +3,7 @@
 use super::mock::*;
 use crate::*;
 use frame_support::{assert_err, assert_ok};
+use sp_runtime::DispatchError;
 
 pub fn add_curator_to_new_group(curator_id: CuratorId) -> CuratorGroupId {
     let curator_group_id = Content::next_curator_group_id();
@@ -129,3 +130,41 @@ fn curator_group_management() {
         );
     })
 }
+
+#[test]
+fn max_number_of_curators_per_group_can_be_set_by_root() {
+    with_default_mock_builder(|| {
+        // Run to block one to see emitted events
+        run_to_block(1);
+
+        // Only root can set the limit
+        assert_err!(
+            Content::set_max_number_of_curators_per_group(Origin::signed(LEAD_ORIGIN), 1),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Content::set_max_number_of_curators_per_group(
+            Origin::root(),
+            1
+        ));
+
+        assert_eq!(
+            System::events().last().unwrap().event,
+            MetaEvent::content(RawEvent::MaxNumberOfCuratorsPerGroupSet(1))
+        );
+
+        assert_eq!(Content::max_number_of_curators_per_group(), 1);
+
+        let curator_group_id = add_curator_to_new_group(FIRST_CURATOR_ID);
+
+        // Cannot add more curators than the new limit allows
+        assert_err!(
+            Content::add_curator_to_group(
+                Origin::signed(LEAD_ORIGIN),
+                curator_group_id,
+                SECOND_CURATOR_ID
+            ),
+            Error::<Test>::CuratorsPerGroupLimitReached
+        );
+    })
+}
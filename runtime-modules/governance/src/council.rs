@@ -1,10 +1,17 @@
+use codec::{Decode, Encode};
+use frame_support::storage::IterableStorageMap;
+use frame_support::traits::{
+    Currency, Get, LockIdentifier, LockableCurrency, ReservableCurrency, WithdrawReasons,
+};
 use frame_support::{debug, decl_event, decl_module, decl_storage, ensure};
 use frame_system::ensure_root;
-use sp_arithmetic::traits::{One, Zero};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::traits::{One, Saturating, Zero};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
-pub use super::election::{self, CouncilElected, Seat, Seats};
+pub use super::election::{self, Backer, CouncilElected, Seat, Seats};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
 
 // Hook For announcing that council term has ended
@@ -22,15 +29,77 @@ impl<X: CouncilTermEnded> CouncilTermEnded for (X,) {
     }
 }
 
-pub trait Trait: frame_system::Trait + recurringrewards::Trait + GovernanceCurrency {
+// Hook for announcing that a council seat was vacated during the term
+pub trait CouncilSeatVacated {
+    fn council_seat_vacated();
+}
+
+impl CouncilSeatVacated for () {
+    fn council_seat_vacated() {}
+}
+
+impl<X: CouncilSeatVacated> CouncilSeatVacated for (X,) {
+    fn council_seat_vacated() {
+        X::council_seat_vacated();
+    }
+}
+
+pub trait Trait:
+    frame_system::Trait + recurringrewards::Trait + GovernanceCurrency + membership::Trait
+{
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
     type CouncilTermEnded: CouncilTermEnded;
+
+    type CouncilSeatVacated: CouncilSeatVacated;
+
+    /// Balance lock id of the council candidacy stakes: the applicant and the seat stakes.
+    type CouncilLockId: Get<LockIdentifier>;
+}
+
+// Council seat of the account based elections. Used by the active council migration only.
+#[derive(Encode, Decode)]
+struct AccountSeat<AccountId, Balance> {
+    member: AccountId,
+    stake: Balance,
+    backers: Vec<Backer<AccountId, Balance>>,
+}
+
+pub type SpendingRecordId = u64;
+
+/// What the council mint spending was made for.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub enum SpendingPurpose {
+    /// Spending approved by the spending proposal with the given id
+    Proposal(u32),
+    /// Spending described by the text
+    Text(Vec<u8>),
+}
+
+impl Default for SpendingPurpose {
+    fn default() -> Self {
+        SpendingPurpose::Text(Vec::new())
+    }
+}
+
+/// Itemised spending from the council mint.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct SpendingRecord<AccountId, Balance, BlockNumber> {
+    /// Amount of tokens transferred
+    pub amount: Balance,
+    /// Account the tokens were transferred to
+    pub destination: AccountId,
+    /// Block at which the spending was made
+    pub spent_at: BlockNumber,
+    /// What the tokens were spent on
+    pub purpose: SpendingPurpose,
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as Council {
-        pub ActiveCouncil get(fn active_council) config(): Seats<T::AccountId, BalanceOf<T>>;
+        pub ActiveCouncil get(fn active_council) config(): Seats<T::MemberId, T::AccountId, BalanceOf<T>>;
 
         pub TermEndsAt get(fn term_ends_at) config() : T::BlockNumber = T::BlockNumber::from(1u32);
 
@@ -51,6 +120,30 @@ decl_storage! {
 
         /// How many blocks after the reward is created, the first payout will be made
         pub FirstPayoutAfterRewardCreated get(fn first_payout_after_reward_created): T::BlockNumber;
+
+        /// Budget granted to each new council term. Everything minted by the council mint, spendings
+        /// and councilor rewards alike, is charged against it. No budget is tracked when not set.
+        pub BudgetPerTerm get(fn budget_per_term): Option<minting::BalanceOf<T>>;
+
+        /// Budget left in the current term, as of the last charge.
+        pub RemainingBudget get(fn remaining_budget): Option<minting::BalanceOf<T>>;
+
+        /// Total minted by the council mint when the budget was last charged.
+        MintedWhenBudgetCharged get(fn minted_when_budget_charged): minting::BalanceOf<T>;
+
+        /// Whether councilor rewards are suspended until the budget is refilled by the next council.
+        pub RewardsSuspended get(fn rewards_suspended): bool;
+
+        /// Itemised spending from the council mint.
+        pub SpendingRecords get(fn spending_records): map hasher(blake2_128_concat)
+            SpendingRecordId => SpendingRecord<T::AccountId, minting::BalanceOf<T>, T::BlockNumber>;
+
+        /// Identifier of the next spending record.
+        pub NextSpendingRecordId get(fn next_spending_record_id): SpendingRecordId;
+
+        /// Total council candidacy stake locked on the account: the applicant and the seat stakes.
+        pub CandidacyLockedBalances get(fn candidacy_locked_balance): map hasher(blake2_128_concat)
+            T::AccountId => BalanceOf<T>;
     }
     add_extra_genesis {
         build(|_config: &GenesisConfig<T>| {
@@ -71,22 +164,40 @@ decl_storage! {
 
 // Event for this module.
 decl_event!(
-    pub enum Event<T> where <T as frame_system::Trait>::BlockNumber {
+    pub enum Event<T> where
+        <T as frame_system::Trait>::BlockNumber,
+        <T as frame_system::Trait>::AccountId,
+        <T as membership::Trait>::MemberId,
+        Balance = minting::BalanceOf<T>,
+    {
         CouncilTermEnded(BlockNumber),
         NewCouncilTermStarted(BlockNumber),
+        BudgetPerTermUpdated(Option<Balance>),
+        BudgetRefilled(Balance),
+        CouncilorRewardsSuspended(),
+        SpendingRecorded(SpendingRecordId, AccountId, Balance),
+        CouncilorRemoved(MemberId),
+        VacantSeatsFilled(Vec<MemberId>),
     }
 );
 
-impl<T: Trait> CouncilElected<Seats<T::AccountId, BalanceOf<T>>, T::BlockNumber> for Module<T> {
-    fn council_elected(seats: Seats<T::AccountId, BalanceOf<T>>, term: T::BlockNumber) {
+impl<T: Trait> CouncilElected<Seats<T::MemberId, T::AccountId, BalanceOf<T>>, T::BlockNumber>
+    for Module<T>
+{
+    fn council_elected(
+        seats: Seats<T::MemberId, T::AccountId, BalanceOf<T>>,
+        term: T::BlockNumber,
+    ) {
         <ActiveCouncil<T>>::put(seats.clone());
 
         let next_term_ends_at = <frame_system::Module<T>>::block_number() + term;
 
         <TermEndsAt<T>>::put(next_term_ends_at);
 
+        Self::refill_budget();
+
         for seat in seats.iter() {
-            Self::add_reward_relationship(&seat.member, Self::council_mint());
+            Self::add_reward_relationship(&seat.account, Self::council_mint());
         }
 
         Self::deposit_event(RawEvent::NewCouncilTermStarted(next_term_ends_at));
@@ -98,8 +209,211 @@ impl<T: Trait> Module<T> {
         <frame_system::Module<T>>::block_number() >= Self::term_ends_at()
     }
 
-    pub fn is_councilor(sender: &T::AccountId) -> bool {
-        Self::active_council().iter().any(|c| c.member == *sender)
+    pub fn is_councilor(member_id: &T::MemberId) -> bool {
+        Self::active_council()
+            .iter()
+            .any(|c| c.member == *member_id)
+    }
+
+    // Creates a zero staked seat held by the member controller account.
+    fn zero_staked_seat(
+        member_id: T::MemberId,
+    ) -> Result<Seat<T::MemberId, T::AccountId, BalanceOf<T>>, &'static str> {
+        let membership = <membership::Module<T>>::ensure_membership(member_id)?;
+
+        Ok(Seat {
+            member: member_id,
+            account: membership.controller_account,
+            stake: BalanceOf::<T>::zero(),
+            backers: vec![],
+        })
+    }
+
+    // Finds the member id of the account: controlled members take precedence over the rooted ones.
+    // The lowest member id is chosen when the account has several memberships.
+    fn member_id_by_account(account: &T::AccountId) -> Option<T::MemberId> {
+        <membership::Module<T>>::member_ids_by_controller_account_id(account)
+            .into_iter()
+            .min()
+            .or_else(|| {
+                <membership::Module<T>>::member_ids_by_root_account_id(account)
+                    .into_iter()
+                    .min()
+            })
+    }
+
+    /// Migrates the active council seats of the account based elections to member ids.
+    /// Seats of the accounts without a membership are dropped along with their rewards.
+    /// The reserved seat stakes are locked instead. Should be called on the migration to the new
+    /// runtime version.
+    pub fn migrate_active_council() {
+        let _ = <ActiveCouncil<T>>::translate::<Vec<AccountSeat<T::AccountId, BalanceOf<T>>>, _>(
+            |seats| {
+                seats.map(|seats| {
+                    seats
+                        .into_iter()
+                        .filter_map(|seat| {
+                            let member_id = Self::member_id_by_account(&seat.member);
+
+                            if member_id.is_none() {
+                                debug::warn!(
+                                    "Dropped council seat of an account without membership"
+                                );
+                                Self::remove_reward_relationship(&seat.member);
+                            }
+
+                            member_id.map(|member_id| {
+                                if !seat.stake.is_zero() {
+                                    <T as GovernanceCurrency>::Currency::unreserve(
+                                        &seat.member,
+                                        seat.stake,
+                                    );
+                                    Self::lock_candidacy_stake(&seat.member, seat.stake);
+                                }
+
+                                Seat {
+                                    member: member_id,
+                                    account: seat.member,
+                                    stake: seat.stake,
+                                    backers: seat.backers,
+                                }
+                            })
+                        })
+                        .collect()
+                })
+            },
+        );
+    }
+
+    /// Adds the seats elected in a by-election to the active council. The new councilors get
+    /// rewards for the rest of the term.
+    pub fn fill_vacant_seats(seats: Seats<T::MemberId, T::AccountId, BalanceOf<T>>) {
+        for seat in seats.iter() {
+            Self::add_reward_relationship(&seat.account, Self::council_mint());
+        }
+
+        let member_ids = seats.iter().map(|seat| seat.member).collect();
+
+        <ActiveCouncil<T>>::mutate(|council| council.extend(seats));
+
+        Self::deposit_event(RawEvent::VacantSeatsFilled(member_ids));
+    }
+
+    // Returns the stakes held by the seat: the locked seat stake is released or slashed, the
+    // reserved backing stakes are always refunded.
+    fn release_seat_stakes(seat: &Seat<T::MemberId, T::AccountId, BalanceOf<T>>, slash: bool) {
+        if slash {
+            let _ = <T as GovernanceCurrency>::Currency::slash(&seat.account, seat.stake);
+        }
+
+        Self::unlock_candidacy_stake(&seat.account, seat.stake);
+
+        for backer in seat.backers.iter() {
+            <T as GovernanceCurrency>::Currency::unreserve(&backer.member, backer.stake);
+        }
+    }
+
+    /// Checks whether the free balance of the account covers the candidacy stakes locked on it
+    /// along with the new stake.
+    pub fn can_lock_candidacy_stake(account: &T::AccountId, stake: BalanceOf<T>) -> bool {
+        let total_stake = Self::candidacy_locked_balance(account).saturating_add(stake);
+
+        <T as GovernanceCurrency>::Currency::free_balance(account) >= total_stake
+    }
+
+    /// Adds the stake to the candidacy lock of the account.
+    pub fn lock_candidacy_stake(account: &T::AccountId, stake: BalanceOf<T>) {
+        let total_stake = Self::candidacy_locked_balance(account).saturating_add(stake);
+
+        Self::set_candidacy_lock(account, total_stake);
+    }
+
+    /// Removes the stake from the candidacy lock of the account.
+    pub fn unlock_candidacy_stake(account: &T::AccountId, stake: BalanceOf<T>) {
+        let total_stake = Self::candidacy_locked_balance(account).saturating_sub(stake);
+
+        Self::set_candidacy_lock(account, total_stake);
+    }
+
+    fn set_candidacy_lock(account: &T::AccountId, total_stake: BalanceOf<T>) {
+        if total_stake.is_zero() {
+            <T as GovernanceCurrency>::Currency::remove_lock(T::CouncilLockId::get(), account);
+            <CandidacyLockedBalances<T>>::remove(account);
+        } else {
+            <T as GovernanceCurrency>::Currency::set_lock(
+                T::CouncilLockId::get(),
+                account,
+                total_stake,
+                WithdrawReasons::all(),
+            );
+            <CandidacyLockedBalances<T>>::insert(account, total_stake);
+        }
+    }
+
+    /// Budget left in the current term, taking into account everything minted since the last
+    /// charge. `None` when no budget is tracked.
+    pub fn current_remaining_budget() -> Option<minting::BalanceOf<T>> {
+        Self::remaining_budget()
+            .map(|remaining| remaining.saturating_sub(Self::minted_since_budget_charged()))
+    }
+
+    fn council_mint_total_minted() -> minting::BalanceOf<T> {
+        <minting::Module<T>>::mints(Self::council_mint()).total_minted()
+    }
+
+    fn minted_since_budget_charged() -> minting::BalanceOf<T> {
+        Self::council_mint_total_minted().saturating_sub(Self::minted_when_budget_charged())
+    }
+
+    // Charges the budget with everything minted since the last charge, and suspends councilor
+    // rewards once the budget is used up.
+    // Nothing is written when no budget is tracked or nothing was minted since the last charge.
+    fn charge_budget() {
+        let remaining_budget = match Self::remaining_budget() {
+            Some(remaining_budget) => remaining_budget,
+            None => return,
+        };
+
+        let minted_since_budget_charged = Self::minted_since_budget_charged();
+        if minted_since_budget_charged.is_zero() {
+            return;
+        }
+
+        let remaining_budget = remaining_budget.saturating_sub(minted_since_budget_charged);
+
+        <MintedWhenBudgetCharged<T>>::put(Self::council_mint_total_minted());
+        <RemainingBudget<T>>::put(remaining_budget);
+
+        if remaining_budget.is_zero() && !Self::rewards_suspended() {
+            Self::suspend_rewards();
+        }
+    }
+
+    // Starts the budget of a new term. Rewards of the previous council are removed by then, so the
+    // rewards of the new council can be created active.
+    fn refill_budget() {
+        // Everything minted so far belongs to the previous term.
+        <MintedWhenBudgetCharged<T>>::put(Self::council_mint_total_minted());
+
+        RewardsSuspended::put(false);
+
+        if let Some(budget) = Self::budget_per_term() {
+            <RemainingBudget<T>>::put(budget);
+
+            Self::deposit_event(RawEvent::BudgetRefilled(budget));
+        } else {
+            <RemainingBudget<T>>::kill();
+        }
+    }
+
+    fn suspend_rewards() {
+        for (_, relationship_id) in <RewardRelationships<T>>::iter() {
+            let _ = <recurringrewards::Module<T>>::try_to_deactivate_relationship(relationship_id);
+        }
+
+        RewardsSuspended::put(true);
+
+        Self::deposit_event(RawEvent::CouncilorRewardsSuspended());
     }
 
     fn add_reward_relationship(destination: &T::AccountId, reward_source: T::MintId) {
@@ -120,17 +434,26 @@ impl<T: Trait> Module<T> {
             Self::payout_interval(),
         ) {
             RewardRelationships::<T>::insert(destination, relationship_id);
+
+            if Self::rewards_suspended() {
+                let _ =
+                    <recurringrewards::Module<T>>::try_to_deactivate_relationship(relationship_id);
+            }
         } else {
             debug::warn!("Failed to create a reward relationship for council seat");
         }
     }
 
+    fn remove_reward_relationship(account: &T::AccountId) {
+        if RewardRelationships::<T>::contains_key(account) {
+            let id = Self::reward_relationships(account);
+            <recurringrewards::Module<T>>::remove_reward_relationship(id);
+        }
+    }
+
     fn remove_reward_relationships() {
         for seat in Self::active_council().into_iter() {
-            if RewardRelationships::<T>::contains_key(&seat.member) {
-                let id = Self::reward_relationships(&seat.member);
-                <recurringrewards::Module<T>>::remove_reward_relationship(id);
-            }
+            Self::remove_reward_relationship(&seat.account);
         }
     }
 
@@ -153,6 +476,8 @@ decl_module! {
         fn deposit_event() = default;
 
         fn on_finalize(now: T::BlockNumber) {
+            Self::charge_budget();
+
             if now == Self::term_ends_at() {
                 Self::on_term_ended(now);
             }
@@ -163,43 +488,36 @@ decl_module! {
         /// Force set a zero staked council. Stakes in existing council seats are not returned.
         /// Existing council rewards are removed and new council members do NOT get any rewards.
         /// Avoid using this call if possible, will be deprecated. The term of the new council is
-        /// not extended.
+        /// not extended. Seats are held by the member controller accounts.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn set_council(origin, accounts: Vec<T::AccountId>) {
+        pub fn set_council(origin, members: Vec<T::MemberId>) {
             ensure_root(origin)?;
 
+            let new_council = members
+                .into_iter()
+                .map(Self::zero_staked_seat)
+                .collect::<Result<Seats<T::MemberId, T::AccountId, BalanceOf<T>>, _>>()?;
+
             // Council is being replaced so remove existing reward relationships if they exist
             Self::remove_reward_relationships();
 
-            for account in accounts.clone() {
-                Self::add_reward_relationship(&account, Self::council_mint());
+            for seat in new_council.iter() {
+                Self::add_reward_relationship(&seat.account, Self::council_mint());
             }
 
-            let new_council: Seats<T::AccountId, BalanceOf<T>> = accounts.into_iter().map(|account| {
-                Seat {
-                    member: account,
-                    stake: BalanceOf::<T>::zero(),
-                    backers: vec![]
-                }
-            }).collect();
-
             <ActiveCouncil<T>>::put(new_council);
         }
 
         /// Adds a zero staked council member. A member added in this way does not get a recurring reward.
         #[weight = 10_000_000] // TODO: adjust weight
-        fn add_council_member(origin, account: T::AccountId) {
+        fn add_council_member(origin, member_id: T::MemberId) {
             ensure_root(origin)?;
 
-            ensure!(!Self::is_councilor(&account), "cannot add same account multiple times");
+            ensure!(!Self::is_councilor(&member_id), "cannot add same member multiple times");
 
-            Self::add_reward_relationship(&account, Self::council_mint());
+            let seat = Self::zero_staked_seat(member_id)?;
 
-            let seat = Seat {
-                member: account,
-                stake: BalanceOf::<T>::zero(),
-                backers: vec![]
-            };
+            Self::add_reward_relationship(&seat.account, Self::council_mint());
 
             // add member to existing council
             <ActiveCouncil<T>>::mutate(|council| council.push(seat));
@@ -207,22 +525,57 @@ decl_module! {
 
         /// Remove a single council member and their reward.
         #[weight = 10_000_000] // TODO: adjust weight
-        fn remove_council_member(origin, account_to_remove: T::AccountId) {
+        fn remove_council_member(origin, member_to_remove: T::MemberId) {
             ensure_root(origin)?;
 
-            ensure!(Self::is_councilor(&account_to_remove), "account is not a councilor");
+            ensure!(Self::is_councilor(&member_to_remove), "member is not a councilor");
+
+            let (removed_seats, filtered_council): (Seats<T::MemberId, T::AccountId, BalanceOf<T>>, _) =
+                Self::active_council()
+                    .into_iter()
+                    .partition(|c| c.member == member_to_remove);
 
-            if RewardRelationships::<T>::contains_key(&account_to_remove) {
-                let relationship_id = Self::reward_relationships(&account_to_remove);
-                <recurringrewards::Module<T>>::remove_reward_relationship(relationship_id);
+            for seat in removed_seats.iter() {
+                Self::remove_reward_relationship(&seat.account);
             }
 
-            let filtered_council: Seats<T::AccountId, BalanceOf<T>> = Self::active_council()
-                .into_iter()
-                .filter(|c| c.member != account_to_remove)
-                .collect();
+            <ActiveCouncil<T>>::put(filtered_council);
+        }
+
+        /// Removes a councilor who lost a vote of no confidence along with their reward. The seat
+        /// stake is slashed or refunded, backing stakes are refunded. A by-election for the
+        /// vacant seat can be requested.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_councilor_by_no_confidence(
+            origin,
+            councilor_id: T::MemberId,
+            slash_stake: bool,
+            start_by_election: bool
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(Self::is_councilor(&councilor_id), "member is not a councilor");
+
+            // Seat stakes are taken over by the election once the term ends.
+            ensure!(!Self::is_term_ended(), "council term has ended");
+
+            let (removed_seats, filtered_council): (Seats<T::MemberId, T::AccountId, BalanceOf<T>>, _) =
+                Self::active_council()
+                    .into_iter()
+                    .partition(|c| c.member == councilor_id);
+
+            for seat in removed_seats.iter() {
+                Self::remove_reward_relationship(&seat.account);
+                Self::release_seat_stakes(seat, slash_stake);
+            }
 
             <ActiveCouncil<T>>::put(filtered_council);
+
+            Self::deposit_event(RawEvent::CouncilorRemoved(councilor_id));
+
+            if start_by_election {
+                T::CouncilSeatVacated::council_seat_vacated();
+            }
         }
 
         /// Set blocknumber when council term will end
@@ -242,13 +595,53 @@ decl_module! {
             minting::Module::<T>::set_mint_capacity(Self::council_mint(), capacity).map_err(<&str>::from)?;
         }
 
-        /// Attempts to mint and transfer amount to destination account
+        /// Attempts to mint and transfer amount to destination account. The spending is recorded
+        /// and charged against the council budget.
         #[weight = 10_000_000] // TODO: adjust weight
-        fn spend_from_council_mint(origin, amount: minting::BalanceOf<T>, destination: T::AccountId) {
+        fn spend_from_council_mint(
+            origin,
+            amount: minting::BalanceOf<T>,
+            destination: T::AccountId,
+            purpose: SpendingPurpose
+        ) {
             ensure_root(origin)?;
 
+            if let Some(remaining_budget) = Self::current_remaining_budget() {
+                ensure!(amount <= remaining_budget, "council budget exceeded");
+            }
+
             minting::Module::<T>::transfer_tokens(Self::council_mint(), amount, &destination)
                 .map_err(<&str>::from)?;
+
+            Self::charge_budget();
+
+            let record_id = Self::next_spending_record_id();
+
+            <SpendingRecords<T>>::insert(record_id, SpendingRecord {
+                amount,
+                destination: destination.clone(),
+                spent_at: <frame_system::Module<T>>::block_number(),
+                purpose,
+            });
+
+            NextSpendingRecordId::put(record_id + 1);
+
+            Self::deposit_event(RawEvent::SpendingRecorded(record_id, destination, amount));
+        }
+
+        /// Sets the budget granted to each council term, starting with the next elected council.
+        /// `None` disables budget tracking from then on.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn set_council_budget_per_term(origin, budget: Option<minting::BalanceOf<T>>) {
+            ensure_root(origin)?;
+
+            if let Some(budget) = budget {
+                <BudgetPerTerm<T>>::put(budget);
+            } else {
+                <BudgetPerTerm<T>>::kill();
+            }
+
+            Self::deposit_event(RawEvent::BudgetPerTermUpdated(budget));
         }
 
         /// Sets the council rewards which is only applied on new council being elected.
@@ -278,12 +671,13 @@ mod tests {
     use super::*;
     use crate::mock::*;
     use crate::DispatchResult;
+    use frame_support::traits::Currency;
     use frame_support::*;
 
     fn add_council_member_as_root(
-        account: <Test as frame_system::Trait>::AccountId,
+        member_id: <Test as membership::Trait>::MemberId,
     ) -> DispatchResult {
-        Council::add_council_member(frame_system::RawOrigin::Root.into(), account)
+        Council::add_council_member(frame_system::RawOrigin::Root.into(), member_id)
             .map_err(|e| e.into())
     }
 
@@ -319,6 +713,77 @@ mod tests {
         });
     }
 
+    #[test]
+    fn remove_councilor_by_no_confidence_test() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+
+            for account in [5, 6, 9].iter() {
+                let _ = Balances::deposit_creating(account, 1000);
+            }
+            Council::lock_candidacy_stake(&5, 100);
+            Council::lock_candidacy_stake(&6, 100);
+            assert_ok!(Balances::reserve(&9, 50));
+
+            Council::council_elected(
+                vec![
+                    Seat {
+                        member: 5,
+                        account: 5,
+                        stake: 100,
+                        backers: vec![Backer {
+                            member: 9,
+                            stake: 50,
+                        }],
+                    },
+                    Seat {
+                        member: 6,
+                        account: 6,
+                        stake: 100,
+                        backers: vec![],
+                    },
+                ],
+                50,
+            );
+
+            // stakes are refunded
+            assert_ok!(Council::remove_councilor_by_no_confidence(
+                frame_system::RawOrigin::Root.into(),
+                5,
+                false,
+                false
+            ));
+
+            assert!(!Council::is_councilor(&5));
+            assert!(Council::is_councilor(&6));
+            assert_eq!(Balances::free_balance(&5), 1000);
+            assert_eq!(Council::candidacy_locked_balance(&5), 0);
+            assert_eq!(Balances::free_balance(&9), 1000);
+
+            // seat stake is slashed
+            assert_ok!(Council::remove_councilor_by_no_confidence(
+                frame_system::RawOrigin::Root.into(),
+                6,
+                true,
+                false
+            ));
+
+            assert!(Council::active_council().is_empty());
+            assert_eq!(Balances::total_balance(&6), 900);
+            assert_eq!(Council::candidacy_locked_balance(&6), 0);
+
+            assert_err!(
+                Council::remove_councilor_by_no_confidence(
+                    frame_system::RawOrigin::Root.into(),
+                    6,
+                    false,
+                    false
+                ),
+                "member is not a councilor"
+            );
+        });
+    }
+
     #[test]
     fn set_council_test() {
         initial_test_ext().execute_with(|| {
@@ -332,6 +797,99 @@ mod tests {
         });
     }
 
+    #[test]
+    fn migrate_active_council_test() {
+        initial_test_ext().execute_with(|| {
+            let account_based_council = vec![
+                AccountSeat {
+                    member: 5u64,
+                    stake: 10u64,
+                    backers: vec![Backer {
+                        member: 6,
+                        stake: 5,
+                    }],
+                },
+                // account without membership
+                AccountSeat {
+                    member: 100,
+                    stake: 0,
+                    backers: vec![],
+                },
+            ];
+            storage::unhashed::put(&<ActiveCouncil<Test>>::hashed_key(), &account_based_council);
+
+            // seat stakes of the account based elections were reserved
+            let _ = Balances::deposit_creating(&5, 100);
+            assert_ok!(Balances::reserve(&5, 10));
+
+            Council::migrate_active_council();
+
+            assert_eq!(
+                Council::active_council(),
+                vec![Seat {
+                    member: 5,
+                    account: 5,
+                    stake: 10,
+                    backers: vec![Backer {
+                        member: 6,
+                        stake: 5,
+                    }],
+                }]
+            );
+            assert_eq!(Balances::reserved_balance(&5), 0);
+            assert_eq!(Council::candidacy_locked_balance(&5), 10);
+        });
+    }
+
+    #[test]
+    fn migrate_active_council_picks_lowest_member_id_of_account() {
+        initial_test_ext().execute_with(|| {
+            let account = 7u64;
+
+            // The account controls the members 7, 9 and 15, the member 7 is listed last.
+            for member_id in [15u64, 9].iter() {
+                assert_ok!(membership::Module::<Test>::set_controller_account(
+                    Origin::signed(*member_id),
+                    *member_id,
+                    account
+                ));
+            }
+            assert_ok!(membership::Module::<Test>::set_controller_account(
+                Origin::signed(account),
+                7,
+                100
+            ));
+            assert_ok!(membership::Module::<Test>::set_controller_account(
+                Origin::signed(account),
+                7,
+                account
+            ));
+            assert_eq!(
+                membership::Module::<Test>::member_ids_by_controller_account_id(account),
+                vec![15, 9, 7]
+            );
+
+            let account_based_council = vec![AccountSeat {
+                member: account,
+                stake: 10u64,
+                backers: vec![],
+            }];
+            storage::unhashed::put(&<ActiveCouncil<Test>>::hashed_key(), &account_based_council);
+
+            Council::migrate_active_council();
+
+            assert_eq!(
+                Council::active_council(),
+                vec![Seat {
+                    member: 7,
+                    account,
+                    stake: 10,
+                    backers: vec![],
+                }]
+            );
+        });
+    }
+
     #[test]
     fn council_elected_test() {
         initial_test_ext().execute_with(|| {
@@ -345,16 +903,19 @@ mod tests {
                 vec![
                     Seat {
                         member: 5,
+                        account: 5,
                         stake: 0,
                         backers: vec![],
                     },
                     Seat {
                         member: 6,
+                        account: 6,
                         stake: 0,
                         backers: vec![],
                     },
                     Seat {
                         member: 7,
+                        account: 7,
                         stake: 0,
                         backers: vec![],
                     },
@@ -371,4 +932,159 @@ mod tests {
             assert!(RewardRelationships::<Test>::contains_key(&7));
         });
     }
+
+    #[test]
+    fn council_budget_is_refilled_and_charged_with_spending() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_council_mint_capacity(
+                frame_system::RawOrigin::Root.into(),
+                10_000
+            ));
+            assert_ok!(Council::set_council_budget_per_term(
+                frame_system::RawOrigin::Root.into(),
+                Some(1000)
+            ));
+
+            // budget is granted from the next council on
+            assert_eq!(Council::current_remaining_budget(), None);
+            assert_ok!(Council::spend_from_council_mint(
+                frame_system::RawOrigin::Root.into(),
+                2000,
+                1,
+                SpendingPurpose::Text(b"grant".to_vec())
+            ));
+
+            Council::council_elected(vec![], 50);
+            assert_eq!(Council::current_remaining_budget(), Some(1000));
+
+            assert_ok!(Council::spend_from_council_mint(
+                frame_system::RawOrigin::Root.into(),
+                300,
+                2,
+                SpendingPurpose::Proposal(1)
+            ));
+            assert_eq!(Council::current_remaining_budget(), Some(700));
+
+            assert_eq!(Council::next_spending_record_id(), 2);
+            assert_eq!(
+                Council::spending_records(1),
+                SpendingRecord {
+                    amount: 300,
+                    destination: 2,
+                    spent_at: System::block_number(),
+                    purpose: SpendingPurpose::Proposal(1),
+                }
+            );
+
+            assert_err!(
+                Council::spend_from_council_mint(
+                    frame_system::RawOrigin::Root.into(),
+                    800,
+                    2,
+                    SpendingPurpose::Proposal(2)
+                ),
+                "council budget exceeded"
+            );
+        });
+    }
+
+    #[test]
+    fn council_budget_is_charged_only_when_tracked_and_minted() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_council_mint_capacity(
+                frame_system::RawOrigin::Root.into(),
+                10_000
+            ));
+            assert_ok!(Council::spend_from_council_mint(
+                frame_system::RawOrigin::Root.into(),
+                2000,
+                1,
+                SpendingPurpose::Text(b"grant".to_vec())
+            ));
+
+            // no budget is tracked
+            Council::charge_budget();
+            assert_eq!(Council::minted_when_budget_charged(), 0);
+
+            assert_ok!(Council::set_council_budget_per_term(
+                frame_system::RawOrigin::Root.into(),
+                Some(1000)
+            ));
+            Council::council_elected(vec![], 50);
+            assert_eq!(Council::minted_when_budget_charged(), 2000);
+
+            // nothing minted since the refill
+            Council::charge_budget();
+            assert_eq!(Council::remaining_budget(), Some(1000));
+
+            assert_ok!(Council::spend_from_council_mint(
+                frame_system::RawOrigin::Root.into(),
+                300,
+                2,
+                SpendingPurpose::Proposal(1)
+            ));
+            assert_eq!(Council::minted_when_budget_charged(), 2300);
+            assert_eq!(Council::remaining_budget(), Some(700));
+        });
+    }
+
+    #[test]
+    fn councilor_rewards_are_suspended_when_budget_runs_out() {
+        initial_test_ext().execute_with(|| {
+            assert_ok!(Council::set_council_mint_capacity(
+                frame_system::RawOrigin::Root.into(),
+                10_000
+            ));
+            assert_ok!(Council::set_council_budget_per_term(
+                frame_system::RawOrigin::Root.into(),
+                Some(500)
+            ));
+
+            Council::council_elected(
+                vec![Seat {
+                    member: 5,
+                    account: 5,
+                    stake: 0,
+                    backers: vec![],
+                }],
+                50,
+            );
+
+            let relationship_id = Council::reward_relationships(&5);
+            assert!(
+                <recurringrewards::Module<Test>>::reward_relationships(relationship_id).is_active()
+            );
+
+            assert_ok!(Council::spend_from_council_mint(
+                frame_system::RawOrigin::Root.into(),
+                500,
+                2,
+                SpendingPurpose::Text(b"grant".to_vec())
+            ));
+
+            assert!(Council::rewards_suspended());
+            assert!(
+                !<recurringrewards::Module<Test>>::reward_relationships(relationship_id)
+                    .is_active()
+            );
+
+            // the next council gets a fresh budget and active rewards
+            Council::council_elected(
+                vec![Seat {
+                    member: 6,
+                    account: 6,
+                    stake: 0,
+                    backers: vec![],
+                }],
+                50,
+            );
+
+            assert!(!Council::rewards_suspended());
+            assert_eq!(Council::current_remaining_budget(), Some(500));
+            assert!(<recurringrewards::Module<Test>>::reward_relationships(
+                Council::reward_relationships(&6)
+            )
+            .is_active());
+        });
+    }
 }
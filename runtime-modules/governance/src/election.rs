@@ -19,7 +19,31 @@
 //!
 //! We only guard against these edge cases in the [`set_election_parameters`] call.
 //!
+//! # Tally modes:
+//! Votes are revealed as ballots listing the approved applicants, most preferred first. A plain
+//! [`reveal`] is a ballot with a single applicant, while [`reveal_ballot`] accepts several.
+//!
+//! - `TopStaked` - each vote backs the first choice of its ballot and the applicants with the
+//! highest total backing take the seats.
+//!
+//! - `SequentialPhragmen` - seats are assigned by sequential Phragmén over the full ballots, and
+//! each vote backs the most preferred elected applicant of its ballot.
+//!
+//! # Vote delegation:
+//! An account can delegate its vote to a representative until it revokes the delegation. The
+//! delegating account still commits a vote with its stake in every round, but when it doesn't
+//! reveal the commitment the vote falls back to the ballot revealed by the delegate. Only a
+//! vote revealed by the delegate itself is followed, delegations are not chained.
+//!
+//! # By-elections:
+//! A council seat vacated during the term can be filled by a by-election. It elects applicants
+//! for the vacant seats only and they join the active council for the rest of its term. The
+//! sitting councilors cannot apply. A by-election still running when the council term ends is
+//! stopped in favour of the regular election.
+//!
 //! [`set_election_parameters`]: struct.Module.html#method.set_election_parameters
+//! [`reveal`]: struct.Module.html#method.reveal
+//! [`reveal_ballot`]: struct.Module.html#method.reveal_ballot
 
 // Clippy linter warning
 #![allow(clippy::type_complexity)]
@@ -34,11 +58,15 @@
 use serde::{Deserialize, Serialize};
 
 use codec::{Decode, Encode};
+use frame_support::storage::migration;
 use frame_support::traits::{Currency, ReservableCurrency};
-use frame_support::{decl_event, decl_module, decl_storage, ensure};
+use frame_support::{
+    debug, decl_event, decl_module, decl_storage, ensure, Blake2_128Concat, IterableStorageMap,
+    StorageHasher,
+};
 use frame_system::{ensure_root, ensure_signed};
 use sp_arithmetic::traits::Zero;
-use sp_runtime::traits::Hash;
+use sp_runtime::traits::{Hash, SaturatedConversion};
 use sp_std::collections::btree_map::BTreeMap;
 use sp_std::ops::Add;
 use sp_std::vec;
@@ -48,8 +76,10 @@ use super::sealed_vote::SealedVote;
 use super::stake::Stake;
 
 use super::council;
-use crate::election_params::ElectionParameters;
+use super::phragmen;
+use crate::election_params::{ElectionParameters, ElectionTallyMode};
 pub use common::currency::{BalanceOf, GovernanceCurrency};
+use common::origin::ActorOriginValidator;
 
 use crate::DispatchResult;
 
@@ -58,7 +88,17 @@ pub trait Trait:
 {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
-    type CouncilElected: CouncilElected<Seats<Self::AccountId, BalanceOf<Self>>, Self::BlockNumber>;
+    type CouncilElected: CouncilElected<
+        Seats<Self::MemberId, Self::AccountId, BalanceOf<Self>>,
+        Self::BlockNumber,
+    >;
+
+    /// Validates the member origin of the council applicants.
+    type MembershipOriginValidator: ActorOriginValidator<
+        Self::Origin,
+        Self::MemberId,
+        Self::AccountId,
+    >;
 }
 
 pub static MSG_CANNOT_CHANGE_PARAMS_DURING_ELECTION: &str = "CannotChangeParamsDuringElection";
@@ -72,13 +112,15 @@ pub enum ElectionStage<BlockNumber> {
 
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
-pub struct Seat<AccountId, Balance> {
-    pub member: AccountId,
+pub struct Seat<MemberId, AccountId, Balance> {
+    pub member: MemberId,
+    /// Account holding the seat stake and receiving the council reward.
+    pub account: AccountId,
     pub stake: Balance,
     pub backers: Vec<Backer<AccountId, Balance>>,
 }
 
-impl<AccountId, Balance> Seat<AccountId, Balance>
+impl<MemberId, AccountId, Balance> Seat<MemberId, AccountId, Balance>
 where
     Balance: Add<Output = Balance> + Copy,
 {
@@ -96,7 +138,10 @@ pub struct Backer<AccountId, Balance> {
     pub stake: Balance,
 }
 
-pub type Seats<AccountId, Balance> = Vec<Seat<AccountId, Balance>>;
+pub type Seats<MemberId, AccountId, Balance> = Vec<Seat<MemberId, AccountId, Balance>>;
+
+/// Applicants approved by a revealed vote, most preferred first.
+pub type Ballot<MemberId> = Vec<MemberId>;
 
 // Hook for setting a new council when it is elected
 pub trait CouncilElected<Elected, Term> {
@@ -151,15 +196,19 @@ decl_storage! {
         TransferableStakes get(fn transferable_stakes): map hasher(blake2_128_concat)
             T::AccountId => TransferableStake<BalanceOf<T>>;
 
-        Applicants get(fn applicants): Vec<T::AccountId>;
+        Applicants get(fn applicants): Vec<T::MemberId>;
         ApplicantStakes get(fn applicant_stakes): map hasher(blake2_128_concat)
-            T::AccountId => ElectionStake<T>;
+            T::MemberId => ElectionStake<T>;
+
+        // Accounts holding the applicant stakes.
+        ApplicantAccounts get(fn applicant_account): map hasher(blake2_128_concat)
+            T::MemberId => T::AccountId;
 
         Commitments get(fn commitments): Vec<T::Hash>;
 
         // TODO value type of this map looks scary, is there any way to simplify the notation?
         Votes get(fn votes): map hasher(blake2_128_concat)
-            T::Hash => SealedVote<T::AccountId, ElectionStake<T>, T::Hash, T::AccountId>;
+            T::Hash => SealedVote<T::AccountId, ElectionStake<T>, T::Hash, Ballot<T::MemberId>>;
 
         // Current Election Parameters.
         // Should we replace all the individual values with a single ElectionParameters type?
@@ -173,6 +222,20 @@ decl_storage! {
         MinCouncilStake get(fn min_council_stake): BalanceOf<T>;
         NewTermDuration get(fn new_term_duration): T::BlockNumber;
         MinVotingStake get(fn min_voting_stake): BalanceOf<T>;
+        TallyMode get(fn tally_mode): ElectionTallyMode;
+
+        /// Number of vacant council seats filled by the running by-election. `None` when no
+        /// by-election is running.
+        ByElectionSeats get(fn by_election_seats): Option<u32>;
+
+        /// A council seat was vacated while a by-election was running. The by-election for the
+        /// remaining vacant seats is started once the running one ends.
+        ByElectionQueued get(fn by_election_queued): bool;
+
+        /// Standing vote delegations: the representative whose revealed ballot is used for the
+        /// unrevealed votes of the delegating account.
+        VoteDelegations get(fn vote_delegation): map hasher(blake2_128_concat)
+            T::AccountId => Option<T::AccountId>;
     }
     add_extra_genesis {
         config(election_parameters): ElectionParameters<BalanceOf<T>, T::BlockNumber>;
@@ -188,7 +251,8 @@ decl_event!(
     pub enum Event<T> where
     <T as frame_system::Trait>::BlockNumber,
     <T as frame_system::Trait>::AccountId,
-    <T as frame_system::Trait>::Hash  {
+    <T as frame_system::Trait>::Hash,
+    <T as membership::Trait>::MemberId {
         /// A new election started
         ElectionStarted(),
         AnnouncingStarted(u32),
@@ -198,9 +262,12 @@ decl_event!(
         RevealingStarted(),
         RevealingEnded(),
         CouncilElected(BlockNumber),
-        Applied(AccountId),
+        Applied(MemberId),
         Voted(AccountId, Hash),
-        Revealed(AccountId, Hash, AccountId),
+        Revealed(AccountId, Hash, MemberId),
+        BallotRevealed(AccountId, Hash, Vec<MemberId>),
+        VoteDelegated(AccountId, AccountId),
+        VoteDelegationRevoked(AccountId),
     }
 );
 
@@ -211,6 +278,12 @@ impl<T: Trait> Module<T> {
         Self::council_size() as usize
     }
 
+    // Seats assigned by the running election: the vacant seats in a by-election, the whole
+    // council otherwise.
+    fn seats_to_fill() -> usize {
+        Self::by_election_seats().map_or(Self::council_size_usize(), |seats| seats as usize)
+    }
+
     fn candidacy_limit_usize() -> usize {
         Self::candidacy_limit() as usize
     }
@@ -248,7 +321,9 @@ impl<T: Trait> Module<T> {
 
     /// Starts an election. Will fail if an election is already running
     /// Initializes transferable stakes. Assumes election parameters have already been set.
-    fn start_election(current_council: Seats<T::AccountId, BalanceOf<T>>) -> DispatchResult {
+    fn start_election(
+        current_council: Seats<T::MemberId, T::AccountId, BalanceOf<T>>,
+    ) -> DispatchResult {
         ensure!(!Self::is_election_running(), "election already in progress");
         ensure!(
             Self::existing_stake_holders().is_empty(),
@@ -268,6 +343,29 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    /// Starts a by-election for the vacant seats of the active council. The elected applicants
+    /// join the active council for the rest of its term, and the seats of the sitting councilors
+    /// are not contested.
+    fn start_by_election() -> DispatchResult {
+        let vacant_seats = Self::council_size()
+            .saturating_sub(<council::Module<T>>::active_council().len() as u32);
+
+        ensure!(vacant_seats > 0, "no vacant council seats");
+
+        Self::start_election(Vec::new())?;
+
+        ByElectionSeats::put(vacant_seats);
+
+        Ok(())
+    }
+
+    /// Starts a by-election, reporting the failure instead of returning it.
+    fn try_start_by_election() {
+        if let Err(err) = Self::start_by_election() {
+            debug::warn!("Failed to start the council by-election: {:?}", err);
+        }
+    }
+
     /// Sets announcing stage. Can be called from any stage and assumes all preparatory work
     /// for entering the stage has been performed.
     /// Bumps the election round.
@@ -307,9 +405,9 @@ impl<T: Trait> Module<T> {
     /// Sorts applicants by stake, and returns slice of applicants with least stake. Applicants not
     /// returned in the slice are the top `len` highest staked.
     fn find_least_staked_applicants(
-        applicants: &mut Vec<T::AccountId>,
+        applicants: &mut Vec<T::MemberId>,
         len: usize,
-    ) -> &[T::AccountId] {
+    ) -> &[T::MemberId] {
         if len >= applicants.len() {
             &[]
         } else {
@@ -322,12 +420,12 @@ impl<T: Trait> Module<T> {
     fn on_announcing_ended() {
         let mut applicants = Self::applicants();
 
-        if applicants.len() < Self::council_size_usize() {
+        if applicants.len() < Self::seats_to_fill() {
             // Not enough applicants announced candidacy
             Self::move_to_announcing_stage();
         } else {
             // upper limit on applicants that will move to voting stage
-            let limit = sp_std::cmp::max(Self::council_size_usize(), Self::candidacy_limit_usize());
+            let limit = sp_std::cmp::max(Self::seats_to_fill(), Self::candidacy_limit_usize());
             let applicants_to_drop = Self::find_least_staked_applicants(&mut applicants, limit);
 
             Self::drop_applicants(applicants_to_drop);
@@ -348,7 +446,65 @@ impl<T: Trait> Module<T> {
             votes.push(Self::votes(commitment));
         }
 
-        let mut new_council = Self::tally_votes(&votes);
+        Self::cast_delegated_votes(&mut votes);
+
+        let new_council = match Self::tally_mode() {
+            ElectionTallyMode::TopStaked => Self::tally_top_staked(&votes),
+            ElectionTallyMode::SequentialPhragmen => Self::tally_sequential_phragmen(&votes),
+        };
+
+        let is_by_election = Self::by_election_seats().is_some();
+
+        // unless we want to add more filtering criteria to what is considered a successful election
+        // other than just the minimum stake for candidacy, we have a new council!
+
+        Self::teardown_election(
+            &votes,
+            &new_council,
+            true, /* unlock transferable stakes */
+        );
+
+        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
+
+        if is_by_election {
+            <council::Module<T>>::fill_vacant_seats(new_council);
+        } else {
+            T::CouncilElected::council_elected(new_council, Self::new_term_duration());
+        }
+
+        Self::deposit_event(RawEvent::CouncilElected(
+            <frame_system::Module<T>>::block_number(),
+        ));
+    }
+
+    // Unrevealed votes of the delegating accounts follow the first ballot revealed by their delegate.
+    fn cast_delegated_votes(
+        votes: &mut [SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, Ballot<T::MemberId>>],
+    ) {
+        let mut revealed_ballots: BTreeMap<T::AccountId, Ballot<T::MemberId>> = BTreeMap::new();
+
+        for sealed_vote in votes.iter() {
+            if let Some(ballot) = sealed_vote.get_vote() {
+                revealed_ballots
+                    .entry(sealed_vote.voter.clone())
+                    .or_insert_with(|| ballot.clone());
+            }
+        }
+
+        for sealed_vote in votes.iter_mut().filter(|vote| vote.is_not_revealed()) {
+            let delegate_ballot = Self::vote_delegation(&sealed_vote.voter)
+                .and_then(|delegate| revealed_ballots.get(&delegate));
+
+            if let Some(ballot) = delegate_ballot {
+                sealed_vote.cast_on_behalf(ballot.clone());
+            }
+        }
+    }
+
+    fn tally_top_staked(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, Ballot<T::MemberId>>],
+    ) -> BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>> {
+        let mut new_council = Self::tally_votes(votes);
 
         // Note here that applicants with zero votes dont appear in the tally.
         // Is an applicant with some votes but less total stake than another applicant with zero votes
@@ -358,9 +514,10 @@ impl<T: Trait> Module<T> {
         for applicant in Self::applicants().iter() {
             if !new_council.contains_key(applicant) {
                 new_council.insert(
-                    applicant.clone(),
+                    *applicant,
                     Seat {
-                        member: applicant.clone(),
+                        member: *applicant,
+                        account: Self::applicant_account(applicant),
                         stake: Self::applicant_stakes(applicant).total(),
                         backers: Vec::new(),
                     },
@@ -369,13 +526,13 @@ impl<T: Trait> Module<T> {
         }
 
         match new_council.len() {
-            ncl if ncl == Self::council_size_usize() => {
+            ncl if ncl == Self::seats_to_fill() => {
                 // all applicants in the tally will form the new council
             }
-            ncl if ncl > Self::council_size_usize() => {
+            ncl if ncl > Self::seats_to_fill() => {
                 // we have more than enough applicants to form the new council.
                 // select top staked
-                Self::filter_top_staked(&mut new_council, Self::council_size_usize());
+                Self::filter_top_staked(&mut new_council, Self::seats_to_fill());
             }
             _ => {
                 // Not enough applicants with votes to form a council.
@@ -385,26 +542,93 @@ impl<T: Trait> Module<T> {
             }
         }
 
-        // unless we want to add more filtering criteria to what is considered a successful election
-        // other than just the minimum stake for candidacy, we have a new council!
+        new_council
+    }
 
-        Self::teardown_election(
-            &votes,
-            &new_council,
-            true, /* unlock transferable stakes */
-        );
+    fn tally_sequential_phragmen(
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, Ballot<T::MemberId>>],
+    ) -> BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>> {
+        let applicants = Self::applicants();
 
-        let new_council = new_council.into_iter().map(|(_, seat)| seat).collect();
-        T::CouncilElected::council_elected(new_council, Self::new_term_duration());
+        // applicants back themselves with their own stake
+        let mut voters: Vec<phragmen::Voter<T::MemberId>> = applicants
+            .iter()
+            .map(|applicant| phragmen::Voter {
+                stake: Self::applicant_stakes(applicant).total().saturated_into(),
+                approvals: vec![*applicant],
+            })
+            .collect();
 
-        Self::deposit_event(RawEvent::CouncilElected(
-            <frame_system::Module<T>>::block_number(),
-        ));
+        voters.extend(votes.iter().filter_map(|sealed_vote| {
+            sealed_vote
+                .get_vote()
+                .as_ref()
+                .map(|ballot| phragmen::Voter {
+                    stake: sealed_vote.stake.total().saturated_into(),
+                    approvals: ballot.clone(),
+                })
+        }));
+
+        let mut elected = phragmen::elect(Self::seats_to_fill(), &applicants, &voters);
+
+        // As in the top staked tally, applicants without any backing still get the remaining seats
+        for applicant in applicants.iter() {
+            if elected.len() >= Self::seats_to_fill() {
+                break;
+            }
+            if !elected.contains(applicant) {
+                elected.push(*applicant);
+            }
+        }
+
+        let mut new_council: BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>> =
+            elected
+                .into_iter()
+                .map(|applicant| {
+                    let seat = Seat {
+                        member: applicant,
+                        account: Self::applicant_account(applicant),
+                        stake: Self::applicant_stakes(applicant).total(),
+                        backers: vec![],
+                    };
+                    (applicant, seat)
+                })
+                .collect();
+
+        for sealed_vote in votes.iter() {
+            if let Some(ballot) = sealed_vote.get_vote() {
+                if let Some(applicant) = Self::backed_applicant(ballot, &new_council) {
+                    if let Some(seat) = new_council.get_mut(&applicant) {
+                        seat.backers.push(Backer {
+                            member: sealed_vote.voter.clone(),
+                            stake: sealed_vote.stake.total(),
+                        });
+                    }
+                }
+            }
+        }
+
+        new_council
+    }
+
+    // Finds the elected applicant backed by the ballot stake, if any.
+    fn backed_applicant(
+        ballot: &[T::MemberId],
+        new_council: &BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>>,
+    ) -> Option<T::MemberId> {
+        match Self::tally_mode() {
+            ElectionTallyMode::TopStaked => ballot.first(),
+            ElectionTallyMode::SequentialPhragmen => ballot
+                .iter()
+                .find(|applicant| new_council.contains_key(applicant)),
+        }
+        .filter(|applicant| new_council.contains_key(applicant))
+        .copied()
     }
 
     fn teardown_election(
-        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
-        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+        votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, Ballot<T::MemberId>>],
+        new_council: &BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>>,
         unlock_ts: bool,
     ) {
         Self::refund_voting_stakes(&votes, &new_council);
@@ -420,17 +644,90 @@ impl<T: Trait> Module<T> {
         Self::clear_transferable_stakes();
 
         <Stage<T>>::kill();
+        ByElectionSeats::kill();
+    }
+
+    // Stops the running election without electing anyone.
+    fn stop_election() {
+        let mut votes = Vec::new();
+        for commitment in Self::commitments() {
+            votes.push(Self::votes(commitment));
+        }
+
+        // no council gets elected
+        let empty_council = BTreeMap::new();
+
+        Self::teardown_election(
+            &votes,
+            &empty_council,
+            false, /* do not unlock transferable stakes */
+        );
+    }
+
+    /// Stops the election running on the migration to the member id based elections: the applicant
+    /// and voting stakes stored with the account based types are refunded and the election storage
+    /// is cleared. Should be called on the migration to the new runtime version.
+    pub fn migrate_election_in_progress() {
+        if !Self::is_election_running() {
+            return;
+        }
+
+        let applicants = migration::take_storage_value::<Vec<T::AccountId>>(
+            b"CouncilElection",
+            b"Applicants",
+            &[],
+        )
+        .unwrap_or_default();
+
+        for applicant in applicants {
+            let stake = migration::take_storage_value::<ElectionStake<T>>(
+                b"CouncilElection",
+                b"ApplicantStakes",
+                &Blake2_128Concat::hash(&applicant.encode()),
+            )
+            .unwrap_or_default();
+
+            // Transferred stakes remain locked by the active council, as on the election stop.
+            // Stakes of the account based elections were reserved.
+            if !stake.new.is_zero() {
+                <T as GovernanceCurrency>::Currency::unreserve(&applicant, stake.new);
+            }
+        }
+
+        <Votes<T>>::translate::<SealedVote<T::AccountId, ElectionStake<T>, T::Hash, T::AccountId>, _>(
+            |_, sealed_vote| {
+                if !sealed_vote.stake.new.is_zero() {
+                    <T as GovernanceCurrency>::Currency::unreserve(
+                        &sealed_vote.voter,
+                        sealed_vote.stake.new,
+                    );
+                }
+
+                None
+            },
+        );
+
+        <Commitments<T>>::kill();
+
+        Self::clear_transferable_stakes();
+
+        <Stage<T>>::kill();
+        ByElectionSeats::kill();
+        ByElectionQueued::kill();
+
+        debug::warn!("Stopped the council election running on the runtime upgrade");
     }
 
     fn unlock_transferable_stakes() {
         // move stakes back to account holder's free balance
         for stakeholder in Self::existing_stake_holders().iter() {
             let stake = Self::transferable_stakes(stakeholder);
-            if !stake.seat.is_zero() || !stake.backing.is_zero() {
-                <T as GovernanceCurrency>::Currency::unreserve(
-                    stakeholder,
-                    stake.seat + stake.backing,
-                );
+            if !stake.seat.is_zero() {
+                <council::Module<T>>::unlock_candidacy_stake(stakeholder, stake.seat);
+            }
+
+            if !stake.backing.is_zero() {
+                <T as GovernanceCurrency>::Currency::unreserve(stakeholder, stake.backing);
             }
         }
     }
@@ -446,28 +743,30 @@ impl<T: Trait> Module<T> {
     fn clear_applicants() {
         for applicant in Self::applicants() {
             <ApplicantStakes<T>>::remove(applicant);
+            <ApplicantAccounts<T>>::remove(applicant);
         }
         <Applicants<T>>::kill();
     }
 
-    fn refund_applicant(applicant: &T::AccountId) {
+    fn refund_applicant(applicant: &T::MemberId) {
         let stake = <ApplicantStakes<T>>::get(applicant);
+        let account = Self::applicant_account(applicant);
 
-        // return new stake to account's free balance
+        // unlock new stake
         if !stake.new.is_zero() {
-            <T as GovernanceCurrency>::Currency::unreserve(applicant, stake.new);
+            <council::Module<T>>::unlock_candidacy_stake(&account, stake.new);
         }
 
         // return unused transferable stake
         if !stake.transferred.is_zero() {
-            <TransferableStakes<T>>::mutate(applicant, |transferable| {
+            <TransferableStakes<T>>::mutate(&account, |transferable| {
                 (*transferable).seat += stake.transferred
             });
         }
     }
 
-    fn drop_applicants(drop: &[T::AccountId]) {
-        let not_dropped: Vec<T::AccountId> = Self::applicants()
+    fn drop_applicants(drop: &[T::MemberId]) {
+        let not_dropped: Vec<T::MemberId> = Self::applicants()
             .into_iter()
             .filter(|id| !drop.iter().any(|x| *x == *id))
             .collect();
@@ -475,15 +774,16 @@ impl<T: Trait> Module<T> {
         for applicant in drop {
             Self::refund_applicant(applicant);
             <ApplicantStakes<T>>::remove(applicant);
+            <ApplicantAccounts<T>>::remove(applicant);
         }
 
         <Applicants<T>>::put(not_dropped);
     }
 
     fn drop_unelected_applicants(
-        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+        new_council: &BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>>,
     ) {
-        let applicants_to_drop: Vec<T::AccountId> = Self::applicants()
+        let applicants_to_drop: Vec<T::MemberId> = Self::applicants()
             .into_iter()
             .filter(|applicant| !new_council.contains_key(&applicant))
             .collect();
@@ -492,8 +792,13 @@ impl<T: Trait> Module<T> {
     }
 
     fn refund_voting_stakes(
-        sealed_votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
-        new_council: &BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+        sealed_votes: &[SealedVote<
+            T::AccountId,
+            Stake<BalanceOf<T>>,
+            T::Hash,
+            Ballot<T::MemberId>,
+        >],
+        new_council: &BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>>,
     ) {
         for sealed_vote in sealed_votes.iter() {
             // Do a refund if commitment was not revealed, or the vote was for applicant that did
@@ -501,7 +806,7 @@ impl<T: Trait> Module<T> {
             // TODO critical: shouldn't we slash the stake in such a case? This is the whole idea behid staking on something: people need to decide carefully and be responsible for their bahavior because they can loose their stake
             // See https://github.com/Joystream/substrate-node-joystream/issues/4
             let do_refund = match sealed_vote.get_vote() {
-                Some(applicant) => !new_council.contains_key(&applicant),
+                Some(ballot) => Self::backed_applicant(ballot, &new_council).is_none(),
                 None => true,
             };
 
@@ -530,18 +835,30 @@ impl<T: Trait> Module<T> {
     }
 
     fn tally_votes(
-        sealed_votes: &[SealedVote<T::AccountId, Stake<BalanceOf<T>>, T::Hash, T::AccountId>],
-    ) -> BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> {
-        let mut tally: BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>> = BTreeMap::new();
+        sealed_votes: &[SealedVote<
+            T::AccountId,
+            Stake<BalanceOf<T>>,
+            T::Hash,
+            Ballot<T::MemberId>,
+        >],
+    ) -> BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>> {
+        let mut tally: BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>> =
+            BTreeMap::new();
 
         for sealed_vote in sealed_votes.iter() {
-            if let Some(applicant) = sealed_vote.get_vote() {
+            // only the first choice of a ballot is counted
+            if let Some(applicant) = sealed_vote
+                .get_vote()
+                .as_ref()
+                .and_then(|ballot| ballot.first())
+            {
                 if !tally.contains_key(&applicant) {
                     // Add new seat
                     tally.insert(
-                        applicant.clone(),
+                        *applicant,
                         Seat {
-                            member: applicant.clone(),
+                            member: *applicant,
+                            account: Self::applicant_account(applicant),
                             stake: Self::applicant_stakes(applicant).total(),
                             backers: vec![],
                         },
@@ -561,7 +878,7 @@ impl<T: Trait> Module<T> {
     }
 
     fn filter_top_staked(
-        tally: &mut BTreeMap<T::AccountId, Seat<T::AccountId, BalanceOf<T>>>,
+        tally: &mut BTreeMap<T::MemberId, Seat<T::MemberId, T::AccountId, BalanceOf<T>>>,
         limit: usize,
     ) {
         if limit >= tally.len() {
@@ -569,7 +886,7 @@ impl<T: Trait> Module<T> {
         }
 
         // use ordering in the applicants vector (not ordering resulting from btreemap iteration)
-        let mut seats: Vec<T::AccountId> = Self::applicants()
+        let mut seats: Vec<T::MemberId> = Self::applicants()
             .into_iter()
             .filter(|id| tally.contains_key(id))
             .collect();
@@ -624,14 +941,16 @@ impl<T: Trait> Module<T> {
     }
 
     /// Takes a snapshot of the stakes from the current council
-    fn initialize_transferable_stakes(current_council: Seats<T::AccountId, BalanceOf<T>>) {
+    fn initialize_transferable_stakes(
+        current_council: Seats<T::MemberId, T::AccountId, BalanceOf<T>>,
+    ) {
         let mut stakeholder_accounts: Vec<T::AccountId> = Vec::new();
 
         for seat in current_council.into_iter() {
-            let Seat { member, stake, .. } = seat;
+            let Seat { account, stake, .. } = seat;
 
-            if <TransferableStakes<T>>::contains_key(&member) {
-                <TransferableStakes<T>>::mutate(&member, |transferbale_stake| {
+            if <TransferableStakes<T>>::contains_key(&account) {
+                <TransferableStakes<T>>::mutate(&account, |transferbale_stake| {
                     *transferbale_stake = TransferableStake {
                         seat: transferbale_stake.seat + stake,
                         backing: transferbale_stake.backing,
@@ -639,14 +958,14 @@ impl<T: Trait> Module<T> {
                 });
             } else {
                 <TransferableStakes<T>>::insert(
-                    &member,
+                    &account,
                     TransferableStake {
                         seat: stake,
                         backing: BalanceOf::<T>::zero(),
                     },
                 );
 
-                stakeholder_accounts.push(member);
+                stakeholder_accounts.push(account);
             }
 
             for backer in seat.backers.into_iter() {
@@ -694,32 +1013,44 @@ impl<T: Trait> Module<T> {
         }
     }
 
-    fn try_add_applicant(applicant: T::AccountId, stake: BalanceOf<T>) -> DispatchResult {
-        let mut transferable_stake = <TransferableStakes<T>>::get(&applicant);
+    fn try_add_applicant(
+        applicant: T::MemberId,
+        account: T::AccountId,
+        stake: BalanceOf<T>,
+    ) -> DispatchResult {
+        // all stake of an applicant is held by a single account
+        if <ApplicantAccounts<T>>::contains_key(&applicant) {
+            ensure!(
+                Self::applicant_account(&applicant) == account,
+                "applicant stake must be provided by the same account"
+            );
+        }
+
+        let mut transferable_stake = <TransferableStakes<T>>::get(&account);
 
         let new_stake = Self::new_stake_reusing_transferable(&mut transferable_stake.seat, stake);
 
         ensure!(
-            <T as GovernanceCurrency>::Currency::can_reserve(&applicant, new_stake.new),
-            "not enough free balance to reserve"
+            <council::Module<T>>::can_lock_candidacy_stake(&account, new_stake.new),
+            "not enough free balance to lock"
         );
 
-        ensure!(
-            <T as GovernanceCurrency>::Currency::reserve(&applicant, new_stake.new).is_ok(),
-            "failed to reserve applicant stake!"
-        );
+        if !new_stake.new.is_zero() {
+            <council::Module<T>>::lock_candidacy_stake(&account, new_stake.new);
+        }
 
         let applicant_stake = <ApplicantStakes<T>>::get(&applicant);
         let total_stake = applicant_stake.add(&new_stake);
 
-        if <TransferableStakes<T>>::contains_key(&applicant) {
-            <TransferableStakes<T>>::insert(&applicant, transferable_stake);
+        if <TransferableStakes<T>>::contains_key(&account) {
+            <TransferableStakes<T>>::insert(&account, transferable_stake);
         }
 
         if !<ApplicantStakes<T>>::contains_key(&applicant) {
             // insert element at the begining, this gives priority to early applicants
             // when ordering applicants by stake if stakes are equal
-            <Applicants<T>>::mutate(|applicants| applicants.insert(0, applicant.clone()));
+            <Applicants<T>>::mutate(|applicants| applicants.insert(0, applicant));
+            <ApplicantAccounts<T>>::insert(applicant, account);
         }
 
         <ApplicantStakes<T>>::insert(applicant, total_stake);
@@ -766,22 +1097,32 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
-    fn try_reveal_vote(
+    fn ensure_vote_can_be_revealed(
         voter: T::AccountId,
-        commitment: T::Hash,
-        vote_for: T::AccountId,
-        salt: Vec<u8>,
-    ) -> DispatchResult {
-        ensure!(
-            <Votes<T>>::contains_key(&commitment),
-            "commitment not found"
-        );
+        commitment: &T::Hash,
+    ) -> Result<
+        SealedVote<T::AccountId, ElectionStake<T>, T::Hash, Ballot<T::MemberId>>,
+        &'static str,
+    > {
+        ensure!(<Votes<T>>::contains_key(commitment), "commitment not found");
 
-        let mut sealed_vote = <Votes<T>>::get(&commitment);
+        let sealed_vote = <Votes<T>>::get(commitment);
 
         ensure!(sealed_vote.is_not_revealed(), "vote already revealed");
         // only voter can reveal their own votes
         ensure!(sealed_vote.is_owned_by(voter), "only voter can reveal vote");
+
+        Ok(sealed_vote)
+    }
+
+    fn try_reveal_vote(
+        voter: T::AccountId,
+        commitment: T::Hash,
+        vote_for: T::MemberId,
+        salt: Vec<u8>,
+    ) -> DispatchResult {
+        let mut sealed_vote = Self::ensure_vote_can_be_revealed(voter, &commitment)?;
+
         ensure!(
             <ApplicantStakes<T>>::contains_key(&vote_for),
             "vote for non-applicant not allowed"
@@ -789,9 +1130,11 @@ impl<T: Trait> Module<T> {
 
         let mut salt = salt;
 
-        // Tries to unseal, if salt is invalid will return error
-        sealed_vote.unseal(
-            vote_for,
+        // Tries to unseal, if salt is invalid will return error.
+        // A single vote is committed as the bare applicant id.
+        sealed_vote.unseal_from(
+            &vote_for,
+            vec![vote_for],
             &mut salt,
             <T as frame_system::Trait>::Hashing::hash,
         )?;
@@ -802,6 +1145,38 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn try_reveal_ballot(
+        voter: T::AccountId,
+        commitment: T::Hash,
+        ballot: Ballot<T::MemberId>,
+        salt: Vec<u8>,
+    ) -> DispatchResult {
+        let mut sealed_vote = Self::ensure_vote_can_be_revealed(voter, &commitment)?;
+
+        ensure!(!ballot.is_empty(), "ballot is empty");
+
+        for (index, applicant) in ballot.iter().enumerate() {
+            ensure!(
+                <ApplicantStakes<T>>::contains_key(applicant),
+                "vote for non-applicant not allowed"
+            );
+            ensure!(
+                !ballot[..index].contains(applicant),
+                "applicant listed twice in ballot"
+            );
+        }
+
+        let mut salt = salt;
+
+        // Tries to unseal, if salt is invalid will return error
+        sealed_vote.unseal(ballot, &mut salt, <T as frame_system::Trait>::Hashing::hash)?;
+
+        // Update the revealed vote
+        <Votes<T>>::insert(commitment, sealed_vote);
+
+        Ok(())
+    }
+
     fn set_verified_election_parameters(params: ElectionParameters<BalanceOf<T>, T::BlockNumber>) {
         <AnnouncingPeriod<T>>::put(params.announcing_period);
         <VotingPeriod<T>>::put(params.voting_period);
@@ -811,6 +1186,7 @@ impl<T: Trait> Module<T> {
         CouncilSize::put(params.council_size);
         CandidacyLimit::put(params.candidacy_limit);
         <MinVotingStake<T>>::put(params.min_voting_stake);
+        TallyMode::put(params.tally_mode);
     }
 }
 
@@ -821,15 +1197,28 @@ decl_module! {
         // No origin so this is a priviledged call
         fn on_finalize(now: T::BlockNumber) {
             Self::check_if_stage_is_ending(now);
+
+            if Self::by_election_queued() && !Self::is_election_running() {
+                ByElectionQueued::kill();
+                Self::try_start_by_election();
+            }
         }
 
         // Member can apply during announcing stage only. On first call a minimum stake will need to be provided.
         // Member can make subsequent calls during announcing stage to increase their stake.
+        // The stake is locked on the member controller account.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn apply(origin, stake: BalanceOf<T>) {
-            let sender = ensure_signed(origin)?;
+        pub fn apply(origin, applicant_id: T::MemberId, stake: BalanceOf<T>) {
+            let sender = T::MembershipOriginValidator::ensure_actor_origin(origin, applicant_id)?;
             ensure!(Self::can_participate(&sender), "Only members can apply to be on council");
 
+            if Self::by_election_seats().is_some() {
+                ensure!(
+                    !<council::Module<T>>::is_councilor(&applicant_id),
+                    "councilors cannot apply in a by-election"
+                );
+            }
+
             let stage = Self::stage();
             ensure!(Self::stage().is_some(), "election not running");
 
@@ -840,13 +1229,13 @@ decl_module! {
             ensure!(is_announcing, "election not in announcing stage");
 
             // minimum stake on first attempt to apply
-            if !<ApplicantStakes<T>>::contains_key(&sender) {
+            if !<ApplicantStakes<T>>::contains_key(&applicant_id) {
                 ensure!(stake >= Self::min_council_stake(), "minimum stake must be provided");
             }
 
-            Self::try_add_applicant(sender.clone(), stake)?;
+            Self::try_add_applicant(applicant_id, sender, stake)?;
 
-            Self::deposit_event(RawEvent::Applied(sender));
+            Self::deposit_event(RawEvent::Applied(applicant_id));
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
@@ -869,7 +1258,7 @@ decl_module! {
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
-        fn reveal(origin, commitment: T::Hash, vote: T::AccountId, salt: Vec<u8>) {
+        fn reveal(origin, commitment: T::Hash, vote: T::MemberId, salt: Vec<u8>) {
             let sender = ensure_signed(origin)?;
 
             ensure!(salt.len() <= 32, "salt too large"); // at most 256 bits salt
@@ -883,10 +1272,56 @@ decl_module! {
             };
             ensure!(is_revealing, "election not in revealing stage");
 
-            Self::try_reveal_vote(sender.clone(), commitment, vote.clone(), salt)?;
+            Self::try_reveal_vote(sender.clone(), commitment, vote, salt)?;
             Self::deposit_event(RawEvent::Revealed(sender, commitment, vote));
         }
 
+        /// Reveals a ranked approval ballot: the applicants approved by the vote, most preferred
+        /// first. The commitment must be the hash of the encoded ballot followed by the salt.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn reveal_ballot(origin, commitment: T::Hash, ballot: Ballot<T::MemberId>, salt: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            ensure!(salt.len() <= 32, "salt too large"); // at most 256 bits salt
+
+            let stage = Self::stage();
+            ensure!(Self::stage().is_some(), "election not running");
+
+            let is_revealing = match stage.unwrap() {
+                ElectionStage::Revealing(_) => true,
+                _ => false
+            };
+            ensure!(is_revealing, "election not in revealing stage");
+
+            Self::try_reveal_ballot(sender.clone(), commitment, ballot.clone(), salt)?;
+            Self::deposit_event(RawEvent::BallotRevealed(sender, commitment, ballot));
+        }
+
+        /// Delegates the vote of the sender to a representative, replacing any previous delegation.
+        /// Votes the sender doesn't reveal fall back to the ballot revealed by the representative.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn delegate_vote(origin, delegate: T::AccountId) {
+            let sender = ensure_signed(origin)?;
+            ensure!(<membership::Module<T>>::is_member_account(&sender), "only members can delegate their vote");
+            ensure!(<membership::Module<T>>::is_member_account(&delegate), "vote can be delegated to members only");
+            ensure!(sender != delegate, "cannot delegate vote to self");
+
+            <VoteDelegations<T>>::insert(&sender, delegate.clone());
+
+            Self::deposit_event(RawEvent::VoteDelegated(sender, delegate));
+        }
+
+        /// Revokes the vote delegation of the sender. Takes effect in the running election as well.
+        #[weight = 10_000_000] // TODO: adjust weight
+        fn revoke_vote_delegation(origin) {
+            let sender = ensure_signed(origin)?;
+            ensure!(Self::vote_delegation(&sender).is_some(), "vote is not delegated");
+
+            <VoteDelegations<T>>::remove(&sender);
+
+            Self::deposit_event(RawEvent::VoteDelegationRevoked(sender));
+        }
+
         #[weight = 10_000_000] // TODO: adjust weight
         fn set_stage_announcing(origin, ends_at: T::BlockNumber) {
             ensure_root(origin)?;
@@ -926,19 +1361,7 @@ decl_module! {
             ensure_root(origin)?;
             ensure!(Self::is_election_running(), "only running election can be stopped");
 
-            let mut votes = Vec::new();
-            for commitment in Self::commitments() {
-                votes.push(Self::votes(commitment));
-            }
-
-            // no council gets elected
-            let empty_council = BTreeMap::new();
-
-            Self::teardown_election (
-                &votes,
-                &empty_council,
-                false /* do not unlock transferable stakes */
-            );
+            Self::stop_election();
         }
 
         #[weight = 10_000_000] // TODO: adjust weight
@@ -957,12 +1380,30 @@ decl_module! {
 
 impl<T: Trait> council::CouncilTermEnded for Module<T> {
     fn council_term_ended() {
+        // The regular election fills all the seats, including the vacant ones.
+        if Self::by_election_seats().is_some() {
+            Self::stop_election();
+        }
+        ByElectionQueued::kill();
+
         if Self::auto_start() {
             let _ = Self::start_election(<council::Module<T>>::active_council());
         }
     }
 }
 
+impl<T: Trait> council::CouncilSeatVacated for Module<T> {
+    fn council_seat_vacated() {
+        if Self::by_election_seats().is_some() {
+            // The running by-election fills only the seats vacant at its start.
+            ByElectionQueued::put(true);
+        } else if !Self::is_election_running() {
+            Self::try_start_by_election();
+        }
+        // A running regular election fills all the seats, including the vacated one.
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1070,6 +1511,7 @@ mod tests {
             let existing_council = vec![
                 Seat {
                     member: 1,
+                    account: 1,
                     stake: 100,
                     backers: vec![
                         Backer {
@@ -1088,6 +1530,7 @@ mod tests {
                 },
                 Seat {
                     member: 2,
+                    account: 2,
                     stake: 200,
                     backers: vec![
                         Backer {
@@ -1106,6 +1549,7 @@ mod tests {
                 },
                 Seat {
                     member: 3,
+                    account: 3,
                     stake: 300,
                     backers: vec![
                         Backer {
@@ -1154,13 +1598,36 @@ mod tests {
 
             let stake = 100 as u64;
 
-            assert!(Election::try_add_applicant(applicant, stake).is_ok());
+            assert!(Election::try_add_applicant(applicant, applicant, stake).is_ok());
             assert_eq!(Election::applicants(), vec![applicant]);
+            assert_eq!(Election::applicant_account(applicant), applicant);
 
             assert_eq!(Election::applicant_stakes(applicant).new, stake);
             assert_eq!(Election::applicant_stakes(applicant).transferred, 0);
 
-            assert_eq!(Balances::free_balance(&applicant), starting_balance - stake);
+            assert_eq!(Balances::free_balance(&applicant), starting_balance);
+            assert_eq!(Council::candidacy_locked_balance(&applicant), stake);
+        });
+    }
+
+    #[test]
+    fn applying_requires_member_controller_origin() {
+        initial_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            <MinCouncilStake<Test>>::put(50);
+            let _ = Balances::deposit_creating(&1, 1000);
+            let _ = Balances::deposit_creating(&2, 1000);
+
+            assert_ok!(Election::start_election(vec![]));
+
+            // account 2 doesn't control member 1
+            assert!(Election::apply(Origin::signed(2), 1, 100).is_err());
+
+            assert_ok!(Election::apply(Origin::signed(1), 1, 100));
+            assert_eq!(Election::applicants(), vec![1]);
+            assert_eq!(Election::applicant_account(1), 1);
+            assert_eq!(Balances::reserved_balance(&1), 0);
+            assert_eq!(Council::candidacy_locked_balance(&1), 100);
         });
     }
 
@@ -1181,7 +1648,7 @@ mod tests {
 
             let additional_stake = 100 as u64;
             let _ = Balances::deposit_creating(&applicant, additional_stake);
-            assert!(Election::try_add_applicant(applicant, additional_stake).is_ok());
+            assert!(Election::try_add_applicant(applicant, applicant, additional_stake).is_ok());
 
             assert_eq!(
                 Election::applicant_stakes(applicant).new,
@@ -1214,7 +1681,7 @@ mod tests {
             <ApplicantStakes<Test>>::insert(applicant, starting_stake);
 
             // transferable stake covers new stake
-            assert!(Election::try_add_applicant(applicant, 600).is_ok());
+            assert!(Election::try_add_applicant(applicant, applicant, 600).is_ok());
             assert_eq!(
                 Election::applicant_stakes(applicant).new,
                 starting_stake.new
@@ -1224,14 +1691,15 @@ mod tests {
             assert_eq!(Balances::free_balance(applicant), 5000);
 
             // all remaining transferable stake is consumed and free balance covers remaining stake
-            assert!(Election::try_add_applicant(applicant, 1000).is_ok());
+            assert!(Election::try_add_applicant(applicant, applicant, 1000).is_ok());
             assert_eq!(
                 Election::applicant_stakes(applicant).new,
                 starting_stake.new + 600
             );
             assert_eq!(Election::applicant_stakes(applicant).transferred, 1000);
             assert_eq!(Election::transferable_stakes(applicant).seat, 0);
-            assert_eq!(Balances::free_balance(applicant), 4400);
+            assert_eq!(Balances::free_balance(applicant), 5000);
+            assert_eq!(Council::candidacy_locked_balance(applicant), 600);
         });
     }
 
@@ -1319,11 +1787,14 @@ mod tests {
         initial_test_ext().execute_with(|| {
             let _ = Balances::deposit_creating(&1, 1000);
             let _ = Balances::deposit_creating(&2, 7000);
-            let _ = Balances::reserve(&2, 5000);
+            Council::lock_candidacy_stake(&2, 5000);
             let _ = Balances::deposit_creating(&3, 8000);
-            let _ = Balances::reserve(&3, 5000);
+            Council::lock_candidacy_stake(&3, 5000);
 
             <Applicants<Test>>::put(vec![1, 2, 3]);
+            <ApplicantAccounts<Test>>::insert(1, 1);
+            <ApplicantAccounts<Test>>::insert(2, 2);
+            <ApplicantAccounts<Test>>::insert(3, 3);
 
             save_transferable_stake(
                 1,
@@ -1382,13 +1853,16 @@ mod tests {
 
             //assert_eq!(Election::applicant_stakes(2), Default::default());
             assert!(!<ApplicantStakes<Test>>::contains_key(2));
+            assert!(!<ApplicantAccounts<Test>>::contains_key(2));
             assert_eq!(Election::transferable_stakes(2).seat, 400);
-            assert_eq!(Balances::free_balance(&2), 2300);
+            assert_eq!(Balances::free_balance(&2), 7000);
+            assert_eq!(Council::candidacy_locked_balance(&2), 4700);
 
             //assert_eq!(Election::applicant_stakes(3), Default::default());
             assert!(!<ApplicantStakes<Test>>::contains_key(3));
             assert_eq!(Election::transferable_stakes(3).seat, 600);
-            assert_eq!(Balances::free_balance(&3), 3500);
+            assert_eq!(Balances::free_balance(&3), 8000);
+            assert_eq!(Council::candidacy_locked_balance(&3), 4500);
         });
     }
 
@@ -1509,7 +1983,7 @@ mod tests {
     }
 
     fn make_commitment_for_applicant(
-        applicant: <Test as frame_system::Trait>::AccountId,
+        applicant: <Test as membership::Trait>::MemberId,
         salt: &mut Vec<u8>,
     ) -> <Test as frame_system::Trait>::Hash {
         let mut payload = applicant.encode();
@@ -1549,7 +2023,7 @@ mod tests {
             assert!(Election::try_reveal_vote(voter, commitment, applicant, salt).is_ok());
             assert_eq!(
                 <Votes<Test>>::get(commitment).get_vote().unwrap(),
-                applicant
+                vec![applicant]
             );
         });
     }
@@ -1671,7 +2145,7 @@ mod tests {
 
     pub fn mock_votes(
         mock: Vec<(u64, u64, u64, u64)>,
-    ) -> Vec<SealedVote<u64, Stake<u64>, sp_core::H256, u64>> {
+    ) -> Vec<SealedVote<u64, Stake<u64>, sp_core::H256, Vec<u64>>> {
         let commitment = make_commitment_for_applicant(1, &mut vec![0u8]);
 
         mock.into_iter()
@@ -1683,12 +2157,206 @@ mod tests {
                         transferred: stake_tran,
                     },
                     commitment,
-                    applicant as u64,
+                    vec![applicant as u64],
                 )
             })
             .collect()
     }
 
+    fn make_commitment_for_ballot(
+        ballot: Vec<<Test as membership::Trait>::MemberId>,
+        salt: &mut Vec<u8>,
+    ) -> <Test as frame_system::Trait>::Hash {
+        let mut payload = ballot.encode();
+        payload.append(salt);
+        <Test as frame_system::Trait>::Hashing::hash(&payload[..])
+    }
+
+    fn mock_ballots(
+        mock: Vec<(u64, u64, Vec<u64>)>,
+    ) -> Vec<SealedVote<u64, Stake<u64>, sp_core::H256, Vec<u64>>> {
+        let commitment = make_commitment_for_applicant(1, &mut vec![0u8]);
+
+        mock.into_iter()
+            .map(|(voter, stake, ballot)| {
+                SealedVote::new_unsealed(
+                    voter,
+                    Stake {
+                        new: stake,
+                        transferred: 0,
+                    },
+                    commitment,
+                    ballot,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn revealing_ballot_works() {
+        initial_test_ext().execute_with(|| {
+            let ballot = vec![20, 30];
+            let salt = vec![128u8];
+            let commitment = make_commitment_for_ballot(ballot.clone(), &mut salt.clone());
+            let voter = 10 as u64;
+
+            for applicant in ballot.iter() {
+                <ApplicantStakes<Test>>::insert(
+                    applicant,
+                    Stake {
+                        new: 0,
+                        transferred: 0,
+                    },
+                );
+            }
+
+            <Votes<Test>>::insert(
+                &commitment,
+                SealedVote::new(
+                    voter,
+                    Stake {
+                        new: 100,
+                        transferred: 0,
+                    },
+                    commitment,
+                ),
+            );
+
+            // applicants must be listed once
+            assert!(
+                Election::try_reveal_ballot(voter, commitment, vec![20, 30, 20], salt.clone())
+                    .is_err()
+            );
+            // and must all be applying
+            assert!(
+                Election::try_reveal_ballot(voter, commitment, vec![20, 40], salt.clone()).is_err()
+            );
+            assert!(Election::try_reveal_ballot(voter, commitment, vec![], salt.clone()).is_err());
+
+            assert!(Election::try_reveal_ballot(voter, commitment, ballot.clone(), salt).is_ok());
+            assert_eq!(<Votes<Test>>::get(commitment).get_vote().unwrap(), ballot);
+        });
+    }
+
+    #[test]
+    fn delegating_vote_works() {
+        initial_test_ext().execute_with(|| {
+            assert_err!(
+                Election::delegate_vote(Origin::signed(10), 10),
+                "cannot delegate vote to self"
+            );
+            assert_err!(
+                Election::delegate_vote(Origin::signed(10), 1000),
+                "vote can be delegated to members only"
+            );
+            assert_err!(
+                Election::revoke_vote_delegation(Origin::signed(10)),
+                "vote is not delegated"
+            );
+
+            assert_ok!(Election::delegate_vote(Origin::signed(10), 11));
+            assert_eq!(Election::vote_delegation(10), Some(11));
+
+            assert_ok!(Election::delegate_vote(Origin::signed(10), 12));
+            assert_eq!(Election::vote_delegation(10), Some(12));
+
+            assert_ok!(Election::revoke_vote_delegation(Origin::signed(10)));
+            assert_eq!(Election::vote_delegation(10), None);
+        });
+    }
+
+    #[test]
+    fn unrevealed_votes_fall_back_to_delegate_ballot() {
+        initial_test_ext().execute_with(|| {
+            let mut votes = mock_ballots(vec![(10, 100, vec![20, 30])]);
+            let commitment = make_commitment_for_applicant(1, &mut vec![0u8]);
+            for voter in [11, 12, 13].iter() {
+                votes.push(SealedVote::new(
+                    *voter,
+                    Stake {
+                        new: 50,
+                        transferred: 0,
+                    },
+                    commitment,
+                ));
+            }
+
+            // 11 follows the ballot of 10, 12 delegated to an account that didn't vote,
+            // 13 didn't delegate
+            assert_ok!(Election::delegate_vote(Origin::signed(11), 10));
+            assert_ok!(Election::delegate_vote(Origin::signed(12), 14));
+
+            Election::cast_delegated_votes(&mut votes);
+
+            assert_eq!(votes[1].get_vote(), &Some(vec![20, 30]));
+            assert!(votes[2].is_not_revealed());
+            assert!(votes[3].is_not_revealed());
+        });
+    }
+
+    #[test]
+    fn sequential_phragmen_tally_should_work() {
+        initial_test_ext().execute_with(|| {
+            CouncilSize::put(3);
+            TallyMode::put(ElectionTallyMode::SequentialPhragmen);
+
+            let applicants = vec![1, 2, 3, 4];
+            <Applicants<Test>>::put(applicants.clone());
+            for applicant in applicants {
+                <ApplicantStakes<Test>>::insert(
+                    applicant,
+                    Stake {
+                        new: 0,
+                        transferred: 0,
+                    },
+                );
+                <ApplicantAccounts<Test>>::insert(applicant, applicant);
+            }
+
+            let votes = mock_ballots(vec![
+                //  (voter, stake, ballot)
+                (10, 600, vec![1, 2]),
+                (20, 350, vec![3]),
+                (30, 50, vec![4, 2]),
+            ]);
+
+            let new_council = Election::tally_sequential_phragmen(&votes);
+
+            assert_eq!(
+                new_council.keys().cloned().collect::<Vec<u64>>(),
+                vec![1, 2, 3]
+            );
+
+            // each vote backs the most preferred elected applicant of its ballot
+            assert_eq!(
+                new_council.get(&1).unwrap().backers,
+                vec![Backer {
+                    member: 10,
+                    stake: 600
+                }]
+            );
+            assert_eq!(
+                new_council.get(&2).unwrap().backers,
+                vec![Backer {
+                    member: 30,
+                    stake: 50
+                }]
+            );
+            assert_eq!(
+                new_council.get(&3).unwrap().backers,
+                vec![Backer {
+                    member: 20,
+                    stake: 350
+                }]
+            );
+
+            // only the first choice counts in the top staked mode
+            TallyMode::put(ElectionTallyMode::TopStaked);
+            assert_eq!(Election::backed_applicant(&[4, 2], &new_council), None);
+            assert_eq!(Election::backed_applicant(&[1, 2], &new_council), Some(1));
+        });
+    }
+
     #[test]
     fn vote_tallying_should_work() {
         initial_test_ext().execute_with(|| {
@@ -1801,9 +2469,10 @@ mod tests {
     fn drop_unelected_applicants_should_work() {
         initial_test_ext().execute_with(|| {
             <Applicants<Test>>::put(vec![100, 200, 300]);
+            <ApplicantAccounts<Test>>::insert(100, 100);
 
             let _ = Balances::deposit_creating(&100, 2000);
-            let _ = Balances::reserve(&100, 1000);
+            Council::lock_candidacy_stake(&100, 1000);
 
             <ApplicantStakes<Test>>::insert(
                 100,
@@ -1821,11 +2490,12 @@ mod tests {
                 },
             );
 
-            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            let mut new_council: BTreeMap<u64, Seat<u64, u64, u64>> = BTreeMap::new();
             new_council.insert(
                 200 as u64,
                 Seat {
                     member: 200 as u64,
+                    account: 200 as u64,
                     stake: 0 as u64,
                     backers: vec![],
                 },
@@ -1834,6 +2504,7 @@ mod tests {
                 300 as u64,
                 Seat {
                     member: 300 as u64,
+                    account: 300 as u64,
                     stake: 0 as u64,
                     backers: vec![],
                 },
@@ -1847,8 +2518,8 @@ mod tests {
 
             // and refunded
             assert_eq!(Election::transferable_stakes(100).seat, 150);
-            assert_eq!(Balances::free_balance(&100), 1020);
-            assert_eq!(Balances::reserved_balance(&100), 980);
+            assert_eq!(Balances::free_balance(&100), 2000);
+            assert_eq!(Council::candidacy_locked_balance(&100), 980);
         });
     }
 
@@ -1898,11 +2569,12 @@ mod tests {
                 (30, 1000, 140, 300),
             ]);
 
-            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            let mut new_council: BTreeMap<u64, Seat<u64, u64, u64>> = BTreeMap::new();
             new_council.insert(
                 200 as u64,
                 Seat {
                     member: 200 as u64,
+                    account: 200 as u64,
                     stake: 0 as u64,
                     backers: vec![],
                 },
@@ -1911,6 +2583,7 @@ mod tests {
                 300 as u64,
                 Seat {
                     member: 300 as u64,
+                    account: 300 as u64,
                     stake: 0 as u64,
                     backers: vec![],
                 },
@@ -1938,6 +2611,7 @@ mod tests {
 
             let _ = Balances::deposit_creating(&10, 6000);
             let _ = Balances::reserve(&10, 5000);
+            Council::lock_candidacy_stake(&10, 50);
             save_transferable_stake(
                 10,
                 TransferableStake {
@@ -1948,6 +2622,7 @@ mod tests {
 
             let _ = Balances::deposit_creating(&20, 7000);
             let _ = Balances::reserve(&20, 5000);
+            Council::lock_candidacy_stake(&20, 60);
             save_transferable_stake(
                 20,
                 TransferableStake {
@@ -1958,6 +2633,7 @@ mod tests {
 
             let _ = Balances::deposit_creating(&30, 8000);
             let _ = Balances::reserve(&30, 5000);
+            Council::lock_candidacy_stake(&30, 70);
             save_transferable_stake(
                 30,
                 TransferableStake {
@@ -1968,20 +2644,25 @@ mod tests {
 
             Election::unlock_transferable_stakes();
 
-            assert_eq!(Balances::free_balance(&10), 1150);
-            assert_eq!(Balances::free_balance(&20), 2260);
-            assert_eq!(Balances::free_balance(&30), 3370);
+            assert_eq!(Balances::free_balance(&10), 1100);
+            assert_eq!(Balances::free_balance(&20), 2200);
+            assert_eq!(Balances::free_balance(&30), 3300);
+
+            assert_eq!(Council::candidacy_locked_balance(&10), 0);
+            assert_eq!(Council::candidacy_locked_balance(&20), 0);
+            assert_eq!(Council::candidacy_locked_balance(&30), 0);
         });
     }
 
     #[test]
     fn council_elected_hook_should_work() {
         initial_test_ext().execute_with(|| {
-            let mut new_council: BTreeMap<u64, Seat<u64, u64>> = BTreeMap::new();
+            let mut new_council: BTreeMap<u64, Seat<u64, u64, u64>> = BTreeMap::new();
             new_council.insert(
                 200 as u64,
                 Seat {
                     member: 200 as u64,
+                    account: 200 as u64,
                     stake: 10 as u64,
                     backers: vec![],
                 },
@@ -1990,6 +2671,7 @@ mod tests {
                 300 as u64,
                 Seat {
                     member: 300 as u64,
+                    account: 300 as u64,
                     stake: 20 as u64,
                     backers: vec![],
                 },
@@ -2007,6 +2689,127 @@ mod tests {
         });
     }
 
+    #[test]
+    fn by_election_fills_vacant_seats() {
+        initial_test_ext().execute_with(|| {
+            CouncilSize::put(2);
+            CandidacyLimit::put(0);
+            <MinCouncilStake<Test>>::put(10);
+            <AnnouncingPeriod<Test>>::put(10);
+            <VotingPeriod<Test>>::put(10);
+            <RevealingPeriod<Test>>::put(10);
+            <NewTermDuration<Test>>::put(100);
+
+            for i in 1..4 {
+                let _ = Balances::deposit_creating(&(i as u64), 1000);
+            }
+
+            System::set_block_number(1);
+            Council::council_elected(
+                vec![Seat {
+                    member: 1,
+                    account: 1,
+                    stake: 0,
+                    backers: vec![],
+                }],
+                100,
+            );
+            let term_ends_at = Council::term_ends_at();
+
+            <Election as council::CouncilSeatVacated>::council_seat_vacated();
+
+            assert_eq!(Election::by_election_seats(), Some(1));
+            assert_announcing_period(1 + Election::announcing_period());
+
+            assert_err!(
+                Election::apply(Origin::signed(1), 1, 20),
+                "councilors cannot apply in a by-election"
+            );
+            assert_ok!(Election::apply(Origin::signed(2), 2, 20));
+            assert_ok!(Election::apply(Origin::signed(3), 3, 30));
+
+            // only the top staked applicant for the single vacant seat moves to voting
+            let n = 1 + Election::announcing_period();
+            System::set_block_number(n);
+            let _ = Election::on_finalize(n);
+            assert_eq!(Election::applicants(), vec![3]);
+
+            let n = n + Election::voting_period();
+            System::set_block_number(n);
+            let _ = Election::on_finalize(n);
+
+            let n = n + Election::revealing_period();
+            System::set_block_number(n);
+            let _ = Election::on_finalize(n);
+
+            // the elected applicant joins the sitting council for the rest of its term
+            let members: Vec<u64> = Council::active_council()
+                .iter()
+                .map(|seat| seat.member)
+                .collect();
+            assert_eq!(members, vec![1, 3]);
+            assert_eq!(Council::term_ends_at(), term_ends_at);
+            assert_eq!(Election::by_election_seats(), None);
+            assert!(Election::stage().is_none());
+        });
+    }
+
+    #[test]
+    fn by_election_is_queued_while_by_election_is_running() {
+        initial_test_ext().execute_with(|| {
+            CouncilSize::put(3);
+            <AnnouncingPeriod<Test>>::put(10);
+
+            let seat = |member| Seat {
+                member,
+                account: member,
+                stake: 0,
+                backers: vec![],
+            };
+
+            System::set_block_number(1);
+            Council::council_elected(vec![seat(1), seat(2)], 100);
+
+            <Election as council::CouncilSeatVacated>::council_seat_vacated();
+            assert_eq!(Election::by_election_seats(), Some(1));
+
+            // a second seat is vacated while the by-election is running
+            Council::council_elected(vec![seat(1)], 100);
+            <Election as council::CouncilSeatVacated>::council_seat_vacated();
+            assert_eq!(Election::by_election_seats(), Some(1));
+            assert!(Election::by_election_queued());
+
+            assert_ok!(Election::force_stop_election(RawOrigin::Root.into()));
+            let _ = Election::on_finalize(2);
+
+            // the queued by-election fills both vacant seats
+            assert!(!Election::by_election_queued());
+            assert_eq!(Election::by_election_seats(), Some(2));
+            assert!(Election::is_election_running());
+        });
+    }
+
+    #[test]
+    fn by_election_is_stopped_when_council_term_ends() {
+        initial_test_ext().execute_with(|| {
+            CouncilSize::put(0);
+            <AnnouncingPeriod<Test>>::put(10);
+
+            System::set_block_number(1);
+            assert_err!(Election::start_by_election(), "no vacant council seats");
+            CouncilSize::put(2);
+
+            assert_ok!(Election::start_by_election());
+            assert_eq!(Election::by_election_seats(), Some(2));
+
+            <Election as council::CouncilTermEnded>::council_term_ended();
+
+            // the regular election replaces the by-election
+            assert_eq!(Election::by_election_seats(), None);
+            assert_announcing_period(1 + Election::announcing_period());
+        });
+    }
+
     #[test]
     fn simulation() {
         initial_test_ext().execute_with(|| {
@@ -2031,10 +2834,12 @@ mod tests {
 
             for i in 1..20 {
                 if i < 21 {
-                    assert!(Election::apply(Origin::signed(i), 150).is_ok());
+                    assert!(Election::apply(Origin::signed(i), i, 150).is_ok());
                 } else {
-                    assert!(Election::apply(Origin::signed(i + 1000), 150).is_err()); // not enough free balance
-                    assert!(Election::apply(Origin::signed(i), 20).is_err()); // not enough minimum stake
+                    // not enough free balance
+                    assert!(Election::apply(Origin::signed(i + 1000), i + 1000, 150).is_err());
+                    // not enough minimum stake
+                    assert!(Election::apply(Origin::signed(i), i, 20).is_err());
                 }
             }
 
@@ -2129,6 +2934,7 @@ mod tests {
                 min_voting_stake: 6,
                 min_council_stake: 7,
                 new_term_duration: 8,
+                tally_mode: ElectionTallyMode::SequentialPhragmen,
             };
 
             assert_ok!(Election::set_election_parameters(
@@ -2159,6 +2965,64 @@ mod tests {
                 <MinVotingStake<Test>>::get(),
                 new_parameters.min_voting_stake
             );
+            assert_eq!(TallyMode::get(), new_parameters.tally_mode);
+        });
+    }
+
+    #[test]
+    fn migrate_election_in_progress_refunds_stakes() {
+        initial_test_ext().execute_with(|| {
+            let applicant = 1;
+            let voter = 2;
+
+            let _ = Balances::deposit_creating(&applicant, 1000);
+            let _ = Balances::reserve(&applicant, 100);
+            let _ = Balances::deposit_creating(&voter, 1000);
+            let _ = Balances::reserve(&voter, 200);
+
+            // Account based election storage of the previous runtime version
+            migration::put_storage_value(b"CouncilElection", b"Applicants", &[], vec![applicant]);
+            migration::put_storage_value(
+                b"CouncilElection",
+                b"ApplicantStakes",
+                &Blake2_128Concat::hash(&applicant.encode()),
+                Stake {
+                    new: 100,
+                    transferred: 0,
+                },
+            );
+
+            let commitment = <Test as frame_system::Trait>::Hash::default();
+            let sealed_vote = SealedVote::<_, _, _, u64>::new(
+                voter,
+                Stake {
+                    new: 200,
+                    transferred: 0,
+                },
+                commitment,
+            );
+            migration::put_storage_value(
+                b"CouncilElection",
+                b"Votes",
+                &Blake2_128Concat::hash(&commitment.encode()),
+                sealed_vote,
+            );
+            <Commitments<Test>>::put(vec![commitment]);
+
+            <Stage<Test>>::put(ElectionStage::Voting(10));
+
+            Election::migrate_election_in_progress();
+
+            assert!(!Election::is_election_running());
+            assert!(Election::applicants().is_empty());
+            assert!(Election::commitments().is_empty());
+            assert!(!<ApplicantStakes<Test>>::contains_key(applicant));
+            assert!(!<Votes<Test>>::contains_key(commitment));
+
+            assert_eq!(Balances::free_balance(&applicant), 1000);
+            assert_eq!(Balances::reserved_balance(&applicant), 0);
+            assert_eq!(Balances::free_balance(&voter), 1000);
+            assert_eq!(Balances::reserved_balance(&voter), 0);
         });
     }
 }
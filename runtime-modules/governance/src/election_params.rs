@@ -12,6 +12,25 @@ pub static MSG_COUNCIL_SIZE_CANNOT_BE_ZERO: &str = "CouncilSizeCannotBeZero";
 pub static MSG_CANDIDACY_LIMIT_WAS_LOWER_THAN_COUNCIL_SIZE: &str =
     "CandidacyWasLessThanCouncilSize";
 
+/// Algorithm used to pick the new council from the applicants and revealed votes.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, Debug)]
+pub enum ElectionTallyMode {
+    /// Applicants with the highest total backing take the seats. Only the first choice of each
+    /// ballot is counted.
+    TopStaked,
+
+    /// Seats are assigned by sequential Phragmén over the approval ballots, which gives
+    /// minority stakeholder groups a share of the council proportional to their backing.
+    SequentialPhragmen,
+}
+
+impl Default for ElectionTallyMode {
+    fn default() -> Self {
+        ElectionTallyMode::TopStaked
+    }
+}
+
 /// Combined Election parameters, as argument for set_election_parameters
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Encode, Decode, Default, PartialEq, Debug)]
@@ -24,6 +43,7 @@ pub struct ElectionParameters<Balance, BlockNumber> {
     pub new_term_duration: BlockNumber,
     pub min_council_stake: Balance,
     pub min_voting_stake: Balance,
+    pub tally_mode: ElectionTallyMode,
 }
 
 impl<Balance, BlockNumber: PartialOrd + Zero> ElectionParameters<Balance, BlockNumber> {
@@ -4,7 +4,9 @@
 pub mod council;
 pub mod election;
 pub mod election_params;
+pub mod runtime_api;
 
+mod phragmen;
 mod sealed_vote;
 mod stake;
 
@@ -3,7 +3,7 @@
 pub use super::{council, election};
 pub use common::currency::GovernanceCurrency;
 
-use frame_support::{impl_outer_origin, parameter_types};
+use frame_support::{ensure, impl_outer_origin, parameter_types};
 pub use frame_system;
 use sp_core::H256;
 use sp_runtime::{
@@ -61,15 +61,25 @@ impl pallet_timestamp::Trait for Test {
     type MinimumPeriod = MinimumPeriod;
     type WeightInfo = ();
 }
+parameter_types! {
+    pub const CouncilLockId: [u8; 8] = *b"council ";
+}
+
 impl council::Trait for Test {
     type Event = ();
 
     type CouncilTermEnded = (Election,);
+
+    type CouncilSeatVacated = (Election,);
+
+    type CouncilLockId = CouncilLockId;
 }
 impl election::Trait for Test {
     type Event = ();
 
     type CouncilElected = (Council,);
+
+    type MembershipOriginValidator = ();
 }
 
 parameter_types! {
@@ -83,15 +93,47 @@ impl membership::Trait for Test {
     type PaidTermId = u32;
     type ActorId = u32;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator = ();
 }
+
+impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
+    fn ensure_actor_origin(origin: Origin, member_id: u64) -> Result<u64, &'static str> {
+        let account_id = frame_system::ensure_signed(origin)?;
+
+        let membership = membership::Module::<Test>::ensure_membership(member_id)?;
+
+        ensure!(
+            membership.controller_account == account_id,
+            "Membership validation failed: given account doesn't match with profile accounts"
+        );
+
+        Ok(account_id)
+    }
+}
+
+impl common::origin::ActorOriginValidator<Origin, u32, u64> for () {
+    fn ensure_actor_origin(_: Origin, _: u32) -> Result<u64, &'static str> {
+        Err("Actor origin validation is not supported")
+    }
+}
+parameter_types! {
+    pub const MaxTransferLogLength: u32 = 10;
+}
+
 impl minting::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type MintId = u64;
+    type MaxTransferLogLength = MaxTransferLogLength;
+}
+parameter_types! {
+    pub const CatchUpPayoutLimit: u64 = 1000;
 }
 impl recurringrewards::Trait for Test {
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type CatchUpPayoutLimit = CatchUpPayoutLimit;
 }
 parameter_types! {
     pub const ExistentialDeposit: u32 = 0;
@@ -122,29 +164,10 @@ pub fn initial_test_ext() -> sp_io::TestExternalities {
 
     let members_config_builder = membership::genesis::GenesisConfigBuilder::<Test>::default()
         .default_paid_membership_fee(0)
-        .members(vec![
-            // member_id, account_id
-            (0, 1),
-            (1, 2),
-            (2, 3),
-            (3, 4),
-            (4, 5),
-            (5, 6),
-            (6, 7),
-            (7, 8),
-            (8, 9),
-            (9, 10),
-            (10, 11),
-            (11, 12),
-            (12, 13),
-            (13, 14),
-            (14, 15),
-            (15, 16),
-            (16, 17),
-            (17, 18),
-            (18, 19),
-            (19, 20),
-        ]);
+        .members(
+            // member_id equals account_id
+            (0..=20).map(|id| (id, id)).collect(),
+        );
 
     members_config_builder
         .build()
@@ -0,0 +1,111 @@
+//! Sequential Phragmén method, used by the election module to assign council seats in
+//! proportion to the stake behind the approval ballots.
+
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+// Fixed point scale of the voter loads. It must stay well above the total stake taking part
+// in an election, otherwise the candidate scores lose precision.
+const LOAD_SCALE: u128 = 1_000_000_000_000_000_000;
+
+/// Approval ballot weighted by the stake behind it.
+pub struct Voter<CandidateId> {
+    pub stake: u128,
+    pub approvals: Vec<CandidateId>,
+}
+
+/// Elects up to `seats` candidates, in the order they were elected. In every round the candidate
+/// with the lowest resulting maximum voter load wins, so a group of voters holding a share of the
+/// total stake can win roughly the same share of the seats.
+/// Ties go to the candidate listed first. Candidates without any approval stake are never elected.
+pub fn elect<CandidateId: PartialEq + Copy>(
+    seats: usize,
+    candidates: &[CandidateId],
+    voters: &[Voter<CandidateId>],
+) -> Vec<CandidateId> {
+    let mut loads = vec![0u128; voters.len()];
+    let mut elected = Vec::new();
+
+    while elected.len() < seats {
+        let mut winner: Option<(CandidateId, u128)> = None;
+
+        for candidate in candidates.iter().filter(|c| !elected.contains(*c)) {
+            let mut approval_stake = 0u128;
+            let mut backing_load = 0u128;
+
+            for (voter, load) in voters.iter().zip(loads.iter()) {
+                if voter.approvals.contains(candidate) {
+                    approval_stake = approval_stake.saturating_add(voter.stake);
+                    backing_load = backing_load.saturating_add(voter.stake.saturating_mul(*load));
+                }
+            }
+
+            if approval_stake == 0 {
+                continue;
+            }
+
+            let score = LOAD_SCALE.saturating_add(backing_load) / approval_stake;
+
+            if winner.map_or(true, |(_, best_score)| score < best_score) {
+                winner = Some((*candidate, score));
+            }
+        }
+
+        match winner {
+            Some((candidate, score)) => {
+                for (voter, load) in voters.iter().zip(loads.iter_mut()) {
+                    if voter.approvals.contains(&candidate) {
+                        *load = score;
+                    }
+                }
+
+                elected.push(candidate);
+            }
+            None => break,
+        }
+    }
+
+    elected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn voter(stake: u128, approvals: Vec<u64>) -> Voter<u64> {
+        Voter { stake, approvals }
+    }
+
+    #[test]
+    fn minority_wins_proportional_share_of_seats() {
+        // majority bloc approves 1 and 2, minority bloc approves 3
+        let voters = vec![voter(60, vec![1, 2]), voter(40, vec![3])];
+
+        assert_eq!(elect(2, &[1, 2, 3], &voters), vec![1, 3]);
+        assert_eq!(elect(3, &[1, 2, 3], &voters), vec![1, 3, 2]);
+    }
+
+    #[test]
+    fn majority_takes_more_seats_when_stake_allows() {
+        // majority bloc holds four fifths of the stake
+        let voters = vec![voter(80, vec![1, 2, 4]), voter(20, vec![3])];
+
+        assert_eq!(elect(3, &[1, 2, 3, 4], &voters), vec![1, 2, 4]);
+        assert_eq!(elect(4, &[1, 2, 3, 4], &voters), vec![1, 2, 4, 3]);
+    }
+
+    #[test]
+    fn candidates_without_approval_stake_are_not_elected() {
+        let voters = vec![voter(10, vec![2]), voter(0, vec![3])];
+
+        assert_eq!(elect(3, &[1, 2, 3], &voters), vec![2]);
+    }
+
+    #[test]
+    fn ties_go_to_first_listed_candidate() {
+        let voters = vec![voter(10, vec![1]), voter(10, vec![2])];
+
+        assert_eq!(elect(1, &[2, 1], &voters), vec![2]);
+        assert_eq!(elect(1, &[1, 2], &voters), vec![1]);
+    }
+}
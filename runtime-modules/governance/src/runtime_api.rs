@@ -0,0 +1,14 @@
+//! Runtime API of the governance module.
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Council budget queries.
+    pub trait CouncilBudgetApi<Balance> where Balance: Codec {
+        /// Budget left in the current council term, `None` when no budget is tracked.
+        fn remaining_budget() -> Option<Balance>;
+
+        /// Budget granted to each new council term, `None` when no budget is tracked.
+        fn budget_per_term() -> Option<Balance>;
+    }
+}
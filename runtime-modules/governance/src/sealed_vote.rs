@@ -53,12 +53,34 @@ where
         vote: Vote,
         salt: &mut Vec<u8>,
         hasher: fn(&[u8]) -> Hash,
+    ) -> Result<(), &'static str> {
+        let payload = vote.encode();
+        self.unseal_payload(payload, vote, salt, hasher)
+    }
+
+    /// Unseals a vote that was committed in a different form than it is stored in.
+    pub fn unseal_from<Committed: Encode>(
+        &mut self,
+        committed: &Committed,
+        vote: Vote,
+        salt: &mut Vec<u8>,
+        hasher: fn(&[u8]) -> Hash,
+    ) -> Result<(), &'static str> {
+        self.unseal_payload(committed.encode(), vote, salt, hasher)
+    }
+
+    fn unseal_payload(
+        &mut self,
+        payload: Vec<u8>,
+        vote: Vote,
+        salt: &mut Vec<u8>,
+        hasher: fn(&[u8]) -> Hash,
     ) -> Result<(), &'static str> {
         // only unseal once
         ensure!(self.is_not_revealed(), "vote already unsealed");
 
         // seralize the vote and append the salt
-        let mut payload = vote.encode();
+        let mut payload = payload;
         payload.append(salt);
 
         // hash the payload, if it matches the commitment it is a valid revealing of the vote
@@ -70,6 +92,14 @@ where
         }
     }
 
+    /// Casts the vote of an unrevealed commitment on behalf of the voter, e.g. by the voter's
+    /// delegate. A revealed vote is kept.
+    pub fn cast_on_behalf(&mut self, vote: Vote) {
+        if self.is_not_revealed() {
+            self.vote = Some(vote);
+        }
+    }
+
     pub fn get_vote(&self) -> &Option<Vote> {
         &self.vote
     }
@@ -82,6 +82,7 @@ impl Trait for Test {
 }
 
 impl stake::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
@@ -10,17 +10,23 @@ pub(crate) mod mock;
 mod tests;
 
 use codec::{Codec, Decode, Encode};
-use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, Get, LockableCurrency, WithdrawReason};
-use frame_support::{decl_error, decl_event, decl_module, decl_storage, ensure, Parameter};
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::traits::{Currency, Get, Imbalance, LockableCurrency, WithdrawReason};
+use frame_support::{
+    decl_error, decl_event, decl_module, decl_storage, ensure, IterableStorageDoubleMap,
+    IterableStorageMap, Parameter,
+};
 use frame_system::{ensure_root, ensure_signed};
 use sp_arithmetic::traits::{BaseArithmetic, One};
 use sp_runtime::traits::{MaybeSerialize, Member, Zero};
+use sp_runtime::Percent;
 use sp_std::borrow::ToOwned;
 use sp_std::vec;
 use sp_std::vec::Vec;
 
 use common::currency::{BalanceOf, GovernanceCurrency};
+use common::origin::ActorOriginValidator;
+
 pub trait Trait: frame_system::Trait + GovernanceCurrency + pallet_timestamp::Trait {
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
 
@@ -65,6 +71,14 @@ pub trait Trait: frame_system::Trait + GovernanceCurrency + pallet_timestamp::Tr
     /// The maximum amount of initial funds that may be endowed to new members added by
     /// screening authority. If set to zero, no initial balance can be given.
     type ScreenedMemberMaxInitialBalance: Get<BalanceOf<Self>>;
+
+    /// Validates the origin of working group workers allowed to verify
+    /// external identities claimed by members.
+    type IdentityVerifierOriginValidator: ActorOriginValidator<
+        Self::Origin,
+        Self::ActorId,
+        Self::AccountId,
+    >;
 }
 
 const FIRST_PAID_TERMS_ID: u8 = 1;
@@ -77,6 +91,15 @@ const DEFAULT_MIN_HANDLE_LENGTH: u32 = 5;
 const DEFAULT_MAX_HANDLE_LENGTH: u32 = 40;
 const DEFAULT_MAX_AVATAR_URI_LENGTH: u32 = 1024;
 const DEFAULT_MAX_ABOUT_TEXT_LENGTH: u32 = 2048;
+const DEFAULT_MAX_METADATA_LENGTH: u32 = 4096;
+const DEFAULT_MAX_EXTERNAL_IDENTITY_LENGTH: u32 = 256;
+const DEFAULT_MAX_EXTERNAL_IDENTITIES_PER_MEMBER: u32 = 10;
+
+// Default number of invitations granted to a new member
+const DEFAULT_INITIAL_INVITATION_COUNT: u32 = 5;
+
+// Max percentage of the paid membership fee transferred to the referrer
+const MAX_REFERRAL_CUT: u8 = 100;
 
 /// Public membership object alias.
 pub type Membership<T> = MembershipObject<
@@ -85,11 +108,12 @@ pub type Membership<T> = MembershipObject<
     <T as Trait>::PaidTermId,
     <T as Trait>::SubscriptionId,
     <T as frame_system::Trait>::AccountId,
+    <T as Trait>::MemberId,
 >;
 
 #[derive(Encode, Decode, Default)]
 /// Stored information about a registered user
-pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, AccountId> {
+pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, AccountId, MemberId> {
     /// The unique handle chosen by member
     pub handle: Vec<u8>,
 
@@ -106,7 +130,7 @@ pub struct MembershipObject<BlockNumber, Moment, PaidTermId, SubscriptionId, Acc
     pub registered_at_time: Moment,
 
     /// How the member was registered
-    pub entry: EntryMethod<PaidTermId, AccountId>,
+    pub entry: EntryMethod<PaidTermId, AccountId, MemberId>,
 
     /// Whether the member is suspended or not.
     pub suspended: bool,
@@ -134,20 +158,42 @@ struct ValidatedUserInfo {
 }
 
 #[derive(Encode, Decode, Debug, PartialEq, Eq, Clone)]
-pub enum EntryMethod<PaidTermId, AccountId> {
+pub enum EntryMethod<PaidTermId, AccountId, MemberId> {
     Paid(PaidTermId),
     Screening(AccountId),
     Genesis,
+    /// Invited by the member with the given id.
+    Invited(MemberId),
 }
 
 /// Must be default constructible because it indirectly is a value in a storage map.
 /// ***SHOULD NEVER ACTUALLY GET CALLED, IS REQUIRED TO DUE BAD STORAGE MODEL IN SUBSTRATE***
-impl<PaidTermId, AccountId> Default for EntryMethod<PaidTermId, AccountId> {
+impl<PaidTermId, AccountId, MemberId> Default for EntryMethod<PaidTermId, AccountId, MemberId> {
     fn default() -> Self {
         Self::Genesis
     }
 }
 
+/// Kind of an external identity claimed by a member.
+#[derive(Encode, Decode, Debug, PartialEq, Eq, Clone)]
+pub enum ExternalIdentityKind {
+    /// Hash of an email address owned by the member.
+    EmailHash,
+
+    /// Handle on a social platform. Contains the platform name.
+    SocialHandle(Vec<u8>),
+}
+
+/// External identity claimed by a member.
+#[derive(Encode, Decode, Default, Debug, PartialEq, Eq, Clone)]
+pub struct ExternalIdentityClaim<AccountId> {
+    /// Claimed identity, e.g. an email hash or a social handle.
+    pub value: Vec<u8>,
+
+    /// Account of the verifier that verified the claim. None if the claim isn't verified.
+    pub verified_by: Option<AccountId>,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Default)]
 pub struct PaidMembershipTerms<Balance> {
     /// Quantity of native tokens which must be provably burned
@@ -167,12 +213,12 @@ decl_storage! {
             T::MemberId => Membership<T>;
 
         /// Mapping of a root account id to vector of member ids it controls.
-        pub(crate) MemberIdsByRootAccountId : map hasher(blake2_128_concat)
-            T::AccountId => Vec<T::MemberId>;
+        pub(crate) MemberIdsByRootAccountId get(fn member_ids_by_root_account_id) :
+            map hasher(blake2_128_concat) T::AccountId => Vec<T::MemberId>;
 
         /// Mapping of a controller account id to vector of member ids it controls
-        pub(crate) MemberIdsByControllerAccountId : map hasher(blake2_128_concat)
-            T::AccountId => Vec<T::MemberId>;
+        pub(crate) MemberIdsByControllerAccountId get(fn member_ids_by_controller_account_id) :
+            map hasher(blake2_128_concat) T::AccountId => Vec<T::MemberId>;
 
         /// Registered unique handles and their mapping to their owner
         pub MemberIdByHandle get(fn handles) : map hasher(blake2_128_concat)
@@ -212,7 +258,50 @@ decl_storage! {
         pub MaxHandleLength get(fn max_handle_length) : u32 = DEFAULT_MAX_HANDLE_LENGTH;
         pub MaxAvatarUriLength get(fn max_avatar_uri_length) : u32 = DEFAULT_MAX_AVATAR_URI_LENGTH;
         pub MaxAboutTextLength get(fn max_about_text_length) : u32 = DEFAULT_MAX_ABOUT_TEXT_LENGTH;
-
+        pub MaxMetadataLength get(fn max_metadata_length) : u32 = DEFAULT_MAX_METADATA_LENGTH;
+        pub MaxExternalIdentityLength get(fn max_external_identity_length) : u32 =
+            DEFAULT_MAX_EXTERNAL_IDENTITY_LENGTH;
+        pub MaxExternalIdentitiesPerMember get(fn max_external_identities_per_member) : u32 =
+            DEFAULT_MAX_EXTERNAL_IDENTITIES_PER_MEMBER;
+
+        /// Structured profile metadata of a member (eg.: encoded protobuf message).
+        /// Kept apart from the `Membership` object to leave its storage layout unchanged.
+        pub MemberMetadata get(fn member_metadata) : map hasher(blake2_128_concat)
+            T::MemberId => Vec<u8>;
+
+        /// External identities claimed by members.
+        pub ExternalIdentityByMemberId get(fn external_identity) : double_map
+            hasher(blake2_128_concat) T::MemberId,
+            hasher(blake2_128_concat) ExternalIdentityKind => ExternalIdentityClaim<T::AccountId>;
+
+        /// Account allowed to verify external identities claimed by members.
+        pub IdentityVerifier get(fn identity_verifier) : Option<T::AccountId>;
+
+        /// Remaining invitations of a member.
+        pub InvitesByMemberId get(fn invites) : map hasher(blake2_128_concat)
+            T::MemberId => u32;
+
+        /// Number of invitations granted to every new member.
+        pub InitialInvitationCount get(fn initial_invitation_count) : u32 =
+            DEFAULT_INITIAL_INVITATION_COUNT;
+
+        /// Remaining number of invitations that can be used by all members in total.
+        pub InvitationBudget get(fn invitation_budget) : u32;
+
+        /// Percentage of the paid membership fee transferred to the referrer.
+        pub ReferralCut get(fn referral_cut) : u8;
+
+        /// Number of paid memberships referred by a member.
+        pub ReferralsByMemberId get(fn referrals) : map hasher(blake2_128_concat)
+            T::MemberId => u32;
+
+        /// Protected handles (eg.: brand names) that cannot be registered by members.
+        pub ReservedHandles get(fn reserved_handles) : map hasher(blake2_128_concat)
+            Vec<u8> => ();
+
+        /// Pending handle swap offers: offering member id => (counterparty member id, offered handle).
+        pub HandleSwapOfferByMemberId get(fn handle_swap_offer) : map hasher(blake2_128_concat)
+            T::MemberId => Option<(T::MemberId, Vec<u8>)>;
     }
     add_extra_genesis {
         config(default_paid_membership_fee): BalanceOf<T>;
@@ -246,13 +335,33 @@ decl_event! {
       <T as frame_system::Trait>::AccountId,
       <T as Trait>::MemberId,
       <T as Trait>::PaidTermId,
+      Balance = BalanceOf<T>,
     {
-        MemberRegistered(MemberId, AccountId, EntryMethod<PaidTermId, AccountId>),
+        MemberRegistered(MemberId, AccountId, EntryMethod<PaidTermId, AccountId, MemberId>),
         MemberUpdatedAboutText(MemberId),
         MemberUpdatedAvatar(MemberId),
         MemberUpdatedHandle(MemberId),
         MemberSetRootAccount(MemberId, AccountId),
         MemberSetControllerAccount(MemberId, AccountId),
+        MemberUpdatedMetadata(MemberId),
+        MemberExternalIdentitySet(MemberId, ExternalIdentityKind),
+        MemberExternalIdentityRemoved(MemberId, ExternalIdentityKind),
+        MemberExternalIdentityVerified(MemberId, ExternalIdentityKind, AccountId),
+        IdentityVerifierSet(AccountId),
+        InvitationBudgetUpdated(u32),
+        InitialInvitationCountUpdated(u32),
+        ReferralCutUpdated(u8),
+        /// Params:
+        /// - referred member id
+        /// - referrer member id
+        /// - reward transferred to the referrer controller account
+        MemberReferred(MemberId, MemberId, Balance),
+        HandleReserved(Vec<u8>),
+        HandleUnreserved(Vec<u8>),
+        HandleSwapOffered(MemberId, MemberId),
+        HandleSwapOfferCancelled(MemberId),
+        HandlesSwapped(MemberId, MemberId),
+        HandleTransferred(MemberId, MemberId),
     }
 }
 
@@ -265,14 +374,16 @@ decl_module! {
 
         const ScreenedMemberMaxInitialBalance: BalanceOf<T> = T::ScreenedMemberMaxInitialBalance::get();
 
-        /// Non-members can buy membership
+        /// Non-members can buy membership. A part of the fee defined by the referral cut
+        /// is transferred to the controller account of the optional referrer.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn buy_membership(
             origin,
             paid_terms_id: T::PaidTermId,
             handle: Option<Vec<u8>>,
             avatar_uri: Option<Vec<u8>>,
-            about: Option<Vec<u8>>
+            about: Option<Vec<u8>>,
+            referrer_id: Option<T::MemberId>
         ) {
             let who = ensure_signed(origin)?;
 
@@ -285,6 +396,10 @@ decl_module! {
             // ensure enough free balance to cover terms fees
             ensure!(T::Currency::can_slash(&who, terms.fee), Error::<T>::NotEnoughBalanceToBuyMembership);
 
+            let referrer = referrer_id
+                .map(|id| Self::ensure_membership(id).map(|membership| (id, membership)))
+                .transpose()?;
+
             let user_info = Self::check_user_registration_info(handle, avatar_uri, about)?;
 
             let member_id = Self::insert_member(
@@ -296,9 +411,21 @@ decl_module! {
                 <pallet_timestamp::Module<T>>::now()
             )?;
 
-            let _ = T::Currency::slash(&who, terms.fee);
+            let (fee_imbalance, _) = T::Currency::slash(&who, terms.fee);
 
             Self::deposit_event(RawEvent::MemberRegistered(member_id, who, EntryMethod::Paid(paid_terms_id)));
+
+            if let Some((referrer_id, referrer)) = referrer {
+                let reward = Percent::from_percent(Self::referral_cut()) * terms.fee;
+
+                // The rest of the fee is burned when the imbalance is dropped.
+                let (reward_imbalance, _) = fee_imbalance.split(reward);
+                T::Currency::resolve_creating(&referrer.controller_account, reward_imbalance);
+
+                <ReferralsByMemberId<T>>::mutate(referrer_id, |referrals| *referrals += 1);
+
+                Self::deposit_event(RawEvent::MemberReferred(member_id, referrer_id, reward));
+            }
         }
 
         /// Change member's about text
@@ -491,6 +618,363 @@ decl_module! {
             ensure_root(origin)?;
             <ScreeningAuthority<T>>::put(authority);
         }
+
+        /// Set member's structured profile metadata.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_member_metadata(origin, member_id: T::MemberId, metadata: Vec<u8>) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, Error::<T>::ControllerAccountRequired);
+
+            ensure!(
+                metadata.len() <= Self::max_metadata_length() as usize,
+                Error::<T>::MetadataTooLong
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <MemberMetadata<T>>::insert(member_id, metadata);
+            Self::deposit_event(RawEvent::MemberUpdatedMetadata(member_id));
+        }
+
+        /// Claim an external identity. Changing an existing claim clears its verification.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_external_identity(
+            origin,
+            member_id: T::MemberId,
+            kind: ExternalIdentityKind,
+            value: Vec<u8>
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, Error::<T>::ControllerAccountRequired);
+
+            Self::validate_external_identity(&kind, &value)?;
+
+            if <ExternalIdentityByMemberId<T>>::contains_key(member_id, &kind) {
+                // keep verification of an unchanged claim
+                if Self::external_identity(member_id, &kind).value == value {
+                    return Ok(());
+                }
+            } else {
+                let claims = <ExternalIdentityByMemberId<T>>::iter_prefix(member_id).count();
+                ensure!(
+                    claims < Self::max_external_identities_per_member() as usize,
+                    Error::<T>::TooManyExternalIdentities
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let claim = ExternalIdentityClaim {
+                value,
+                verified_by: None,
+            };
+            <ExternalIdentityByMemberId<T>>::insert(member_id, &kind, claim);
+            Self::deposit_event(RawEvent::MemberExternalIdentitySet(member_id, kind));
+        }
+
+        /// Remove a claimed external identity.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn remove_external_identity(
+            origin,
+            member_id: T::MemberId,
+            kind: ExternalIdentityKind
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, Error::<T>::ControllerAccountRequired);
+
+            Self::ensure_external_identity_exists(member_id, &kind)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <ExternalIdentityByMemberId<T>>::remove(member_id, &kind);
+            Self::deposit_event(RawEvent::MemberExternalIdentityRemoved(member_id, kind));
+        }
+
+        /// Mark a claimed external identity verified. Can be called either by the identity
+        /// verifier account or by a working group worker (when `worker_id` is provided).
+        /// The verified `value` must match the current claim, so a claim changed in the
+        /// meantime cannot be verified by accident.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn verify_external_identity(
+            origin,
+            worker_id: Option<T::ActorId>,
+            member_id: T::MemberId,
+            kind: ExternalIdentityKind,
+            value: Vec<u8>
+        ) {
+            let verifier = Self::ensure_identity_verifier(origin, worker_id)?;
+
+            Self::ensure_membership(member_id)?;
+
+            let mut claim = Self::ensure_external_identity_exists(member_id, &kind)?;
+
+            ensure!(claim.value == value, Error::<T>::ExternalIdentityClaimChanged);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            claim.verified_by = Some(verifier.clone());
+            <ExternalIdentityByMemberId<T>>::insert(member_id, &kind, claim);
+            Self::deposit_event(RawEvent::MemberExternalIdentityVerified(member_id, kind, verifier));
+        }
+
+        /// Create a new membership invited by an existing member. The invited member doesn't pay
+        /// the membership fee. Consumes an invitation of the inviting member and of the
+        /// global invitation budget.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn invite_member(
+            origin,
+            inviting_member_id: T::MemberId,
+            root_account: T::AccountId,
+            controller_account: T::AccountId,
+            handle: Option<Vec<u8>>,
+            avatar_uri: Option<Vec<u8>>,
+            about: Option<Vec<u8>>
+        ) {
+            let sender = ensure_signed(origin)?;
+
+            let inviting_membership = Self::ensure_membership(inviting_member_id)?;
+
+            ensure!(
+                inviting_membership.controller_account == sender,
+                Error::<T>::ControllerAccountRequired
+            );
+
+            // make sure we are accepting new memberships
+            ensure!(Self::new_memberships_allowed(), Error::<T>::NewMembershipsNotAllowed);
+
+            ensure!(Self::invites(inviting_member_id) > 0, Error::<T>::NotEnoughInvites);
+
+            ensure!(Self::invitation_budget() > 0, Error::<T>::InvitationBudgetExhausted);
+
+            let user_info = Self::check_user_registration_info(handle, avatar_uri, about)?;
+
+            let entry_method = EntryMethod::Invited(inviting_member_id);
+
+            let member_id = Self::insert_member(
+                &root_account,
+                &controller_account,
+                &user_info,
+                entry_method.clone(),
+                <frame_system::Module<T>>::block_number(),
+                <pallet_timestamp::Module<T>>::now()
+            )?;
+
+            <InvitesByMemberId<T>>::mutate(inviting_member_id, |invites| *invites -= 1);
+            InvitationBudget::mutate(|budget| *budget -= 1);
+
+            Self::deposit_event(RawEvent::MemberRegistered(member_id, controller_account, entry_method));
+        }
+
+        /// Set the number of invitations that can be used by all members in total.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_invitation_budget(origin, invitation_budget: u32) {
+            ensure_root(origin)?;
+            InvitationBudget::put(invitation_budget);
+            Self::deposit_event(RawEvent::InvitationBudgetUpdated(invitation_budget));
+        }
+
+        /// Set the number of invitations granted to every new member.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_initial_invitation_count(origin, invitation_count: u32) {
+            ensure_root(origin)?;
+            InitialInvitationCount::put(invitation_count);
+            Self::deposit_event(RawEvent::InitialInvitationCountUpdated(invitation_count));
+        }
+
+        /// Reserve a handle. Reserved handles cannot be registered by members.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn reserve_handle(origin, handle: Vec<u8>) {
+            ensure_root(origin)?;
+
+            Self::validate_handle(&handle)?;
+
+            ensure!(!ReservedHandles::contains_key(&handle), Error::<T>::HandleAlreadyReserved);
+
+            // == MUTATION SAFE ==
+
+            ReservedHandles::insert(handle.clone(), ());
+            Self::deposit_event(RawEvent::HandleReserved(handle));
+        }
+
+        /// Remove a handle from the reserved handles list.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn unreserve_handle(origin, handle: Vec<u8>) {
+            ensure_root(origin)?;
+
+            Self::ensure_reserved_handle(&handle)?;
+
+            // == MUTATION SAFE ==
+
+            ReservedHandles::remove(&handle);
+            Self::deposit_event(RawEvent::HandleUnreserved(handle));
+        }
+
+        /// Assign a reserved handle to a member (eg.: to the brand owner).
+        /// The handle is removed from the reserved handles list.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn grant_reserved_handle(origin, member_id: T::MemberId, handle: Vec<u8>) {
+            ensure_root(origin)?;
+
+            Self::ensure_reserved_handle(&handle)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            Self::validate_handle(&handle)?;
+
+            // The handle is reserved, so only its registration is checked.
+            Self::ensure_handle_is_not_registered(&handle)?;
+
+            // == MUTATION SAFE ==
+
+            Self::set_member_handle(member_id, membership, handle.clone());
+
+            ReservedHandles::remove(&handle);
+            Self::deposit_event(RawEvent::HandleUnreserved(handle));
+        }
+
+        /// Transfer the handle of a member to another member (eg.: to resolve the brand handle
+        /// squatting). The previous handle of the recipient is released and the former owner
+        /// gets the replacement handle.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn transfer_handle(
+            origin,
+            member_id: T::MemberId,
+            recipient_id: T::MemberId,
+            replacement_handle: Vec<u8>
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(member_id != recipient_id, Error::<T>::CannotTransferHandleToSelf);
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            let recipient_membership = Self::ensure_membership(recipient_id)?;
+
+            Self::validate_handle(&replacement_handle)?;
+
+            Self::ensure_unique_handle(&replacement_handle)?;
+
+            // == MUTATION SAFE ==
+
+            let handle = membership.handle.clone();
+
+            Self::set_member_handle(member_id, membership, replacement_handle);
+            Self::set_member_handle(recipient_id, recipient_membership, handle);
+
+            Self::deposit_event(RawEvent::HandleTransferred(member_id, recipient_id));
+        }
+
+        /// Offer to swap handles with another member. The swap is performed once the
+        /// counterparty accepts the offer.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn offer_handle_swap(origin, member_id: T::MemberId, counterparty_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, Error::<T>::ControllerAccountRequired);
+
+            ensure!(member_id != counterparty_id, Error::<T>::CannotSwapHandleWithSelf);
+
+            Self::ensure_membership(counterparty_id)?;
+
+            // == MUTATION SAFE ==
+
+            <HandleSwapOfferByMemberId<T>>::insert(member_id, (counterparty_id, membership.handle));
+            Self::deposit_event(RawEvent::HandleSwapOffered(member_id, counterparty_id));
+        }
+
+        /// Cancel the pending handle swap offer of a member.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn cancel_handle_swap(origin, member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, Error::<T>::ControllerAccountRequired);
+
+            ensure!(
+                <HandleSwapOfferByMemberId<T>>::contains_key(member_id),
+                Error::<T>::HandleSwapOfferNotFound
+            );
+
+            // == MUTATION SAFE ==
+
+            <HandleSwapOfferByMemberId<T>>::remove(member_id);
+            Self::deposit_event(RawEvent::HandleSwapOfferCancelled(member_id));
+        }
+
+        /// Accept the handle swap offered by another member. Both handles are swapped atomically.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn accept_handle_swap(origin, member_id: T::MemberId, offering_member_id: T::MemberId) {
+            let sender = ensure_signed(origin)?;
+
+            let mut membership = Self::ensure_membership(member_id)?;
+
+            ensure!(membership.controller_account == sender, Error::<T>::ControllerAccountRequired);
+
+            let mut offering_membership = Self::ensure_membership(offering_member_id)?;
+
+            let (counterparty_id, offered_handle) = Self::handle_swap_offer(offering_member_id)
+                .ok_or(Error::<T>::HandleSwapOfferNotFound)?;
+
+            ensure!(counterparty_id == member_id, Error::<T>::HandleSwapOfferNotFound);
+
+            // The offering member changed the handle after the offer was made.
+            ensure!(offering_membership.handle == offered_handle, Error::<T>::HandleSwapOfferOutdated);
+
+            // == MUTATION SAFE ==
+
+            <HandleSwapOfferByMemberId<T>>::remove(offering_member_id);
+
+            sp_std::mem::swap(&mut membership.handle, &mut offering_membership.handle);
+
+            <MemberIdByHandle<T>>::insert(membership.handle.clone(), member_id);
+            <MemberIdByHandle<T>>::insert(offering_membership.handle.clone(), offering_member_id);
+
+            <MembershipById<T>>::insert(member_id, membership);
+            <MembershipById<T>>::insert(offering_member_id, offering_membership);
+
+            Self::deposit_event(RawEvent::HandlesSwapped(offering_member_id, member_id));
+        }
+
+        /// Set the percentage of the paid membership fee transferred to the referrer.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_referral_cut(origin, referral_cut: u8) {
+            ensure_root(origin)?;
+
+            ensure!(referral_cut <= MAX_REFERRAL_CUT, Error::<T>::ReferralCutTooHigh);
+
+            // == MUTATION SAFE ==
+
+            ReferralCut::put(referral_cut);
+            Self::deposit_event(RawEvent::ReferralCutUpdated(referral_cut));
+        }
+
+        /// Set the account allowed to verify external identities claimed by members.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_identity_verifier(origin, verifier: T::AccountId) {
+            ensure_root(origin)?;
+            <IdentityVerifier<T>>::put(verifier.clone());
+            Self::deposit_event(RawEvent::IdentityVerifierSet(verifier));
+        }
     }
 }
 
@@ -525,6 +1009,18 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Grants the initial invitations to the members created before the invitations existed.
+    /// Should be called on the migration to the new runtime version.
+    pub fn migrate_member_invites() {
+        let initial_invitation_count = Self::initial_invitation_count();
+
+        for (member_id, _) in <MembershipById<T>>::iter() {
+            if !<InvitesByMemberId<T>>::contains_key(member_id) {
+                <InvitesByMemberId<T>>::insert(member_id, initial_invitation_count);
+            }
+        }
+    }
+
     /// Ensure that given member has given account as the controller account
     pub fn ensure_is_controller_account_for_member(
         member_id: &T::MemberId,
@@ -567,6 +1063,16 @@ impl<T: Trait> Module<T> {
 
     #[allow(clippy::ptr_arg)] // cannot change to the "&[u8]" suggested by clippy
     fn ensure_unique_handle(handle: &Vec<u8>) -> Result<(), Error<T>> {
+        Self::ensure_handle_is_not_registered(handle)?;
+        ensure!(
+            !ReservedHandles::contains_key(handle),
+            Error::<T>::HandleIsReserved
+        );
+        Ok(())
+    }
+
+    #[allow(clippy::ptr_arg)] // cannot change to the "&[u8]" suggested by clippy
+    fn ensure_handle_is_not_registered(handle: &Vec<u8>) -> Result<(), Error<T>> {
         ensure!(
             !<MemberIdByHandle<T>>::contains_key(handle),
             Error::<T>::HandleAlreadyRegistered
@@ -574,6 +1080,15 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    #[allow(clippy::ptr_arg)] // cannot change to the "&[u8]" suggested by clippy
+    fn ensure_reserved_handle(handle: &Vec<u8>) -> Result<(), Error<T>> {
+        ensure!(
+            ReservedHandles::contains_key(handle),
+            Error::<T>::HandleNotReserved
+        );
+        Ok(())
+    }
+
     fn validate_handle(handle: &[u8]) -> Result<(), Error<T>> {
         ensure!(
             handle.len() >= Self::min_handle_length() as usize,
@@ -600,6 +1115,59 @@ impl<T: Trait> Module<T> {
         Ok(())
     }
 
+    fn validate_external_identity(
+        kind: &ExternalIdentityKind,
+        value: &[u8],
+    ) -> Result<(), Error<T>> {
+        let max_length = Self::max_external_identity_length() as usize;
+
+        ensure!(
+            value.len() <= max_length,
+            Error::<T>::ExternalIdentityTooLong
+        );
+
+        if let ExternalIdentityKind::SocialHandle(platform) = kind {
+            ensure!(
+                platform.len() <= max_length,
+                Error::<T>::ExternalIdentityTooLong
+            );
+        }
+
+        Ok(())
+    }
+
+    fn ensure_external_identity_exists(
+        member_id: T::MemberId,
+        kind: &ExternalIdentityKind,
+    ) -> Result<ExternalIdentityClaim<T::AccountId>, Error<T>> {
+        ensure!(
+            <ExternalIdentityByMemberId<T>>::contains_key(member_id, kind),
+            Error::<T>::ExternalIdentityNotFound
+        );
+
+        Ok(Self::external_identity(member_id, kind))
+    }
+
+    // Ensures origin is either the identity verifier account or the working group worker
+    // with the provided id. Returns the verifier account.
+    fn ensure_identity_verifier(
+        origin: T::Origin,
+        worker_id: Option<T::ActorId>,
+    ) -> Result<T::AccountId, DispatchError> {
+        if let Some(worker_id) = worker_id {
+            return T::IdentityVerifierOriginValidator::ensure_actor_origin(origin, worker_id)
+                .map_err(|_| Error::<T>::NotIdentityVerifier.into());
+        }
+
+        let sender = ensure_signed(origin)?;
+
+        let verifier = Self::identity_verifier().ok_or(Error::<T>::NoIdentityVerifierDefined)?;
+
+        ensure!(sender == verifier, Error::<T>::NotIdentityVerifier);
+
+        Ok(sender)
+    }
+
     /// Basic user input validation
     fn check_user_registration_info(
         handle: Option<Vec<u8>>,
@@ -625,7 +1193,7 @@ impl<T: Trait> Module<T> {
         root_account: &T::AccountId,
         controller_account: &T::AccountId,
         user_info: &ValidatedUserInfo,
-        entry_method: EntryMethod<T::PaidTermId, T::AccountId>,
+        entry_method: EntryMethod<T::PaidTermId, T::AccountId, T::MemberId>,
         registered_at_block: T::BlockNumber,
         registered_at_time: T::Moment,
     ) -> Result<T::MemberId, Error<T>> {
@@ -655,6 +1223,7 @@ impl<T: Trait> Module<T> {
 
         <MembershipById<T>>::insert(new_member_id, membership);
         <MemberIdByHandle<T>>::insert(user_info.handle.clone(), new_member_id);
+        <InvitesByMemberId<T>>::insert(new_member_id, Self::initial_invitation_count());
 
         <NextMemberId<T>>::put(new_member_id + One::one());
         Ok(new_member_id)
@@ -679,15 +1248,20 @@ impl<T: Trait> Module<T> {
     }
 
     fn _change_member_handle(id: T::MemberId, handle: Vec<u8>) -> DispatchResult {
-        let mut membership = Self::ensure_membership(id)?;
+        let membership = Self::ensure_membership(id)?;
         Self::validate_handle(&handle)?;
         Self::ensure_unique_handle(&handle)?;
+        Self::set_member_handle(id, membership, handle);
+        Ok(())
+    }
+
+    // Sets the validated handle and releases the previous handle of the member.
+    fn set_member_handle(id: T::MemberId, mut membership: Membership<T>, handle: Vec<u8>) {
         <MemberIdByHandle<T>>::remove(&membership.handle);
         <MemberIdByHandle<T>>::insert(handle.clone(), id);
         membership.handle = handle;
         Self::deposit_event(RawEvent::MemberUpdatedHandle(id));
         <MembershipById<T>>::insert(id, membership);
-        Ok(())
     }
 
     pub fn ensure_member_controller_account_signed(
@@ -796,5 +1370,56 @@ decl_error! {
 
         /// Paid term id not active.
         PaidTermIdNotActive,
+
+        /// Member metadata is too long.
+        MetadataTooLong,
+
+        /// External identity (or its platform name) is too long.
+        ExternalIdentityTooLong,
+
+        /// Member has reached the maximum number of external identities.
+        TooManyExternalIdentities,
+
+        /// External identity not claimed by the member.
+        ExternalIdentityNotFound,
+
+        /// External identity claim doesn't match the verified value.
+        ExternalIdentityClaimChanged,
+
+        /// An identity verifier account is not defined.
+        NoIdentityVerifierDefined,
+
+        /// Origin is not an identity verifier.
+        NotIdentityVerifier,
+
+        /// Inviting member has no invitations left.
+        NotEnoughInvites,
+
+        /// Global invitation budget is exhausted.
+        InvitationBudgetExhausted,
+
+        /// Referral cut cannot exceed 100 percent.
+        ReferralCutTooHigh,
+
+        /// Handle is reserved and cannot be registered.
+        HandleIsReserved,
+
+        /// Handle is already reserved.
+        HandleAlreadyReserved,
+
+        /// Handle is not reserved.
+        HandleNotReserved,
+
+        /// Member cannot swap handles with itself.
+        CannotSwapHandleWithSelf,
+
+        /// Handle swap offer not found.
+        HandleSwapOfferNotFound,
+
+        /// Offered handle was changed after the swap offer was made.
+        HandleSwapOfferOutdated,
+
+        /// Member cannot transfer the handle to itself.
+        CannotTransferHandleToSelf,
     }
 }
@@ -13,6 +13,7 @@ use sp_runtime::{
 };
 
 pub use common::currency::GovernanceCurrency;
+use common::origin::ActorOriginValidator;
 
 impl_outer_origin! {
     pub enum Origin for Test {}
@@ -93,6 +94,27 @@ impl Trait for Test {
     type SubscriptionId = u32;
     type ActorId = u32;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator = IdentityVerifierWorker;
+}
+
+pub const IDENTITY_VERIFIER_WORKER_ID: u32 = 1;
+pub const IDENTITY_VERIFIER_WORKER_ACCOUNT_ID: u64 = 10;
+
+/// Accepts a single mocked worker as an identity verifier.
+pub struct IdentityVerifierWorker;
+
+impl ActorOriginValidator<Origin, u32, u64> for IdentityVerifierWorker {
+    fn ensure_actor_origin(origin: Origin, worker_id: u32) -> Result<u64, &'static str> {
+        let account_id = frame_system::ensure_signed(origin)?;
+
+        if worker_id == IDENTITY_VERIFIER_WORKER_ID
+            && account_id == IDENTITY_VERIFIER_WORKER_ACCOUNT_ID
+        {
+            Ok(account_id)
+        } else {
+            Err("Not a worker")
+        }
+    }
 }
 
 pub struct TestExternalitiesBuilder<T: Trait> {
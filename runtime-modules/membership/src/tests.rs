@@ -55,6 +55,7 @@ fn buy_default_membership_as_alice() -> crate::DispatchResult {
         info.handle,
         info.avatar_uri,
         info.about,
+        None,
     )
     .map_err(|err| err.into())
 }
@@ -151,6 +152,85 @@ fn buy_membership_fails_without_enough_balance() {
         });
 }
 
+#[test]
+fn buy_membership_with_referrer() {
+    const DEFAULT_FEE: u64 = 500;
+    const REFERRAL_CUT: u8 = 20;
+    const BOB_ACCOUNT_ID: u64 = 2;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            set_alice_free_balance(DEFAULT_FEE);
+            let _ = Balances::deposit_creating(&BOB_ACCOUNT_ID, DEFAULT_FEE);
+
+            let referrer_id = Members::members_created();
+            assert_ok!(buy_default_membership_as_alice());
+            assert_eq!(Balances::free_balance(&ALICE_ACCOUNT_ID), 0);
+
+            assert_ok!(Members::set_referral_cut(Origin::root(), REFERRAL_CUT));
+
+            let total_issuance = Balances::total_issuance();
+            let info = get_bob_info();
+            assert_ok!(Members::buy_membership(
+                Origin::signed(BOB_ACCOUNT_ID),
+                DEFAULT_PAID_TERM_ID as u32,
+                info.handle,
+                info.avatar_uri,
+                info.about,
+                Some(referrer_id),
+            ));
+
+            let reward = DEFAULT_FEE * REFERRAL_CUT as u64 / 100;
+            assert_eq!(Balances::free_balance(&ALICE_ACCOUNT_ID), reward);
+            assert_eq!(Balances::free_balance(&BOB_ACCOUNT_ID), 0);
+            assert_eq!(
+                Balances::total_issuance(),
+                total_issuance - (DEFAULT_FEE - reward)
+            );
+            assert_eq!(Members::referrals(referrer_id), 1);
+        });
+}
+
+#[test]
+fn buy_membership_fails_with_invalid_referrer() {
+    const DEFAULT_FEE: u64 = 500;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            set_alice_free_balance(DEFAULT_FEE);
+
+            let info = get_alice_info();
+            assert_err!(
+                Members::buy_membership(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    DEFAULT_PAID_TERM_ID as u32,
+                    info.handle,
+                    info.avatar_uri,
+                    info.about,
+                    Some(100),
+                ),
+                Error::<Test>::MemberProfileNotFound,
+            );
+
+            assert_err!(
+                Members::set_referral_cut(Origin::root(), 101),
+                Error::<Test>::ReferralCutTooHigh,
+            );
+        });
+}
+
 #[test]
 fn new_memberships_allowed_flag() {
     const DEFAULT_FEE: u64 = 500;
@@ -202,6 +282,140 @@ fn unique_handles() {
         });
 }
 
+#[test]
+fn reserved_handles() {
+    const DEFAULT_FEE: u64 = 500;
+    const SURPLUS_BALANCE: u64 = 500;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .default_paid_membership_fee(DEFAULT_FEE)
+                .members(vec![(0, 2)])
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let initial_balance = DEFAULT_FEE + SURPLUS_BALANCE;
+            set_alice_free_balance(initial_balance);
+
+            let alice_handle = get_alice_info().handle.unwrap();
+
+            assert_ok!(Members::reserve_handle(
+                Origin::root(),
+                alice_handle.clone()
+            ));
+
+            // should not be allowed to buy membership with a reserved handle
+            assert_err!(
+                buy_default_membership_as_alice(),
+                Error::<Test>::HandleIsReserved,
+            );
+
+            // should not be allowed to change to a reserved handle
+            assert_err!(
+                Members::change_member_handle(Origin::signed(2), 0, alice_handle.clone()),
+                Error::<Test>::HandleIsReserved,
+            );
+
+            assert_ok!(Members::grant_reserved_handle(
+                Origin::root(),
+                0,
+                alice_handle.clone()
+            ));
+
+            assert_eq!(get_membership_by_id(0).handle, alice_handle);
+            assert_eq!(Members::handles(alice_handle.clone()), 0);
+            assert!(!<crate::ReservedHandles>::contains_key(&alice_handle));
+        });
+}
+
+#[test]
+fn swap_handles() {
+    let initial_members = [(0, 1), (1, 2)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let first_handle = get_membership_by_id(0).handle;
+            let second_handle = get_membership_by_id(1).handle;
+
+            assert_err!(
+                Members::accept_handle_swap(Origin::signed(2), 1, 0),
+                Error::<Test>::HandleSwapOfferNotFound,
+            );
+
+            assert_ok!(Members::offer_handle_swap(Origin::signed(1), 0, 1));
+
+            // only the counterparty can accept the offer
+            assert_err!(
+                Members::accept_handle_swap(Origin::signed(1), 1, 0),
+                Error::<Test>::ControllerAccountRequired,
+            );
+
+            assert_ok!(Members::accept_handle_swap(Origin::signed(2), 1, 0));
+
+            assert_eq!(get_membership_by_id(0).handle, second_handle);
+            assert_eq!(get_membership_by_id(1).handle, first_handle);
+            assert_eq!(Members::handles(first_handle), 1);
+            assert_eq!(Members::handles(second_handle), 0);
+            assert_eq!(Members::handle_swap_offer(0), None);
+        });
+}
+
+#[test]
+fn transfer_handle() {
+    let initial_members = [(0, 1), (1, 2)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let first_handle = get_membership_by_id(0).handle;
+            let second_handle = get_membership_by_id(1).handle;
+            let replacement_handle = b"replacement".to_vec();
+
+            assert_err!(
+                Members::transfer_handle(Origin::signed(1), 0, 1, replacement_handle.clone()),
+                DispatchError::BadOrigin,
+            );
+
+            assert_err!(
+                Members::transfer_handle(Origin::root(), 0, 0, replacement_handle.clone()),
+                Error::<Test>::CannotTransferHandleToSelf,
+            );
+
+            assert_err!(
+                Members::transfer_handle(Origin::root(), 0, 1, second_handle.clone()),
+                Error::<Test>::HandleAlreadyRegistered,
+            );
+
+            assert_ok!(Members::transfer_handle(
+                Origin::root(),
+                0,
+                1,
+                replacement_handle.clone()
+            ));
+
+            assert_eq!(get_membership_by_id(0).handle, replacement_handle);
+            assert_eq!(get_membership_by_id(1).handle, first_handle);
+            assert_eq!(Members::handles(replacement_handle), 0);
+            assert_eq!(Members::handles(first_handle), 1);
+            assert!(!<crate::MemberIdByHandle<Test>>::contains_key(
+                second_handle
+            ));
+        });
+}
+
 #[test]
 fn update_profile() {
     const DEFAULT_FEE: u64 = 500;
@@ -354,3 +568,354 @@ fn set_root_account() {
             assert!(<crate::MemberIdsByRootAccountId<Test>>::get(&ALICE_ACCOUNT_ID).is_empty());
         });
 }
+
+#[test]
+fn set_member_metadata() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let metadata = b"metadata".to_vec();
+
+            assert_ok!(Members::set_member_metadata(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                metadata.clone()
+            ));
+
+            assert_eq!(Members::member_metadata(member_id), metadata);
+
+            let too_long_metadata = vec![0u8; Members::max_metadata_length() as usize + 1];
+            assert_err!(
+                Members::set_member_metadata(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    too_long_metadata
+                ),
+                Error::<Test>::MetadataTooLong
+            );
+        });
+}
+
+#[test]
+fn verify_external_identity() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const IDENTITY_VERIFIER_ACCOUNT_ID: u64 = 5;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let kind = ExternalIdentityKind::SocialHandle(b"twitter".to_vec());
+            let handle = b"@alice".to_vec();
+
+            assert_ok!(Members::set_external_identity(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                kind.clone(),
+                handle.clone()
+            ));
+
+            // no identity verifier account yet
+            assert_err!(
+                Members::verify_external_identity(
+                    Origin::signed(IDENTITY_VERIFIER_ACCOUNT_ID),
+                    None,
+                    member_id,
+                    kind.clone(),
+                    handle.clone()
+                ),
+                Error::<Test>::NoIdentityVerifierDefined
+            );
+
+            assert_ok!(Members::set_identity_verifier(
+                Origin::root(),
+                IDENTITY_VERIFIER_ACCOUNT_ID
+            ));
+
+            assert_err!(
+                Members::verify_external_identity(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    None,
+                    member_id,
+                    kind.clone(),
+                    handle.clone()
+                ),
+                Error::<Test>::NotIdentityVerifier
+            );
+
+            assert_err!(
+                Members::verify_external_identity(
+                    Origin::signed(IDENTITY_VERIFIER_ACCOUNT_ID),
+                    None,
+                    member_id,
+                    kind.clone(),
+                    b"@bob".to_vec()
+                ),
+                Error::<Test>::ExternalIdentityClaimChanged
+            );
+
+            assert_ok!(Members::verify_external_identity(
+                Origin::signed(IDENTITY_VERIFIER_ACCOUNT_ID),
+                None,
+                member_id,
+                kind.clone(),
+                handle.clone()
+            ));
+
+            assert_eq!(
+                Members::external_identity(member_id, &kind),
+                ExternalIdentityClaim {
+                    value: handle.clone(),
+                    verified_by: Some(IDENTITY_VERIFIER_ACCOUNT_ID),
+                }
+            );
+
+            // setting the same claim again keeps the verification
+            assert_ok!(Members::set_external_identity(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                kind.clone(),
+                handle.clone()
+            ));
+
+            assert_eq!(
+                Members::external_identity(member_id, &kind).verified_by,
+                Some(IDENTITY_VERIFIER_ACCOUNT_ID)
+            );
+
+            // changing the claim clears the verification
+            let new_handle = b"@alice2".to_vec();
+            assert_ok!(Members::set_external_identity(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                kind.clone(),
+                new_handle.clone()
+            ));
+
+            assert_eq!(
+                Members::external_identity(member_id, &kind),
+                ExternalIdentityClaim {
+                    value: new_handle,
+                    verified_by: None,
+                }
+            );
+        });
+}
+
+#[test]
+fn worker_can_verify_external_identity() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let email_hash = b"email-hash".to_vec();
+
+            assert_ok!(Members::set_external_identity(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                ExternalIdentityKind::EmailHash,
+                email_hash.clone()
+            ));
+
+            assert_err!(
+                Members::verify_external_identity(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    Some(IDENTITY_VERIFIER_WORKER_ID),
+                    member_id,
+                    ExternalIdentityKind::EmailHash,
+                    email_hash.clone()
+                ),
+                Error::<Test>::NotIdentityVerifier
+            );
+
+            assert_ok!(Members::verify_external_identity(
+                Origin::signed(IDENTITY_VERIFIER_WORKER_ACCOUNT_ID),
+                Some(IDENTITY_VERIFIER_WORKER_ID),
+                member_id,
+                ExternalIdentityKind::EmailHash,
+                email_hash
+            ));
+
+            assert_eq!(
+                Members::external_identity(member_id, &ExternalIdentityKind::EmailHash).verified_by,
+                Some(IDENTITY_VERIFIER_WORKER_ACCOUNT_ID)
+            );
+
+            assert_ok!(Members::remove_external_identity(
+                Origin::signed(ALICE_ACCOUNT_ID),
+                member_id,
+                ExternalIdentityKind::EmailHash
+            ));
+
+            assert!(!<crate::ExternalIdentityByMemberId<Test>>::contains_key(
+                member_id,
+                &ExternalIdentityKind::EmailHash
+            ));
+        });
+}
+
+#[test]
+fn external_identities_are_limited_per_member() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let member_id = 0;
+            let max_identities = Members::max_external_identities_per_member();
+
+            for platform in 0..max_identities {
+                assert_ok!(Members::set_external_identity(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    ExternalIdentityKind::SocialHandle(platform.to_string().into_bytes()),
+                    b"alice".to_vec()
+                ));
+            }
+
+            assert_err!(
+                Members::set_external_identity(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    member_id,
+                    ExternalIdentityKind::EmailHash,
+                    b"email-hash".to_vec()
+                ),
+                Error::<Test>::TooManyExternalIdentities
+            );
+        });
+}
+
+#[test]
+fn invite_member() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+    const BOB_ACCOUNT_ID: u64 = 2;
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviting_member_id = 0;
+            let initial_invites = Members::initial_invitation_count();
+
+            assert_eq!(Members::invites(inviting_member_id), initial_invites);
+
+            let invite_bob = || {
+                let info = get_bob_info();
+                Members::invite_member(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    inviting_member_id,
+                    BOB_ACCOUNT_ID,
+                    BOB_ACCOUNT_ID,
+                    info.handle,
+                    info.avatar_uri,
+                    info.about,
+                )
+            };
+
+            // no global invitation budget yet
+            assert_err!(invite_bob(), Error::<Test>::InvitationBudgetExhausted);
+
+            assert_ok!(Members::set_invitation_budget(Origin::root(), 1));
+
+            let next_member_id = Members::members_created();
+
+            assert_ok!(invite_bob());
+
+            let profile = get_membership_by_id(next_member_id);
+
+            assert_eq!(Some(profile.handle), get_bob_info().handle);
+            assert_eq!(profile.root_account, BOB_ACCOUNT_ID);
+            assert_eq!(
+                crate::EntryMethod::Invited(inviting_member_id),
+                profile.entry
+            );
+            assert_eq!(Members::invites(inviting_member_id), initial_invites - 1);
+            assert_eq!(Members::invites(next_member_id), initial_invites);
+            assert_eq!(Members::invitation_budget(), 0);
+        });
+}
+
+#[test]
+fn invite_member_fails_without_invites() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            let inviting_member_id = 0;
+
+            assert_ok!(Members::set_invitation_budget(Origin::root(), 10));
+            <crate::InvitesByMemberId<Test>>::insert(inviting_member_id, 0);
+
+            let info = get_bob_info();
+            assert_err!(
+                Members::invite_member(
+                    Origin::signed(ALICE_ACCOUNT_ID),
+                    inviting_member_id,
+                    2,
+                    2,
+                    info.handle,
+                    info.avatar_uri,
+                    info.about,
+                ),
+                Error::<Test>::NotEnoughInvites
+            );
+        });
+}
+
+#[test]
+fn migrate_member_invites() {
+    let initial_members = [(0, ALICE_ACCOUNT_ID), (1, 2)];
+
+    TestExternalitiesBuilder::<Test>::default()
+        .set_membership_config(
+            genesis::GenesisConfigBuilder::default()
+                .members(initial_members.to_vec())
+                .build(),
+        )
+        .build()
+        .execute_with(|| {
+            // Members created before the invitations existed
+            <crate::InvitesByMemberId<Test>>::remove(0);
+            <crate::InvitesByMemberId<Test>>::insert(1, 1);
+
+            Members::migrate_member_invites();
+
+            assert_eq!(Members::invites(0), Members::initial_invitation_count());
+            assert_eq!(Members::invites(1), 1);
+        });
+}
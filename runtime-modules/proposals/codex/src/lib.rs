@@ -16,10 +16,16 @@
 //! - [create_text_proposal](./struct.Module.html#method.create_text_proposal)
 //! - [create_runtime_upgrade_proposal](./struct.Module.html#method.create_runtime_upgrade_proposal)
 //! - [create_set_validator_count_proposal](./struct.Module.html#method.create_set_validator_count_proposal)
+//! - [create_batch_proposal](./struct.Module.html#method.create_batch_proposal)
+//!
+//! ### Membership proposals
+//! - [create_set_membership_invitation_budget_proposal](./struct.Module.html#method.create_set_membership_invitation_budget_proposal)
+//! - [create_set_referral_cut_proposal](./struct.Module.html#method.create_set_referral_cut_proposal)
 //!
 //! ### Council and election proposals
 //! - [create_set_election_parameters_proposal](./struct.Module.html#method.create_set_election_parameters_proposal)
 //! - [create_spending_proposal](./struct.Module.html#method.create_spending_proposal)
+//! - [create_vote_of_no_confidence_proposal](./struct.Module.html#method.create_vote_of_no_confidence_proposal)
 //!
 //! ### Working group proposals
 //! - [create_add_working_group_leader_opening_proposal](./struct.Module.html#method.create_add_working_group_leader_opening_proposal)
@@ -31,9 +37,16 @@
 //! - [create_set_working_group_leader_reward_proposal](./struct.Module.html#method.create_set_working_group_leader_reward_proposal)
 //! - [create_terminate_working_group_leader_role_proposal](./struct.Module.html#method.create_terminate_working_group_leader_role_proposal)
 //!
+//! ### Storage and content proposals
+//! - [create_set_global_storage_voucher_limits_proposal](./struct.Module.html#method.create_set_global_storage_voucher_limits_proposal)
+//! - [create_set_storage_voucher_limit_upper_bounds_proposal](./struct.Module.html#method.create_set_storage_voucher_limit_upper_bounds_proposal)
+//! - [create_set_data_object_type_status_proposal](./struct.Module.html#method.create_set_data_object_type_status_proposal)
+//! - [create_set_max_number_of_curators_per_group_proposal](./struct.Module.html#method.create_set_max_number_of_curators_per_group_proposal)
+//!
 //! ### Proposal implementations of this module
 //! - execute_text_proposal - prints the proposal to the log
 //! - execute_runtime_upgrade_proposal - sets the runtime code
+//! - execute_batch_proposal - executes the batched proposals atomically
 //!
 //! ### Dependencies:
 //! - [proposals engine](../substrate_proposals_engine_module/index.html)
@@ -45,6 +58,8 @@
 //! ### Notes
 //! The module uses [ProposalEncoder](./trait.ProposalEncoder.html) to encode the proposal using
 //! its details. Encoded byte vector is passed to the _proposals engine_ as serialized executable code.
+//! Every proposal can be created with an optional `exactly_execute_at` block: the approved proposal
+//! is executed at this block instead of right after its grace period.
 
 // `decl_module!` does a lot of recursion and requires us to increase the limit to 256.
 #![recursion_limit = "256"]
@@ -61,11 +76,14 @@ mod proposal_types;
 #[cfg(test)]
 mod tests;
 
-use frame_support::dispatch::DispatchResult;
-use frame_support::traits::{Currency, Get};
+use codec::Decode;
+use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::storage::with_transaction;
+use frame_support::traits::{Currency, Get, UnfilteredDispatchable};
 use frame_support::{decl_error, decl_module, decl_storage, ensure, print};
-use frame_system::ensure_root;
+use frame_system::{ensure_root, RawOrigin};
 use sp_arithmetic::traits::Zero;
+use sp_runtime::TransactionOutcome;
 use sp_std::clone::Clone;
 use sp_std::str::from_utf8;
 use sp_std::vec::Vec;
@@ -76,7 +94,8 @@ use governance::election_params::ElectionParameters;
 use proposals_engine::ProposalParameters;
 
 pub use crate::proposal_types::{
-    AddOpeningParameters, FillOpeningParameters, ProposalsConfigParameters, TerminateRoleParameters,
+    AddOpeningParameters, FillOpeningParameters, NoConfidenceParameters, ProposalsConfigParameters,
+    TerminateRoleParameters,
 };
 pub use proposal_types::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 
@@ -86,6 +105,12 @@ const WORKING_GROUP_MINT_CAPACITY_MAX_VALUE: u32 = 5_000_000;
 const MAX_SPENDING_PROPOSAL_VALUE: u32 = 50_000_000_u32;
 // Max validator count for the 'set validator count' proposal
 const MAX_VALIDATOR_COUNT: u32 = 300;
+// Max invitation budget for the 'set membership invitation budget' proposal
+const MAX_MEMBERSHIP_INVITATION_BUDGET: u32 = 100_000;
+// Max referral cut for the 'set referral cut' proposal
+const MAX_REFERRAL_CUT: u8 = 100;
+// Max number of the proposals in the 'batch' proposal
+const MAX_BATCH_PROPOSAL_SIZE: u32 = 10;
 // council_size min value for the 'set election parameters' proposal
 const ELECTION_PARAMETERS_COUNCIL_SIZE_MIN_VALUE: u32 = 6;
 // council_size max value for the 'set election parameters' proposal
@@ -127,7 +152,7 @@ struct CreateProposalParameters<T: Trait> {
     pub title: Vec<u8>,
     pub description: Vec<u8>,
     pub stake_balance: Option<BalanceOf<T>>,
-    pub proposal_code: Vec<u8>,
+    pub exactly_execute_at: Option<T::BlockNumber>,
     pub proposal_parameters: ProposalParameters<T::BlockNumber, BalanceOf<T>>,
     pub proposal_details: ProposalDetailsOf<T>,
 }
@@ -245,6 +270,30 @@ decl_error! {
 
         /// Invalid 'decrease stake proposal' parameter - cannot decrease by zero balance.
         DecreasingStakeIsZero,
+
+        /// Invalid invitation budget for the 'set membership invitation budget' proposal
+        InvalidMembershipInvitationBudget,
+
+        /// Invalid referral cut for the 'set referral cut' proposal
+        InvalidReferralCut,
+
+        /// Target of the 'vote of no confidence' proposal is not a councilor
+        NoConfidenceTargetIsNotCouncilor,
+
+        /// The 'batch' proposal cannot be empty
+        BatchProposalIsEmpty,
+
+        /// The 'batch' proposal contains too many proposals
+        BatchProposalSizeExceeded,
+
+        /// The proposal type cannot be included in the 'batch' proposal
+        ProposalCannotBeBatched,
+
+        /// Cannot decode the batched proposal call
+        InvalidBatchedProposalCall,
+
+        /// The proposal to execute before the 'batch' proposal does not exist
+        ExecutionDependencyNotFound,
     }
 }
 
@@ -351,6 +400,62 @@ decl_storage! {
         /// Grace period for the 'terminate working group leader role' proposal
         pub TerminateWorkingGroupLeaderRoleProposalGracePeriod get(fn terminate_working_group_leader_role_proposal_grace_period)
             config(): T::BlockNumber;
+
+        /// Voting period for the 'set membership invitation budget' proposal
+        pub SetMembershipInvitationBudgetProposalVotingPeriod get(fn set_membership_invitation_budget_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set membership invitation budget' proposal
+        pub SetMembershipInvitationBudgetProposalGracePeriod get(fn set_membership_invitation_budget_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set referral cut' proposal
+        pub SetReferralCutProposalVotingPeriod get(fn set_referral_cut_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set referral cut' proposal
+        pub SetReferralCutProposalGracePeriod get(fn set_referral_cut_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'vote of no confidence' proposal
+        pub VoteOfNoConfidenceProposalVotingPeriod get(fn vote_of_no_confidence_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'vote of no confidence' proposal
+        pub VoteOfNoConfidenceProposalGracePeriod get(fn vote_of_no_confidence_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set global storage voucher limits' proposal
+        pub SetGlobalStorageVoucherLimitsProposalVotingPeriod get(fn set_global_storage_voucher_limits_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set global storage voucher limits' proposal
+        pub SetGlobalStorageVoucherLimitsProposalGracePeriod get(fn set_global_storage_voucher_limits_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set storage voucher limit upper bounds' proposal
+        pub SetStorageVoucherLimitUpperBoundsProposalVotingPeriod get(fn set_storage_voucher_limit_upper_bounds_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set storage voucher limit upper bounds' proposal
+        pub SetStorageVoucherLimitUpperBoundsProposalGracePeriod get(fn set_storage_voucher_limit_upper_bounds_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set data object type status' proposal
+        pub SetDataObjectTypeStatusProposalVotingPeriod get(fn set_data_object_type_status_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set data object type status' proposal
+        pub SetDataObjectTypeStatusProposalGracePeriod get(fn set_data_object_type_status_proposal_grace_period)
+            config(): T::BlockNumber;
+
+        /// Voting period for the 'set max number of curators per group' proposal
+        pub SetMaxNumberOfCuratorsPerGroupProposalVotingPeriod get(fn set_max_number_of_curators_per_group_proposal_voting_period)
+            config(): T::BlockNumber;
+
+        /// Grace period for the 'set max number of curators per group' proposal
+        pub SetMaxNumberOfCuratorsPerGroupProposalGracePeriod get(fn set_max_number_of_curators_per_group_proposal_grace_period)
+            config(): T::BlockNumber;
     }
 }
 
@@ -375,6 +480,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             text: Vec<u8>,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
             ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
             ensure!(text.len() as u32 <=  T::TextProposalMaxLength::get(),
@@ -387,9 +493,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::text_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -405,6 +511,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             wasm: Vec<u8>,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
             ensure!(!wasm.is_empty(), Error::<T>::RuntimeProposalIsEmpty);
             ensure!(wasm.len() as u32 <= T::RuntimeUpgradeWasmProposalMaxLength::get(),
@@ -417,9 +524,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::runtime_upgrade_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -435,6 +542,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             election_parameters: ElectionParameters<BalanceOfGovernanceCurrency<T>, T::BlockNumber>,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
             election_parameters.ensure_valid()?;
 
@@ -447,9 +555,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::set_election_parameters_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -466,6 +574,7 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             balance: BalanceOfMint<T>,
             destination: T::AccountId,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
             ensure!(balance != BalanceOfMint::<T>::zero(), Error::<T>::InvalidSpendingProposalBalance);
             ensure!(
@@ -480,9 +589,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::spending_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -498,6 +607,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             new_validator_count: u32,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
             ensure!(
                 new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
@@ -516,9 +626,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::set_validator_count_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -534,6 +644,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             add_opening_parameters: AddOpeningParameters<T::BlockNumber, BalanceOfGovernanceCurrency<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::AddWorkingGroupLeaderOpening(add_opening_parameters);
@@ -543,9 +654,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::add_working_group_leader_opening_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -562,6 +673,7 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             opening_id: working_group::OpeningId<T>,
             working_group: WorkingGroup,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::BeginReviewWorkingGroupLeaderApplications(opening_id, working_group);
@@ -571,9 +683,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -593,7 +705,8 @@ decl_module! {
                 BalanceOfMint<T>,
                 working_group::OpeningId<T>,
                 working_group::ApplicationId<T>
-            >
+            >,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::FillWorkingGroupLeaderOpening(fill_opening_parameters);
@@ -603,9 +716,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::fill_working_group_leader_opening_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -613,6 +726,7 @@ decl_module! {
 
         /// Create 'Set working group mint capacity' proposal type.
         /// This proposal uses `set_mint_capacity()` extrinsic from the `working-group`  module.
+        /// When the working group budget is set, the capacity also becomes its spending ceiling.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn create_set_working_group_mint_capacity_proposal(
             origin,
@@ -622,6 +736,7 @@ decl_module! {
             stake_balance: Option<BalanceOf<T>>,
             mint_balance: BalanceOfMint<T>,
             working_group: WorkingGroup,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
             ensure!(
                 mint_balance <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
@@ -635,9 +750,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::set_working_group_mint_capacity_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -655,6 +770,7 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             decreasing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
 
             ensure!(decreasing_stake != Zero::zero(), Error::<T>::DecreasingStakeIsZero);
@@ -671,9 +787,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::decrease_working_group_leader_stake_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -691,6 +807,7 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             slashing_stake: BalanceOf<T>,
             working_group: WorkingGroup,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
 
             ensure!(slashing_stake != Zero::zero(), Error::<T>::SlashingStakeIsZero);
@@ -707,9 +824,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::slash_working_group_leader_stake_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -727,6 +844,7 @@ decl_module! {
             worker_id: working_group::WorkerId<T>,
             reward_amount: BalanceOfMint<T>,
             working_group: WorkingGroup,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
 
             let proposal_details = ProposalDetails::SetWorkingGroupLeaderReward(
@@ -741,9 +859,9 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::set_working_group_leader_reward_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
@@ -759,6 +877,7 @@ decl_module! {
             description: Vec<u8>,
             stake_balance: Option<BalanceOf<T>>,
             terminate_role_parameters: TerminateRoleParameters<working_group::WorkerId<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
         ) {
             let proposal_details = ProposalDetails::TerminateWorkingGroupLeaderRole(terminate_role_parameters);
 
@@ -768,14 +887,289 @@ decl_module! {
                 title,
                 description,
                 stake_balance,
-                proposal_details: proposal_details.clone(),
+                exactly_execute_at,
+                proposal_details,
                 proposal_parameters: proposal_types::parameters::terminate_working_group_leader_role_proposal::<T>(),
-                proposal_code: T::ProposalEncoder::encode_proposal(proposal_details)
             };
 
             Self::create_proposal(params)?;
         }
 
+        /// Create 'set membership invitation budget' proposal type.
+        /// This proposal uses `set_invitation_budget()` extrinsic from the `membership`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_membership_invitation_budget_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            invitation_budget: u32,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            ensure!(
+                invitation_budget <= MAX_MEMBERSHIP_INVITATION_BUDGET,
+                Error::<T>::InvalidMembershipInvitationBudget
+            );
+
+            let proposal_details = ProposalDetails::SetMembershipInvitationBudget(invitation_budget);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters: proposal_types::parameters::set_membership_invitation_budget_proposal::<T>(),
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set referral cut' proposal type.
+        /// This proposal uses `set_referral_cut()` extrinsic from the `membership`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_referral_cut_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            referral_cut: u8,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            ensure!(
+                referral_cut <= MAX_REFERRAL_CUT,
+                Error::<T>::InvalidReferralCut
+            );
+
+            let proposal_details = ProposalDetails::SetReferralCut(referral_cut);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters: proposal_types::parameters::set_referral_cut_proposal::<T>(),
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'vote of no confidence' proposal type.
+        /// This proposal uses `remove_councilor_by_no_confidence()` extrinsic from the `council`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_vote_of_no_confidence_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            no_confidence_parameters: NoConfidenceParameters<MemberId<T>>,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            let councilor_id = no_confidence_parameters.councilor_id;
+
+            ensure!(
+                <governance::council::Module<T>>::is_councilor(&councilor_id),
+                Error::<T>::NoConfidenceTargetIsNotCouncilor
+            );
+
+            let proposal_details = ProposalDetails::VoteOfNoConfidence(no_confidence_parameters);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters: proposal_types::parameters::vote_of_no_confidence_proposal::<T>(),
+            };
+
+            let proposal_id = Self::create_proposal(params)?;
+
+            // The councilor cannot vote on their own removal.
+            <proposals_engine::Module<T>>::exclude_voter(proposal_id, councilor_id)?;
+        }
+
+        /// Create 'Batch' proposal type. The batched proposals are executed atomically in the
+        /// provided order using the `execute_batch_proposal()` extrinsic. The proposal uses the
+        /// strictest parameters of the batched proposals. With `execute_after` provided the proposal
+        /// is executed only after the execution of that proposal.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_batch_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            proposals: Vec<ProposalDetailsOf<T>>,
+            execute_after: Option<T::ProposalId>,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            ensure!(!proposals.is_empty(), Error::<T>::BatchProposalIsEmpty);
+            ensure!(
+                proposals.len() as u32 <= MAX_BATCH_PROPOSAL_SIZE,
+                Error::<T>::BatchProposalSizeExceeded
+            );
+
+            for proposal_details in proposals.iter() {
+                Self::ensure_batched_proposal_details_valid(proposal_details)?;
+            }
+
+            if let Some(dependency_id) = execute_after {
+                ensure!(
+                    <proposals_engine::Proposals<T>>::contains_key(dependency_id),
+                    Error::<T>::ExecutionDependencyNotFound
+                );
+            }
+
+            let proposal_parameters = proposal_types::parameters::batch_proposal::<T>(&proposals);
+            let proposal_details = ProposalDetails::Batch(proposals);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters,
+            };
+
+            let proposal_id = Self::create_proposal(params)?;
+
+            if let Some(dependency_id) = execute_after {
+                <proposals_engine::Module<T>>::set_execution_dependency(proposal_id, dependency_id)?;
+            }
+        }
+
+        /// Create 'set global storage voucher limits' proposal type.
+        /// This proposal uses `set_global_voucher_size_limit()` and `set_global_voucher_objects_limit()`
+        /// extrinsics from the `data_directory` module executed atomically as a batch.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_global_storage_voucher_limits_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            size_limit: u64,
+            objects_limit: u64,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            let proposal_details = ProposalDetails::SetGlobalStorageVoucherLimits(size_limit, objects_limit);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters: proposal_types::parameters::set_global_storage_voucher_limits_proposal::<T>(),
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set storage voucher limit upper bounds' proposal type.
+        /// This proposal uses `set_voucher_size_limit_upper_bound()` and
+        /// `set_voucher_objects_limit_upper_bound()` extrinsics from the `data_directory` module
+        /// executed atomically as a batch.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_storage_voucher_limit_upper_bounds_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            size_limit_upper_bound: u64,
+            objects_limit_upper_bound: u64,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            let proposal_details = ProposalDetails::SetStorageVoucherLimitUpperBounds(size_limit_upper_bound, objects_limit_upper_bound);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters: proposal_types::parameters::set_storage_voucher_limit_upper_bounds_proposal::<T>(),
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set data object type status' proposal type.
+        /// This proposal uses `activate_data_object_type()` or `deactivate_data_object_type()` extrinsic
+        /// from the `data_object_type_registry` module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_data_object_type_status_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            data_object_type_id: u64,
+            is_active: bool,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            let proposal_details = ProposalDetails::SetDataObjectTypeStatus(data_object_type_id, is_active);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters: proposal_types::parameters::set_data_object_type_status_proposal::<T>(),
+            };
+
+            Self::create_proposal(params)?;
+        }
+
+        /// Create 'set max number of curators per group' proposal type.
+        /// This proposal uses `set_max_number_of_curators_per_group()` extrinsic from the `content`  module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn create_set_max_number_of_curators_per_group_proposal(
+            origin,
+            member_id: MemberId<T>,
+            title: Vec<u8>,
+            description: Vec<u8>,
+            stake_balance: Option<BalanceOf<T>>,
+            max_number_of_curators_per_group: u32,
+            exactly_execute_at: Option<T::BlockNumber>,
+        ) {
+            let proposal_details = ProposalDetails::SetMaxNumberOfCuratorsPerGroup(max_number_of_curators_per_group);
+
+            let params = CreateProposalParameters{
+                origin,
+                member_id,
+                title,
+                description,
+                stake_balance,
+                exactly_execute_at,
+                proposal_details,
+                proposal_parameters: proposal_types::parameters::set_max_number_of_curators_per_group_proposal::<T>(),
+            };
+
+            Self::create_proposal(params)?;
+        }
 
 // *************** Extrinsic to execute
 
@@ -809,12 +1203,35 @@ decl_module! {
 
             print("Runtime upgrade proposal execution finished.");
         }
+
+        /// Batch proposal extrinsic. Dispatches the encoded proposal calls in the provided order.
+        /// Changes made by the batch are reverted if any of the calls fails.
+        /// Should be used as callable object to pass to the `engine` module.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn execute_batch_proposal(
+            origin,
+            calls: Vec<Vec<u8>>,
+        ) {
+            ensure_root(origin)?;
+
+            with_transaction(|| {
+                for encoded_call in calls.iter() {
+                    if let Err(error) = Self::dispatch_batched_proposal_call(encoded_call) {
+                        return TransactionOutcome::Rollback(Err(error));
+                    }
+                }
+
+                TransactionOutcome::Commit(Ok(()))
+            })?;
+        }
     }
 }
 
 impl<T: Trait> Module<T> {
     // Generic template proposal builder
-    fn create_proposal(params: CreateProposalParameters<T>) -> DispatchResult {
+    fn create_proposal(
+        params: CreateProposalParameters<T>,
+    ) -> Result<T::ProposalId, DispatchError> {
         let account_id =
             T::MembershipOriginValidator::ensure_actor_origin(params.origin, params.member_id)?;
 
@@ -824,6 +1241,7 @@ impl<T: Trait> Module<T> {
             &params.description,
             params.stake_balance,
             &account_id,
+            params.exactly_execute_at,
         )?;
 
         <proposals_discussion::Module<T>>::ensure_can_create_thread(
@@ -843,12 +1261,115 @@ impl<T: Trait> Module<T> {
             params.title,
             params.description,
             params.stake_balance,
-            params.proposal_code,
+            Vec::new(),
+            params.exactly_execute_at,
         )?;
 
+        // The proposal code can reference the proposal itself (eg.: the spending purpose), so it
+        // is encoded with the proposal id returned by the engine.
+        let proposal_code =
+            T::ProposalEncoder::encode_proposal(proposal_id, params.proposal_details.clone());
+        <proposals_engine::DispatchableCallCode<T>>::insert(proposal_id, proposal_code);
+
         <ThreadIdByProposalId<T>>::insert(proposal_id, discussion_thread_id);
         <ProposalDetailsByProposalId<T>>::insert(proposal_id, params.proposal_details);
 
+        Ok(proposal_id)
+    }
+
+    // Dispatches the encoded call of the batched proposal using the root origin
+    fn dispatch_batched_proposal_call(encoded_call: &[u8]) -> DispatchResult {
+        let call = T::DispatchableCallCode::decode(&mut &encoded_call[..])
+            .map_err(|_| Error::<T>::InvalidBatchedProposalCall)?;
+
+        call.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
+            .map(|_| ())
+            .map_err(|error| error.error)
+    }
+
+    // Validates the proposal included in the 'batch' proposal. It performs the same checks as the
+    // extrinsic creating the proposal. Proposals requiring additional actions on creation cannot
+    // be batched.
+    fn ensure_batched_proposal_details_valid(
+        proposal_details: &ProposalDetailsOf<T>,
+    ) -> DispatchResult {
+        match proposal_details {
+            ProposalDetails::Text(text) => {
+                ensure!(!text.is_empty(), Error::<T>::TextProposalIsEmpty);
+                ensure!(
+                    text.len() as u32 <= T::TextProposalMaxLength::get(),
+                    Error::<T>::TextProposalSizeExceeded
+                );
+            }
+            ProposalDetails::SetElectionParameters(election_parameters) => {
+                election_parameters.ensure_valid()?;
+                Self::ensure_council_election_parameters_valid(election_parameters)?;
+            }
+            ProposalDetails::Spending(balance, _) => {
+                ensure!(
+                    *balance != BalanceOfMint::<T>::zero(),
+                    Error::<T>::InvalidSpendingProposalBalance
+                );
+                ensure!(
+                    *balance <= <BalanceOfMint<T>>::from(MAX_SPENDING_PROPOSAL_VALUE),
+                    Error::<T>::InvalidSpendingProposalBalance
+                );
+            }
+            ProposalDetails::SetValidatorCount(new_validator_count) => {
+                ensure!(
+                    *new_validator_count >= <staking::Module<T>>::minimum_validator_count(),
+                    Error::<T>::InvalidValidatorCount
+                );
+                ensure!(
+                    *new_validator_count <= MAX_VALIDATOR_COUNT,
+                    Error::<T>::InvalidValidatorCount
+                );
+            }
+            ProposalDetails::SetWorkingGroupMintCapacity(mint_balance, _) => {
+                ensure!(
+                    *mint_balance
+                        <= <BalanceOfMint<T>>::from(WORKING_GROUP_MINT_CAPACITY_MAX_VALUE),
+                    Error::<T>::InvalidWorkingGroupMintCapacity
+                );
+            }
+            ProposalDetails::DecreaseWorkingGroupLeaderStake(_, decreasing_stake, _) => {
+                ensure!(
+                    *decreasing_stake != Zero::zero(),
+                    Error::<T>::DecreasingStakeIsZero
+                );
+            }
+            ProposalDetails::SlashWorkingGroupLeaderStake(_, slashing_stake, _) => {
+                ensure!(
+                    *slashing_stake != Zero::zero(),
+                    Error::<T>::SlashingStakeIsZero
+                );
+            }
+            ProposalDetails::SetMembershipInvitationBudget(invitation_budget) => {
+                ensure!(
+                    *invitation_budget <= MAX_MEMBERSHIP_INVITATION_BUDGET,
+                    Error::<T>::InvalidMembershipInvitationBudget
+                );
+            }
+            ProposalDetails::SetReferralCut(referral_cut) => {
+                ensure!(
+                    *referral_cut <= MAX_REFERRAL_CUT,
+                    Error::<T>::InvalidReferralCut
+                );
+            }
+            ProposalDetails::AddWorkingGroupLeaderOpening(..)
+            | ProposalDetails::BeginReviewWorkingGroupLeaderApplications(..)
+            | ProposalDetails::FillWorkingGroupLeaderOpening(..)
+            | ProposalDetails::SetWorkingGroupLeaderReward(..)
+            | ProposalDetails::TerminateWorkingGroupLeaderRole(..)
+            | ProposalDetails::SetGlobalStorageVoucherLimits(..)
+            | ProposalDetails::SetStorageVoucherLimitUpperBounds(..)
+            | ProposalDetails::SetDataObjectTypeStatus(..)
+            | ProposalDetails::SetMaxNumberOfCuratorsPerGroup(..) => {}
+            // The runtime upgrade is limited to the allowed proposers, the vote of no confidence
+            // excludes the voter on creation and the deprecated proposals cannot be executed.
+            _ => return Err(Error::<T>::ProposalCannotBeBatched.into()),
+        }
+
         Ok(())
     }
 
@@ -1036,5 +1557,55 @@ impl<T: Trait> Module<T> {
         <TerminateWorkingGroupLeaderRoleProposalGracePeriod<T>>::put(T::BlockNumber::from(
             p.terminate_working_group_leader_role_proposal_grace_period,
         ));
+
+        Self::migrate_config_values(p);
+    }
+
+    /// Sets config values for the proposal types added by the current runtime version, keeping
+    /// the values of the existing proposal types.
+    /// Should be called on the migration to the new runtime version.
+    pub fn migrate_config_values(p: ProposalsConfigParameters) {
+        <SetMembershipInvitationBudgetProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_membership_invitation_budget_proposal_voting_period,
+        ));
+        <SetMembershipInvitationBudgetProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_membership_invitation_budget_proposal_grace_period,
+        ));
+        <SetReferralCutProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_referral_cut_proposal_voting_period,
+        ));
+        <SetReferralCutProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_referral_cut_proposal_grace_period,
+        ));
+        <VoteOfNoConfidenceProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.vote_of_no_confidence_proposal_voting_period,
+        ));
+        <VoteOfNoConfidenceProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.vote_of_no_confidence_proposal_grace_period,
+        ));
+        <SetGlobalStorageVoucherLimitsProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_global_storage_voucher_limits_proposal_voting_period,
+        ));
+        <SetGlobalStorageVoucherLimitsProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_global_storage_voucher_limits_proposal_grace_period,
+        ));
+        <SetStorageVoucherLimitUpperBoundsProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_storage_voucher_limit_upper_bounds_proposal_voting_period,
+        ));
+        <SetStorageVoucherLimitUpperBoundsProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_storage_voucher_limit_upper_bounds_proposal_grace_period,
+        ));
+        <SetDataObjectTypeStatusProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_data_object_type_status_proposal_voting_period,
+        ));
+        <SetDataObjectTypeStatusProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_data_object_type_status_proposal_grace_period,
+        ));
+        <SetMaxNumberOfCuratorsPerGroupProposalVotingPeriod<T>>::put(T::BlockNumber::from(
+            p.set_max_number_of_curators_per_group_proposal_voting_period,
+        ));
+        <SetMaxNumberOfCuratorsPerGroupProposalGracePeriod<T>>::put(T::BlockNumber::from(
+            p.set_max_number_of_curators_per_group_proposal_grace_period,
+        ));
     }
 }
@@ -12,8 +12,12 @@ use common::working_group::WorkingGroup;
 
 /// Encodes proposal using its details information.
 pub trait ProposalEncoder<T: crate::Trait> {
-    /// Encodes proposal using its details information.
-    fn encode_proposal(proposal_details: ProposalDetailsOf<T>) -> Vec<u8>;
+    /// Encodes proposal using its details information. The proposal id is provided for the calls
+    /// referencing the proposal.
+    fn encode_proposal(
+        proposal_id: <T as proposals_engine::Trait>::ProposalId,
+        proposal_details: ProposalDetailsOf<T>,
+    ) -> Vec<u8>;
 }
 
 /// _ProposalDetails_ alias for type simplification
@@ -32,6 +36,9 @@ pub type ProposalDetailsOf<T> = ProposalDetails<
 /// Proposal details provide voters the information required for the perceived voting.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
+// The type is recursive because of the batch proposal: plain trait bounds for the type parameters
+// prevent the codec derive from looping over the nested details type.
+#[codec(dumb_trait_bound)]
 pub enum ProposalDetails<
     MintedBalance,
     CurrencyBalance,
@@ -103,6 +110,44 @@ pub enum ProposalDetails<
 
     /// Fire the working group leader with possible slashing.
     TerminateWorkingGroupLeaderRole(TerminateRoleParameters<WorkerId>),
+
+    /// Set the number of membership invitations that can be used by all members in total.
+    SetMembershipInvitationBudget(u32),
+
+    /// Set the percentage of the paid membership fee transferred to the referrer.
+    SetReferralCut(u8),
+
+    /// Remove the councilor who lost the confidence of the council.
+    VoteOfNoConfidence(NoConfidenceParameters<MemberId>),
+
+    /// Proposals executed atomically in the provided order.
+    Batch(
+        Vec<
+            ProposalDetails<
+                MintedBalance,
+                CurrencyBalance,
+                BlockNumber,
+                AccountId,
+                OpeningId,
+                ApplicationId,
+                StakeBalance,
+                WorkerId,
+                MemberId,
+            >,
+        >,
+    ),
+
+    /// Set the global storage voucher size and objects limits.
+    SetGlobalStorageVoucherLimits(u64, u64),
+
+    /// Set the storage voucher size and objects limit upper bounds.
+    SetStorageVoucherLimitUpperBounds(u64, u64),
+
+    /// Activate or deactivate the data object type.
+    SetDataObjectTypeStatus(u64, bool),
+
+    /// Set the max number of curators per content curator group.
+    SetMaxNumberOfCuratorsPerGroup(u32),
 }
 
 impl<
@@ -150,6 +195,20 @@ pub struct TerminateRoleParameters<WorkerId> {
     pub working_group: WorkingGroup,
 }
 
+/// Parameters for the 'vote of no confidence' proposal.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Debug)]
+pub struct NoConfidenceParameters<MemberId> {
+    /// Member id of the councilor to remove. The councilor cannot vote on the proposal.
+    pub councilor_id: MemberId,
+
+    /// Slash the councilor stake on removal, refund it otherwise.
+    pub slash_stake: bool,
+
+    /// Start a by-election for the vacant seat.
+    pub start_by_election: bool,
+}
+
 /// Parameters for the 'fill opening for the leader position' proposal.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
@@ -309,6 +368,48 @@ pub struct ProposalsConfigParameters {
 
     /// 'Terminate working group leader role' proposal grace period
     pub terminate_working_group_leader_role_proposal_grace_period: u32,
+
+    /// 'Set membership invitation budget' proposal voting period
+    pub set_membership_invitation_budget_proposal_voting_period: u32,
+
+    /// 'Set membership invitation budget' proposal grace period
+    pub set_membership_invitation_budget_proposal_grace_period: u32,
+
+    /// 'Set referral cut' proposal voting period
+    pub set_referral_cut_proposal_voting_period: u32,
+
+    /// 'Set referral cut' proposal grace period
+    pub set_referral_cut_proposal_grace_period: u32,
+
+    /// 'Vote of no confidence' proposal voting period
+    pub vote_of_no_confidence_proposal_voting_period: u32,
+
+    /// 'Vote of no confidence' proposal grace period
+    pub vote_of_no_confidence_proposal_grace_period: u32,
+
+    /// 'Set global storage voucher limits' proposal voting period
+    pub set_global_storage_voucher_limits_proposal_voting_period: u32,
+
+    /// 'Set global storage voucher limits' proposal grace period
+    pub set_global_storage_voucher_limits_proposal_grace_period: u32,
+
+    /// 'Set storage voucher limit upper bounds' proposal voting period
+    pub set_storage_voucher_limit_upper_bounds_proposal_voting_period: u32,
+
+    /// 'Set storage voucher limit upper bounds' proposal grace period
+    pub set_storage_voucher_limit_upper_bounds_proposal_grace_period: u32,
+
+    /// 'Set data object type status' proposal voting period
+    pub set_data_object_type_status_proposal_voting_period: u32,
+
+    /// 'Set data object type status' proposal grace period
+    pub set_data_object_type_status_proposal_grace_period: u32,
+
+    /// 'Set max number of curators per group' proposal voting period
+    pub set_max_number_of_curators_per_group_proposal_voting_period: u32,
+
+    /// 'Set max number of curators per group' proposal grace period
+    pub set_max_number_of_curators_per_group_proposal_grace_period: u32,
 }
 
 impl Default for ProposalsConfigParameters {
@@ -342,6 +443,20 @@ impl Default for ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0u32,
             terminate_working_group_leader_role_proposal_voting_period: 72200u32,
             terminate_working_group_leader_role_proposal_grace_period: 0u32,
+            set_membership_invitation_budget_proposal_voting_period: 43200u32,
+            set_membership_invitation_budget_proposal_grace_period: 0u32,
+            set_referral_cut_proposal_voting_period: 43200u32,
+            set_referral_cut_proposal_grace_period: 0u32,
+            vote_of_no_confidence_proposal_voting_period: 72200u32,
+            vote_of_no_confidence_proposal_grace_period: 0u32,
+            set_global_storage_voucher_limits_proposal_voting_period: 43200u32,
+            set_global_storage_voucher_limits_proposal_grace_period: 0u32,
+            set_storage_voucher_limit_upper_bounds_proposal_voting_period: 43200u32,
+            set_storage_voucher_limit_upper_bounds_proposal_grace_period: 0u32,
+            set_data_object_type_status_proposal_voting_period: 43200u32,
+            set_data_object_type_status_proposal_grace_period: 0u32,
+            set_max_number_of_curators_per_group_proposal_voting_period: 43200u32,
+            set_max_number_of_curators_per_group_proposal_grace_period: 0u32,
         }
     }
 }
@@ -380,6 +495,20 @@ impl ProposalsConfigParameters {
             set_working_group_leader_reward_proposal_grace_period: 0,
             terminate_working_group_leader_role_proposal_voting_period: voting_period,
             terminate_working_group_leader_role_proposal_grace_period: 0,
+            set_membership_invitation_budget_proposal_voting_period: voting_period,
+            set_membership_invitation_budget_proposal_grace_period: 0,
+            set_referral_cut_proposal_voting_period: voting_period,
+            set_referral_cut_proposal_grace_period: 0,
+            vote_of_no_confidence_proposal_voting_period: voting_period,
+            vote_of_no_confidence_proposal_grace_period: 0,
+            set_global_storage_voucher_limits_proposal_voting_period: voting_period,
+            set_global_storage_voucher_limits_proposal_grace_period: 0,
+            set_storage_voucher_limit_upper_bounds_proposal_voting_period: voting_period,
+            set_storage_voucher_limit_upper_bounds_proposal_grace_period: 0,
+            set_data_object_type_status_proposal_voting_period: voting_period,
+            set_data_object_type_status_proposal_grace_period: 0,
+            set_max_number_of_curators_per_group_proposal_voting_period: voting_period,
+            set_max_number_of_curators_per_group_proposal_grace_period: 0,
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::{BalanceOf, Module, ProposalParameters};
+use crate::{BalanceOf, Module, ProposalDetails, ProposalDetailsOf, ProposalParameters};
 
 // Proposal parameters for the 'Set validator count' proposal
 pub(crate) fn set_validator_count_proposal<T: crate::Trait>(
@@ -182,3 +182,179 @@ pub(crate) fn terminate_working_group_leader_role_proposal<T: crate::Trait>(
         required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
     }
 }
+
+// Proposal parameters for the 'Set membership invitation budget' proposal
+pub(crate) fn set_membership_invitation_budget_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_membership_invitation_budget_proposal_voting_period(),
+        grace_period: <Module<T>>::set_membership_invitation_budget_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
+
+// Proposal parameters for the 'Set referral cut' proposal
+pub(crate) fn set_referral_cut_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_referral_cut_proposal_voting_period(),
+        grace_period: <Module<T>>::set_referral_cut_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
+
+// Proposal parameters for the 'Vote of no confidence' proposal
+pub(crate) fn vote_of_no_confidence_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::vote_of_no_confidence_proposal_voting_period(),
+        grace_period: <Module<T>>::vote_of_no_confidence_proposal_grace_period(),
+        approval_quorum_percentage: 66,
+        approval_threshold_percentage: 80,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(100_000_u32)),
+    }
+}
+
+// Proposal parameters for the 'Set global storage voucher limits' proposal
+pub(crate) fn set_global_storage_voucher_limits_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_global_storage_voucher_limits_proposal_voting_period(),
+        grace_period: <Module<T>>::set_global_storage_voucher_limits_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
+
+// Proposal parameters for the 'Set storage voucher limit upper bounds' proposal
+pub(crate) fn set_storage_voucher_limit_upper_bounds_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_storage_voucher_limit_upper_bounds_proposal_voting_period(),
+        grace_period: <Module<T>>::set_storage_voucher_limit_upper_bounds_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
+
+// Proposal parameters for the 'Set data object type status' proposal
+pub(crate) fn set_data_object_type_status_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_data_object_type_status_proposal_voting_period(),
+        grace_period: <Module<T>>::set_data_object_type_status_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
+
+// Proposal parameters for the 'Set max number of curators per group' proposal
+pub(crate) fn set_max_number_of_curators_per_group_proposal<T: crate::Trait>(
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    ProposalParameters {
+        voting_period: <Module<T>>::set_max_number_of_curators_per_group_proposal_voting_period(),
+        grace_period: <Module<T>>::set_max_number_of_curators_per_group_proposal_grace_period(),
+        approval_quorum_percentage: 60,
+        approval_threshold_percentage: 75,
+        slashing_quorum_percentage: 60,
+        slashing_threshold_percentage: 80,
+        required_stake: Some(<BalanceOf<T>>::from(25000u32)),
+    }
+}
+
+// Proposal parameters for the 'Batch' proposal: the strictest parameters of the batched proposals
+pub(crate) fn batch_proposal<T: crate::Trait>(
+    proposals: &[ProposalDetailsOf<T>],
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    proposals.iter().map(batched_proposal::<T>).fold(
+        ProposalParameters::default(),
+        |batch, item| ProposalParameters {
+            voting_period: batch.voting_period.max(item.voting_period),
+            grace_period: batch.grace_period.max(item.grace_period),
+            approval_quorum_percentage: batch
+                .approval_quorum_percentage
+                .max(item.approval_quorum_percentage),
+            approval_threshold_percentage: batch
+                .approval_threshold_percentage
+                .max(item.approval_threshold_percentage),
+            slashing_quorum_percentage: batch
+                .slashing_quorum_percentage
+                .max(item.slashing_quorum_percentage),
+            slashing_threshold_percentage: batch
+                .slashing_threshold_percentage
+                .max(item.slashing_threshold_percentage),
+            required_stake: batch.required_stake.max(item.required_stake),
+        },
+    )
+}
+
+// Proposal parameters of the proposal included in the batch
+fn batched_proposal<T: crate::Trait>(
+    proposal_details: &ProposalDetailsOf<T>,
+) -> ProposalParameters<T::BlockNumber, BalanceOf<T>> {
+    match proposal_details {
+        ProposalDetails::Text(..) => text_proposal::<T>(),
+        ProposalDetails::SetElectionParameters(..) => set_election_parameters_proposal::<T>(),
+        ProposalDetails::Spending(..) => spending_proposal::<T>(),
+        ProposalDetails::SetValidatorCount(..) => set_validator_count_proposal::<T>(),
+        ProposalDetails::AddWorkingGroupLeaderOpening(..) => {
+            add_working_group_leader_opening_proposal::<T>()
+        }
+        ProposalDetails::BeginReviewWorkingGroupLeaderApplications(..) => {
+            begin_review_working_group_leader_applications_proposal::<T>()
+        }
+        ProposalDetails::FillWorkingGroupLeaderOpening(..) => {
+            fill_working_group_leader_opening_proposal::<T>()
+        }
+        ProposalDetails::SetWorkingGroupMintCapacity(..) => {
+            set_working_group_mint_capacity_proposal::<T>()
+        }
+        ProposalDetails::DecreaseWorkingGroupLeaderStake(..) => {
+            decrease_working_group_leader_stake_proposal::<T>()
+        }
+        ProposalDetails::SlashWorkingGroupLeaderStake(..) => {
+            slash_working_group_leader_stake_proposal::<T>()
+        }
+        ProposalDetails::SetWorkingGroupLeaderReward(..) => {
+            set_working_group_leader_reward_proposal::<T>()
+        }
+        ProposalDetails::TerminateWorkingGroupLeaderRole(..) => {
+            terminate_working_group_leader_role_proposal::<T>()
+        }
+        ProposalDetails::SetMembershipInvitationBudget(..) => {
+            set_membership_invitation_budget_proposal::<T>()
+        }
+        ProposalDetails::SetReferralCut(..) => set_referral_cut_proposal::<T>(),
+        ProposalDetails::SetGlobalStorageVoucherLimits(..) => {
+            set_global_storage_voucher_limits_proposal::<T>()
+        }
+        ProposalDetails::SetStorageVoucherLimitUpperBounds(..) => {
+            set_storage_voucher_limit_upper_bounds_proposal::<T>()
+        }
+        ProposalDetails::SetDataObjectTypeStatus(..) => set_data_object_type_status_proposal::<T>(),
+        ProposalDetails::SetMaxNumberOfCuratorsPerGroup(..) => {
+            set_max_number_of_curators_per_group_proposal::<T>()
+        }
+        // Other proposals cannot be batched. Use the strictest parameters just in case.
+        _ => runtime_upgrade_proposal::<T>(),
+    }
+}
@@ -57,6 +57,7 @@ impl membership::Trait for Test {
     type SubscriptionId = u64;
     type ActorId = u64;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator = ();
 }
 
 parameter_types! {
@@ -74,6 +75,7 @@ impl balances::Trait for Test {
 }
 
 impl stake::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
@@ -86,6 +88,7 @@ parameter_types! {
     pub const RejectionFee: u64 = 3;
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
+    pub const VoteRationaleMaxLength: u32 = 100;
     pub const MaxActiveProposalLimit: u32 = 100;
 }
 
@@ -100,6 +103,7 @@ impl proposals_engine::Trait for Test {
     type RejectionFee = RejectionFee;
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
+    type VoteRationaleMaxLength = VoteRationaleMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
     type DispatchableCallCode = crate::Call<Test>;
 }
@@ -110,14 +114,26 @@ impl Default for crate::Call<Test> {
     }
 }
 
+parameter_types! {
+    pub const MaxTransferLogLength: u32 = 10;
+}
+
 impl minting::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type MintId = u64;
+    type MaxTransferLogLength = MaxTransferLogLength;
+}
+
+parameter_types! {
+    pub const CouncilLockId: [u8; 8] = *b"council ";
 }
 
 impl governance::council::Trait for Test {
     type Event = ();
     type CouncilTermEnded = ();
+    type CouncilSeatVacated = ();
+    type CouncilLockId = CouncilLockId;
 }
 
 impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
@@ -133,6 +149,7 @@ parameter_types! {
     pub const MaxThreadInARowNumber: u32 = 3;
     pub const ThreadTitleLengthLimit: u32 = 200;
     pub const PostLengthLimit: u32 = 2000;
+    pub const MaxWhiteListSize: u32 = 20;
 }
 
 impl proposals_discussion::Trait for Test {
@@ -144,6 +161,15 @@ impl proposals_discussion::Trait for Test {
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type MaxWhiteListSize = MaxWhiteListSize;
+    type CouncilMembership = ();
+    type ModeratorOriginValidator = ();
+}
+
+impl proposals_discussion::CouncilMembership<u64> for () {
+    fn is_council_member(_member_id: &u64) -> bool {
+        true
+    }
 }
 
 pub struct MockVotersParameters;
@@ -161,6 +187,7 @@ parameter_types! {
 impl governance::election::Trait for Test {
     type Event = ();
     type CouncilElected = ();
+    type MembershipOriginValidator = ();
 }
 
 // The content directory working group instance alias.
@@ -171,22 +198,36 @@ pub type StorageWorkingGroupInstance = working_group::Instance2;
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
+    pub const WorkingGroupRoleLockId: [u8; 8] = *b"wg-role ";
+    pub const WorkingGroupApplicationLockId: [u8; 8] = *b"wg-apply";
+    pub const WorkingGroupRewardPeriod: u32 = 10;
 }
 
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = WorkingGroupRoleLockId;
+    type ApplicationLockId = WorkingGroupApplicationLockId;
+    type RewardPeriod = WorkingGroupRewardPeriod;
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = ();
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = WorkingGroupRoleLockId;
+    type ApplicationLockId = WorkingGroupApplicationLockId;
+    type RewardPeriod = WorkingGroupRewardPeriod;
+}
+
+parameter_types! {
+    pub const CatchUpPayoutLimit: u64 = 1000;
 }
 
 impl recurring_rewards::Trait for Test {
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type CatchUpPayoutLimit = CatchUpPayoutLimit;
 }
 
 impl hiring::Trait for Test {
@@ -268,7 +309,7 @@ impl crate::Trait for Test {
 }
 
 impl ProposalEncoder<Test> for () {
-    fn encode_proposal(_proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
+    fn encode_proposal(_proposal_id: u32, _proposal_details: ProposalDetailsOf<Test>) -> Vec<u8> {
         Vec::new()
     }
 }
@@ -1,12 +1,13 @@
 mod mock;
 
+use codec::Encode;
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::storage::StorageMap;
+use frame_support::storage::{StorageMap, StorageValue};
 use frame_support::traits::Currency;
 use frame_system::RawOrigin;
 
 use common::working_group::WorkingGroup;
-use governance::election_params::ElectionParameters;
+use governance::election_params::{ElectionParameters, ElectionTallyMode};
 use hiring::ActivateOpeningAt;
 use proposals_engine::ProposalParameters;
 use working_group::OpeningPolicyCommitment;
@@ -30,6 +31,15 @@ pub(crate) fn increase_total_balance_issuance_using_account_id(account_id: u64,
     assert_eq!(Balances::total_issuance(), initial_balance + balance);
 }
 
+pub(crate) fn set_council_with_member(member_id: u64) {
+    <governance::council::ActiveCouncil<Test>>::put(vec![governance::council::Seat {
+        member: member_id,
+        account: member_id,
+        stake: 0,
+        backers: Vec::new(),
+    }]);
+}
+
 struct ProposalTestFixture<InsufficientRightsCall, EmptyStakeCall, InvalidStakeCall, SuccessfulCall>
 where
     InsufficientRightsCall: Fn() -> DispatchResult,
@@ -119,6 +129,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"text".to_vec(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -129,6 +140,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"text".to_vec(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -139,6 +151,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     b"text".to_vec(),
+                    None,
                 )
             },
             successful_call: || {
@@ -149,6 +162,7 @@ fn create_text_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     b"text".to_vec(),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::text_proposal::<Test>(),
@@ -172,6 +186,7 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"body".to_vec(),
                 None,
                 long_text,
+                None,
             ),
             Err(Error::<Test>::TextProposalSizeExceeded.into())
         );
@@ -184,6 +199,7 @@ fn create_text_proposal_codex_call_fails_with_incorrect_text_size() {
                 b"body".to_vec(),
                 None,
                 Vec::new(),
+                None,
             ),
             Err(Error::<Test>::TextProposalIsEmpty.into())
         );
@@ -202,6 +218,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"wasm".to_vec(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -212,6 +229,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     b"wasm".to_vec(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -222,6 +240,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(500u32)),
                     b"wasm".to_vec(),
+                    None,
                 )
             },
             successful_call: || {
@@ -232,6 +251,7 @@ fn create_runtime_upgrade_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5_000_000_u32)),
                     b"wasm".to_vec(),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::runtime_upgrade_proposal::<Test>(),
@@ -255,6 +275,7 @@ fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
                 b"body".to_vec(),
                 None,
                 long_wasm,
+                None,
             ),
             Err(Error::<Test>::RuntimeProposalSizeExceeded.into())
         );
@@ -267,6 +288,7 @@ fn create_upgrade_runtime_proposal_codex_call_fails_with_incorrect_wasm_size() {
                 b"body".to_vec(),
                 None,
                 Vec::new(),
+                None,
             ),
             Err(Error::<Test>::RuntimeProposalIsEmpty.into())
         );
@@ -285,6 +307,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -295,6 +318,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -305,6 +329,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             successful_call: || {
@@ -315,6 +340,7 @@ fn create_set_election_parameters_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(1_000_000_u32)),
                     get_valid_election_parameters(),
+                    None,
                 )
             },
             proposal_parameters:
@@ -339,6 +365,7 @@ fn assert_failed_election_parameters_call(
             b"body".to_vec(),
             Some(<BalanceOf<Test>>::from(3750u32)),
             election_parameters,
+            None,
         ),
         Err(error)
     );
@@ -354,6 +381,7 @@ fn get_valid_election_parameters() -> ElectionParameters<u64, u64> {
         new_term_duration: 14400,
         min_council_stake: 1,
         min_voting_stake: 1,
+        tally_mode: ElectionTallyMode::SequentialPhragmen,
     }
 }
 
@@ -490,6 +518,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     None,
                     20,
                     10,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -501,6 +530,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     None,
                     20,
                     10,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -512,6 +542,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     20,
                     10,
+                    None,
                 )
             },
             successful_call: || {
@@ -523,6 +554,7 @@ fn create_spending_proposal_common_checks_succeed() {
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     100,
                     2,
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::spending_proposal::<Test>(),
@@ -546,6 +578,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 Some(<BalanceOf<Test>>::from(1250u32)),
                 0,
                 2,
+                None,
             ),
             Err(Error::<Test>::InvalidSpendingProposalBalance.into())
         );
@@ -559,6 +592,7 @@ fn create_spending_proposal_call_fails_with_incorrect_balance() {
                 Some(<BalanceOf<Test>>::from(1250u32)),
                 50000001,
                 2,
+                None,
             ),
             Err(Error::<Test>::InvalidSpendingProposalBalance.into())
         );
@@ -577,6 +611,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     4,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -587,6 +622,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     None,
                     4,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -597,6 +633,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     4,
+                    None,
                 )
             },
             successful_call: || {
@@ -607,6 +644,7 @@ fn create_set_validator_count_proposal_common_checks_succeed() {
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(500_000_u32)),
                     4,
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::set_validator_count_proposal::<
@@ -631,6 +669,7 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(500u32)),
                 3,
+                None,
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
         );
@@ -643,12 +682,34 @@ fn create_set_validator_count_proposal_failed_with_invalid_validator_count() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Test>>::from(1001u32)),
                 3,
+                None,
             ),
             Err(Error::<Test>::InvalidValidatorCount.into())
         );
     });
 }
 
+#[test]
+fn migrate_config_values_keeps_existing_proposal_parameters() {
+    initial_test_ext().execute_with(|| {
+        let p = ProposalsConfigParameters::default();
+
+        <TextProposalVotingPeriod<Test>>::put(10);
+
+        ProposalCodex::migrate_config_values(p);
+
+        assert_eq!(<TextProposalVotingPeriod<Test>>::get(), 10);
+        assert_eq!(
+            <SetReferralCutProposalVotingPeriod<Test>>::get(),
+            p.set_referral_cut_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetMaxNumberOfCuratorsPerGroupProposalGracePeriod<Test>>::get(),
+            p.set_max_number_of_curators_per_group_proposal_grace_period as u64
+        );
+    });
+}
+
 #[test]
 fn set_default_proposal_parameters_succeeded() {
     initial_test_ext().execute_with(|| {
@@ -763,6 +824,62 @@ fn set_default_proposal_parameters_succeeded() {
             <TerminateWorkingGroupLeaderRoleProposalGracePeriod<Test>>::get(),
             p.terminate_working_group_leader_role_proposal_grace_period as u64
         );
+        assert_eq!(
+            <SetMembershipInvitationBudgetProposalVotingPeriod<Test>>::get(),
+            p.set_membership_invitation_budget_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetMembershipInvitationBudgetProposalGracePeriod<Test>>::get(),
+            p.set_membership_invitation_budget_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetReferralCutProposalVotingPeriod<Test>>::get(),
+            p.set_referral_cut_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetReferralCutProposalGracePeriod<Test>>::get(),
+            p.set_referral_cut_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <VoteOfNoConfidenceProposalVotingPeriod<Test>>::get(),
+            p.vote_of_no_confidence_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <VoteOfNoConfidenceProposalGracePeriod<Test>>::get(),
+            p.vote_of_no_confidence_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetGlobalStorageVoucherLimitsProposalVotingPeriod<Test>>::get(),
+            p.set_global_storage_voucher_limits_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetGlobalStorageVoucherLimitsProposalGracePeriod<Test>>::get(),
+            p.set_global_storage_voucher_limits_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetStorageVoucherLimitUpperBoundsProposalVotingPeriod<Test>>::get(),
+            p.set_storage_voucher_limit_upper_bounds_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetStorageVoucherLimitUpperBoundsProposalGracePeriod<Test>>::get(),
+            p.set_storage_voucher_limit_upper_bounds_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetDataObjectTypeStatusProposalVotingPeriod<Test>>::get(),
+            p.set_data_object_type_status_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetDataObjectTypeStatusProposalGracePeriod<Test>>::get(),
+            p.set_data_object_type_status_proposal_grace_period as u64
+        );
+        assert_eq!(
+            <SetMaxNumberOfCuratorsPerGroupProposalVotingPeriod<Test>>::get(),
+            p.set_max_number_of_curators_per_group_proposal_voting_period as u64
+        );
+        assert_eq!(
+            <SetMaxNumberOfCuratorsPerGroupProposalGracePeriod<Test>>::get(),
+            p.set_max_number_of_curators_per_group_proposal_grace_period as u64
+        );
     });
 }
 
@@ -794,6 +911,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     None,
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -804,6 +922,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     None,
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -814,6 +933,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             successful_call: || {
@@ -824,6 +944,7 @@ fn run_create_add_working_group_leader_opening_proposal_common_checks_succeed(
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     add_opening_parameters.clone(),
+                    None,
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::add_working_group_leader_opening_proposal::<
@@ -860,7 +981,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     None,
                     opening_id,
-                    working_group
+                    working_group,
+                    None
                 )
             },
             empty_stake_call: || {
@@ -871,7 +993,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     None,
                     opening_id,
-                    working_group
+                    working_group,
+                    None
                 )
             },
             invalid_stake_call: || {
@@ -882,7 +1005,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     opening_id,
-                    working_group
+                    working_group,
+                    None
                 )
             },
             successful_call: || {
@@ -893,7 +1017,8 @@ fn run_create_begin_review_working_group_leader_applications_proposal_common_che
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(25000u32)),
                     opening_id,
-                    working_group
+                    working_group,
+                    None
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::begin_review_working_group_leader_applications_proposal::<
@@ -935,7 +1060,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None
                 )
             },
             empty_stake_call: || {
@@ -945,7 +1071,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     None,
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None
                 )
             },
             invalid_stake_call: || {
@@ -955,7 +1082,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None
                 )
             },
             successful_call: || {
@@ -965,7 +1093,8 @@ fn run_create_fill_working_group_leader_opening_proposal_common_checks_succeed(
                     b"title".to_vec(),
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(50000u32)),
-                    fill_opening_parameters.clone()
+                    fill_opening_parameters.clone(),
+                    None
                 )
             },
             proposal_parameters: crate::proposal_types::parameters::fill_working_group_leader_opening_proposal::<
@@ -1000,6 +1129,7 @@ fn run_create_working_group_mint_capacity_proposal_fails_with_invalid_parameters
                 Some(<BalanceOf<Test>>::from(50000u32)),
                 (crate::WORKING_GROUP_MINT_CAPACITY_MAX_VALUE + 1) as u64,
                 working_group,
+                None,
             ),
             Err(Error::<Test>::InvalidWorkingGroupMintCapacity.into())
         );
@@ -1030,6 +1160,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     None,
                     0,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1041,6 +1172,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     None,
                     0,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1052,6 +1184,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     0,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1063,6 +1196,7 @@ fn run_create_set_working_group_mint_capacity_proposal_common_checks_succeed(
                     Some(<BalanceOf<Test>>::from(50000u32)),
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1099,6 +1233,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1111,6 +1246,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1123,6 +1259,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1135,6 +1272,7 @@ fn run_create_decrease_working_group_leader_stake_proposal_common_checks_succeed
                     10,
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1176,6 +1314,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1188,6 +1327,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1200,6 +1340,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1212,6 +1353,7 @@ fn run_create_slash_working_group_leader_stake_proposal_common_checks_succeed(
                     10,
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1240,12 +1382,8 @@ fn run_slash_stake_with_zero_staking_balance_fails(working_group: WorkingGroup)
     initial_test_ext().execute_with(|| {
         increase_total_balance_issuance_using_account_id(1, 500000);
 
-        let lead_account_id = 20;
-        <governance::council::Module<Test>>::set_council(
-            RawOrigin::Root.into(),
-            vec![lead_account_id],
-        )
-        .unwrap();
+        let lead_member_id = 20;
+        set_council_with_member(lead_member_id);
 
         assert_eq!(
             ProposalCodex::create_slash_working_group_leader_stake_proposal(
@@ -1257,6 +1395,7 @@ fn run_slash_stake_with_zero_staking_balance_fails(working_group: WorkingGroup)
                 10,
                 0,
                 working_group,
+                None,
             ),
             Err(Error::<Test>::SlashingStakeIsZero.into())
         );
@@ -1275,12 +1414,8 @@ fn run_decrease_stake_with_zero_staking_balance_fails(working_group: WorkingGrou
     initial_test_ext().execute_with(|| {
         increase_total_balance_issuance_using_account_id(1, 500000);
 
-        let lead_account_id = 20;
-        <governance::council::Module<Test>>::set_council(
-            RawOrigin::Root.into(),
-            vec![lead_account_id],
-        )
-        .unwrap();
+        let lead_member_id = 20;
+        set_council_with_member(lead_member_id);
 
         assert_eq!(
             ProposalCodex::create_decrease_working_group_leader_stake_proposal(
@@ -1292,6 +1427,7 @@ fn run_decrease_stake_with_zero_staking_balance_fails(working_group: WorkingGrou
                 10,
                 0,
                 working_group,
+                None,
             ),
             Err(Error::<Test>::DecreasingStakeIsZero.into())
         );
@@ -1321,6 +1457,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1333,6 +1470,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1345,6 +1483,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     0,
                     10,
                     working_group,
+                    None,
                 )
             },
             successful_call: || {
@@ -1357,6 +1496,7 @@ fn run_create_set_working_group_leader_reward_proposal_common_checks_succeed(
                     10,
                     10,
                     working_group,
+                    None,
                 )
             },
             proposal_parameters:
@@ -1398,6 +1538,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     None,
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             empty_stake_call: || {
@@ -1408,6 +1549,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     None,
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             invalid_stake_call: || {
@@ -1418,6 +1560,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(5000u32)),
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             successful_call: || {
@@ -1428,6 +1571,7 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
                     b"body".to_vec(),
                     Some(<BalanceOf<Test>>::from(100_000_u32)),
                     terminate_role_parameters.clone(),
+                    None,
                 )
             },
             proposal_parameters:
@@ -1441,3 +1585,738 @@ fn run_create_terminate_working_group_leader_role_proposal_common_checks_succeed
         proposal_fixture.check_all();
     });
 }
+
+#[test]
+fn create_set_membership_invitation_budget_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_membership_invitation_budget_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    100,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_membership_invitation_budget_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    100,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_membership_invitation_budget_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    100,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_membership_invitation_budget_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    100,
+                    None,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_membership_invitation_budget_proposal::<Test>(
+                ),
+            proposal_details: ProposalDetails::SetMembershipInvitationBudget(100),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_membership_invitation_budget_proposal_fails_with_invalid_budget() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalCodex::create_set_membership_invitation_budget_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                crate::MAX_MEMBERSHIP_INVITATION_BUDGET + 1,
+                None,
+            ),
+            Err(Error::<Test>::InvalidMembershipInvitationBudget.into())
+        );
+    });
+}
+
+#[test]
+fn create_set_referral_cut_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_referral_cut_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    20,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_referral_cut_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    20,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_referral_cut_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    20,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_referral_cut_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    20,
+                    None,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::set_referral_cut_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetReferralCut(20),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_referral_cut_proposal_fails_with_invalid_referral_cut() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalCodex::create_set_referral_cut_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                101,
+                None,
+            ),
+            Err(Error::<Test>::InvalidReferralCut.into())
+        );
+    });
+}
+
+#[test]
+fn create_vote_of_no_confidence_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        set_council_with_member(2);
+
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_vote_of_no_confidence_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    NoConfidenceParameters {
+                        councilor_id: 2,
+                        slash_stake: false,
+                        start_by_election: true,
+                    },
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_vote_of_no_confidence_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    NoConfidenceParameters {
+                        councilor_id: 2,
+                        slash_stake: false,
+                        start_by_election: true,
+                    },
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_vote_of_no_confidence_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    NoConfidenceParameters {
+                        councilor_id: 2,
+                        slash_stake: false,
+                        start_by_election: true,
+                    },
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_vote_of_no_confidence_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(100000u32)),
+                    NoConfidenceParameters {
+                        councilor_id: 2,
+                        slash_stake: false,
+                        start_by_election: true,
+                    },
+                    None,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::vote_of_no_confidence_proposal::<Test>(),
+            proposal_details: ProposalDetails::VoteOfNoConfidence(NoConfidenceParameters {
+                councilor_id: 2,
+                slash_stake: false,
+                start_by_election: true,
+            }),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_vote_of_no_confidence_proposal_fails_with_not_a_councilor() {
+    initial_test_ext().execute_with(|| {
+        assert_eq!(
+            ProposalCodex::create_vote_of_no_confidence_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(100_000_u32)),
+                NoConfidenceParameters {
+                    councilor_id: 2,
+                    slash_stake: false,
+                    start_by_election: false,
+                },
+                None,
+            ),
+            Err(Error::<Test>::NoConfidenceTargetIsNotCouncilor.into())
+        );
+    });
+}
+
+#[test]
+fn create_vote_of_no_confidence_proposal_excludes_target_from_voting() {
+    initial_test_ext().execute_with(|| {
+        set_council_with_member(2);
+
+        let account_id = 1;
+        let _imbalance = <Test as stake::Trait>::Currency::deposit_creating(&account_id, 5_000_000);
+
+        assert_eq!(
+            ProposalCodex::create_vote_of_no_confidence_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(100_000_u32)),
+                NoConfidenceParameters {
+                    councilor_id: 2,
+                    slash_stake: true,
+                    start_by_election: false,
+                },
+                None,
+            ),
+            Ok(())
+        );
+
+        let proposal_id = 1;
+        assert_eq!(ProposalsEngine::excluded_voters(proposal_id), vec![2]);
+    });
+}
+
+fn batched_proposals() -> Vec<ProposalDetails<u64, u64, u64, u64, u64, u64, u64, u64, u64>> {
+    vec![
+        ProposalDetails::Text(b"text".to_vec()),
+        ProposalDetails::SetReferralCut(20),
+    ]
+}
+
+#[test]
+fn create_batch_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    batched_proposals(),
+                    None,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    batched_proposals(),
+                    None,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    batched_proposals(),
+                    None,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_batch_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    batched_proposals(),
+                    None,
+                    None,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::batch_proposal::<Test>(
+                &batched_proposals(),
+            ),
+            proposal_details: ProposalDetails::Batch(batched_proposals()),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn batch_proposal_uses_strictest_parameters() {
+    initial_test_ext().execute_with(|| {
+        let parameters = crate::proposal_types::parameters::batch_proposal::<Test>(&vec![
+            ProposalDetails::Text(b"text".to_vec()),
+            ProposalDetails::SetElectionParameters(ElectionParameters::default()),
+        ]);
+
+        let text_parameters = crate::proposal_types::parameters::text_proposal::<Test>();
+        let election_parameters =
+            crate::proposal_types::parameters::set_election_parameters_proposal::<Test>();
+
+        assert_eq!(
+            parameters.approval_quorum_percentage,
+            election_parameters.approval_quorum_percentage
+        );
+        assert_eq!(
+            parameters.approval_threshold_percentage,
+            text_parameters.approval_threshold_percentage
+        );
+        assert_eq!(
+            parameters.required_stake,
+            election_parameters.required_stake
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_fails_with_invalid_proposals() {
+    initial_test_ext().execute_with(|| {
+        let create_batch_proposal = |proposals| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                proposals,
+                None,
+                None,
+            )
+        };
+
+        assert_eq!(
+            create_batch_proposal(Vec::new()),
+            Err(Error::<Test>::BatchProposalIsEmpty.into())
+        );
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::SetReferralCut(20); 11]),
+            Err(Error::<Test>::BatchProposalSizeExceeded.into())
+        );
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::SetReferralCut(101)]),
+            Err(Error::<Test>::InvalidReferralCut.into())
+        );
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::RuntimeUpgrade(b"wasm".to_vec())]),
+            Err(Error::<Test>::ProposalCannotBeBatched.into())
+        );
+        assert_eq!(
+            create_batch_proposal(vec![ProposalDetails::Batch(batched_proposals())]),
+            Err(Error::<Test>::ProposalCannotBeBatched.into())
+        );
+    });
+}
+
+#[test]
+fn create_batch_proposal_with_execution_dependency_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5_000_000);
+
+        let create_batch_proposal = |execute_after| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                batched_proposals(),
+                execute_after,
+                None,
+            )
+        };
+
+        assert_eq!(
+            create_batch_proposal(Some(1)),
+            Err(Error::<Test>::ExecutionDependencyNotFound.into())
+        );
+
+        assert_eq!(create_batch_proposal(None), Ok(()));
+        assert_eq!(create_batch_proposal(Some(1)), Ok(()));
+
+        assert_eq!(ProposalsEngine::execution_dependency(2), Some(1));
+    });
+}
+
+#[test]
+fn execute_batch_proposal_works() {
+    initial_test_ext().execute_with(|| {
+        let text_proposal_call = crate::Call::<Test>::execute_text_proposal(b"text".to_vec());
+
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(
+                RawOrigin::Signed(1).into(),
+                vec![text_proposal_call.encode()],
+            ),
+            Err(DispatchError::BadOrigin)
+        );
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(
+                RawOrigin::Root.into(),
+                vec![text_proposal_call.encode(), b"invalid call".to_vec()],
+            ),
+            Err(Error::<Test>::InvalidBatchedProposalCall.into())
+        );
+        assert_eq!(
+            ProposalCodex::execute_batch_proposal(
+                RawOrigin::Root.into(),
+                vec![text_proposal_call.encode(), text_proposal_call.encode()],
+            ),
+            Ok(())
+        );
+    });
+}
+
+#[test]
+fn create_proposal_with_exact_execution_block_succeeds() {
+    initial_test_ext().execute_with(|| {
+        increase_total_balance_issuance_using_account_id(1, 5_000_000);
+
+        <crate::TextProposalVotingPeriod<Test>>::put(10);
+        <crate::TextProposalGracePeriod<Test>>::put(5);
+
+        let parameters = crate::proposal_types::parameters::text_proposal::<Test>();
+        let earliest_execution_block = parameters.voting_period + parameters.grace_period;
+
+        let create_text_proposal = |exactly_execute_at| {
+            ProposalCodex::create_text_proposal(
+                RawOrigin::Signed(1).into(),
+                1,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Test>>::from(25000u32)),
+                b"text".to_vec(),
+                exactly_execute_at,
+            )
+        };
+
+        assert_eq!(
+            create_text_proposal(Some(earliest_execution_block - 1)),
+            Err(proposals_engine::Error::<Test>::InvalidExactExecutionBlock.into())
+        );
+        assert_eq!(create_text_proposal(Some(earliest_execution_block)), Ok(()));
+
+        assert_eq!(
+            ProposalsEngine::exact_execution_block(1),
+            Some(earliest_execution_block)
+        );
+    });
+}
+
+#[test]
+fn create_set_global_storage_voucher_limits_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_global_storage_voucher_limits_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1000, 100,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_global_storage_voucher_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1000, 100,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_global_storage_voucher_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1000, 100,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_global_storage_voucher_limits_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1000, 100,
+                    None,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::set_global_storage_voucher_limits_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetGlobalStorageVoucherLimits(1000, 100),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_storage_voucher_limit_upper_bounds_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_storage_voucher_limit_upper_bounds_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1000, 100,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_storage_voucher_limit_upper_bounds_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1000, 100,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_storage_voucher_limit_upper_bounds_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1000, 100,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_storage_voucher_limit_upper_bounds_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1000, 100,
+                    None,
+                )
+            },
+            proposal_parameters: crate::proposal_types::parameters::set_storage_voucher_limit_upper_bounds_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetStorageVoucherLimitUpperBounds(1000, 100),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_data_object_type_status_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_data_object_type_status_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    true,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_data_object_type_status_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    1,
+                    true,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_data_object_type_status_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    1,
+                    true,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_data_object_type_status_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    1,
+                    true,
+                    None,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_data_object_type_status_proposal::<Test>(),
+            proposal_details: ProposalDetails::SetDataObjectTypeStatus(1, true),
+        };
+        proposal_fixture.check_all();
+    });
+}
+
+#[test]
+fn create_set_max_number_of_curators_per_group_proposal_common_checks_succeed() {
+    initial_test_ext().execute_with(|| {
+        let proposal_fixture = ProposalTestFixture {
+            insufficient_rights_call: || {
+                ProposalCodex::create_set_max_number_of_curators_per_group_proposal(
+                    RawOrigin::None.into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    None,
+                )
+            },
+            empty_stake_call: || {
+                ProposalCodex::create_set_max_number_of_curators_per_group_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    None,
+                    10,
+                    None,
+                )
+            },
+            invalid_stake_call: || {
+                ProposalCodex::create_set_max_number_of_curators_per_group_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(5000u32)),
+                    10,
+                    None,
+                )
+            },
+            successful_call: || {
+                ProposalCodex::create_set_max_number_of_curators_per_group_proposal(
+                    RawOrigin::Signed(1).into(),
+                    1,
+                    b"title".to_vec(),
+                    b"body".to_vec(),
+                    Some(<BalanceOf<Test>>::from(25000u32)),
+                    10,
+                    None,
+                )
+            },
+            proposal_parameters:
+                crate::proposal_types::parameters::set_max_number_of_curators_per_group_proposal::<
+                    Test,
+                >(),
+            proposal_details: ProposalDetails::SetMaxNumberOfCuratorsPerGroup(10),
+        };
+        proposal_fixture.check_all();
+    });
+}
@@ -8,9 +8,16 @@
 //! about different proposals. It allows to create discussion threads and then add and update related
 //! posts.
 //!
+//! Threads are open by default. The thread author or a councilor can close the thread, so that only
+//! councilors, the thread author and the whitelisted members are able to post there. Moderators
+//! (councilors or the designated forum moderator) can hide posts providing a rationale.
+//!
 //! ## Supported extrinsics
 //! - [add_post](./struct.Module.html#method.add_post) - adds a post to an existing discussion thread
 //! - [update_post](./struct.Module.html#method.update_post) - updates existing post
+//! - [delete_post](./struct.Module.html#method.delete_post) - deletes own post
+//! - [hide_post](./struct.Module.html#method.hide_post) - hides a post with a moderation rationale
+//! - [change_thread_mode](./struct.Module.html#method.change_thread_mode) - changes thread mode
 //!
 //! ## Public API methods
 //! - [create_thread](./struct.Module.html#method.create_thread) - creates a discussion thread
@@ -55,7 +62,9 @@ use sp_std::clone::Clone;
 use sp_std::vec::Vec;
 
 use common::origin::ActorOriginValidator;
-use types::{DiscussionPost, DiscussionThread, ThreadCounter};
+use types::{DiscussionPost, DiscussionThread, PostModeration, ThreadCounter};
+
+pub use types::ThreadMode;
 
 type MemberId<T> = <T as membership::Trait>::MemberId;
 
@@ -66,6 +75,7 @@ decl_event!(
         <T as Trait>::ThreadId,
         MemberId = MemberId<T>,
         <T as Trait>::PostId,
+        ThreadMode = ThreadMode<MemberId<T>>,
     {
         /// Emits on thread creation.
         ThreadCreated(ThreadId, MemberId),
@@ -75,6 +85,19 @@ decl_event!(
 
         /// Emits on post update.
         PostUpdated(PostId, MemberId),
+
+        /// Emits on post deletion by its author.
+        PostDeleted(PostId, MemberId),
+
+        /// Emits on post hiding by a moderator.
+        /// Params:
+        /// - post id
+        /// - moderator id
+        /// - rationale
+        PostHidden(PostId, MemberId, Vec<u8>),
+
+        /// Emits on thread mode change.
+        ThreadModeChanged(ThreadId, ThreadMode),
     }
 );
 
@@ -107,6 +130,25 @@ pub trait Trait: frame_system::Trait + membership::Trait {
 
     /// Defines max thread by same author in a row number limit.
     type MaxThreadInARowNumber: Get<u32>;
+
+    /// Defines max whitelist size for the closed thread mode.
+    type MaxWhiteListSize: Get<u32>;
+
+    /// Provides council membership information.
+    type CouncilMembership: CouncilMembership<MemberId<Self>>;
+
+    /// Validates moderator id and origin combination.
+    type ModeratorOriginValidator: ActorOriginValidator<
+        Self::Origin,
+        MemberId<Self>,
+        Self::AccountId,
+    >;
+}
+
+/// Provides information about the current council.
+pub trait CouncilMembership<MemberId> {
+    /// Defines whether the member is a current councilor.
+    fn is_council_member(member_id: &MemberId) -> bool;
 }
 
 decl_error! {
@@ -141,6 +183,24 @@ decl_error! {
 
         /// Require root origin in extrinsics
         RequireRootOrigin,
+
+        /// Only councilors, the thread author and whitelisted members can post in the closed thread
+        CannotPostOnClosedThread,
+
+        /// Only the thread author or a councilor can change the thread mode
+        NotAuthorOrCouncilor,
+
+        /// Max whitelist size limit exceeded
+        MaxWhiteListSizeExceeded,
+
+        /// Moderation rationale cannot be empty
+        EmptyRationaleProvided,
+
+        /// Moderation rationale is too long
+        RationaleIsTooLong,
+
+        /// Post is hidden by a moderator
+        PostIsHidden,
     }
 }
 
@@ -165,6 +225,15 @@ decl_storage! {
         /// Last author thread counter (part of the antispam mechanism)
         pub LastThreadAuthorCounter get(fn last_thread_author_counter):
             Option<ThreadCounter<MemberId<T>>>;
+
+        /// Map thread identifier to corresponding thread mode. Threads are open by default.
+        pub ThreadModeById get(fn thread_mode_by_id): map hasher(blake2_128_concat)
+            T::ThreadId => ThreadMode<MemberId<T>>;
+
+        /// Map thread id and post id to the moderation outcome of the hidden post.
+        pub HiddenPostByPostId get(fn hidden_post_by_post_id):
+            double_map hasher(blake2_128_concat) T::ThreadId, hasher(blake2_128_concat) T::PostId =>
+                Option<PostModeration<MemberId<T>, T::BlockNumber>>;
     }
 }
 
@@ -189,6 +258,9 @@ decl_module! {
         /// Exports max thread by same author in a row number limit const.
         const MaxThreadInARowNumber: u32 = T::MaxThreadInARowNumber::get();
 
+        /// Exports max whitelist size const.
+        const MaxWhiteListSize: u32 = T::MaxWhiteListSize::get();
+
         /// Adds a post with author origin check.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn add_post(
@@ -202,6 +274,7 @@ decl_module! {
                 post_author_id,
             )?;
             ensure!(<ThreadById<T>>::contains_key(thread_id), Error::<T>::ThreadDoesntExist);
+            Self::ensure_thread_mode_allows_posting(thread_id, post_author_id)?;
 
             ensure!(!text.is_empty(),Error::<T>::EmptyPostProvided);
             ensure!(
@@ -255,6 +328,10 @@ decl_module! {
             let post = <PostThreadIdByPostId<T>>::get(&thread_id, &post_id);
 
             ensure!(post.author_id == post_author_id, Error::<T>::NotAuthor);
+            ensure!(
+                !<HiddenPostByPostId<T>>::contains_key(thread_id, post_id),
+                Error::<T>::PostIsHidden
+            );
             ensure!(post.edition_number < T::MaxPostEditionNumber::get(),
                 Error::<T>::PostEditionNumberExceeded);
 
@@ -270,6 +347,112 @@ decl_module! {
             <PostThreadIdByPostId<T>>::insert(thread_id, post_id, new_post);
             Self::deposit_event(RawEvent::PostUpdated(post_id, post_author_id));
        }
+
+        /// Deletes a post with author origin check. Posts hidden by a moderator cannot be deleted,
+        /// so the moderation record is kept.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn delete_post(
+            origin,
+            post_author_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id : T::PostId,
+        ){
+            T::PostAuthorOriginValidator::ensure_actor_origin(
+                origin,
+                post_author_id,
+            )?;
+
+            ensure!(<ThreadById<T>>::contains_key(thread_id), Error::<T>::ThreadDoesntExist);
+            ensure!(<PostThreadIdByPostId<T>>::contains_key(thread_id, post_id), Error::<T>::PostDoesntExist);
+
+            let post = <PostThreadIdByPostId<T>>::get(&thread_id, &post_id);
+
+            ensure!(post.author_id == post_author_id, Error::<T>::NotAuthor);
+            ensure!(
+                !<HiddenPostByPostId<T>>::contains_key(thread_id, post_id),
+                Error::<T>::PostIsHidden
+            );
+
+            // mutation
+
+            <PostThreadIdByPostId<T>>::remove(thread_id, post_id);
+            Self::deposit_event(RawEvent::PostDeleted(post_id, post_author_id));
+       }
+
+        /// Hides a post with moderator origin check. Rationale length is limited by the post
+        /// length limit.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn hide_post(
+            origin,
+            moderator_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            post_id : T::PostId,
+            rationale : Vec<u8>
+        ){
+            T::ModeratorOriginValidator::ensure_actor_origin(
+                origin,
+                moderator_id,
+            )?;
+
+            ensure!(<ThreadById<T>>::contains_key(thread_id), Error::<T>::ThreadDoesntExist);
+            ensure!(<PostThreadIdByPostId<T>>::contains_key(thread_id, post_id), Error::<T>::PostDoesntExist);
+            ensure!(
+                !<HiddenPostByPostId<T>>::contains_key(thread_id, post_id),
+                Error::<T>::PostIsHidden
+            );
+
+            ensure!(!rationale.is_empty(), Error::<T>::EmptyRationaleProvided);
+            ensure!(
+                rationale.len() as u32 <= T::PostLengthLimit::get(),
+                Error::<T>::RationaleIsTooLong
+            );
+
+            let moderation = PostModeration {
+                moderator_id,
+                hidden_at: Self::current_block(),
+                rationale: rationale.clone(),
+            };
+
+            // mutation
+
+            <HiddenPostByPostId<T>>::insert(thread_id, post_id, moderation);
+            Self::deposit_event(RawEvent::PostHidden(post_id, moderator_id, rationale));
+       }
+
+        /// Changes the thread mode. Only the thread author or a councilor can change the mode.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn change_thread_mode(
+            origin,
+            member_id: MemberId<T>,
+            thread_id: T::ThreadId,
+            mode : ThreadMode<MemberId<T>>
+        ){
+            T::PostAuthorOriginValidator::ensure_actor_origin(
+                origin,
+                member_id,
+            )?;
+
+            ensure!(<ThreadById<T>>::contains_key(thread_id), Error::<T>::ThreadDoesntExist);
+
+            let thread = <ThreadById<T>>::get(thread_id);
+
+            ensure!(
+                thread.author_id == member_id || T::CouncilMembership::is_council_member(&member_id),
+                Error::<T>::NotAuthorOrCouncilor
+            );
+
+            if let ThreadMode::Closed(ref whitelist) = mode {
+                ensure!(
+                    whitelist.len() as u32 <= T::MaxWhiteListSize::get(),
+                    Error::<T>::MaxWhiteListSizeExceeded
+                );
+            }
+
+            // mutation
+
+            <ThreadModeById<T>>::insert(thread_id, mode.clone());
+            Self::deposit_event(RawEvent::ThreadModeChanged(thread_id, mode));
+       }
     }
 }
 
@@ -347,4 +530,23 @@ impl<T: Trait> Module<T> {
         // else return new counter (set with 1 thread number)
         ThreadCounter::new(author_id)
     }
+
+    // ensures that the member can post in the thread according to the thread mode
+    fn ensure_thread_mode_allows_posting(
+        thread_id: T::ThreadId,
+        post_author_id: MemberId<T>,
+    ) -> DispatchResult {
+        if let ThreadMode::Closed(whitelist) = Self::thread_mode_by_id(thread_id) {
+            let thread = <ThreadById<T>>::get(thread_id);
+
+            ensure!(
+                thread.author_id == post_author_id
+                    || whitelist.contains(&post_author_id)
+                    || T::CouncilMembership::is_council_member(&post_author_id),
+                Error::<T>::CannotPostOnClosedThread
+            );
+        }
+
+        Ok(())
+    }
 }
@@ -11,7 +11,7 @@ use sp_runtime::{
     Perbill,
 };
 
-use crate::ActorOriginValidator;
+use crate::{ActorOriginValidator, CouncilMembership};
 
 impl_outer_origin! {
     pub enum Origin for Test {}
@@ -34,6 +34,7 @@ parameter_types! {
     pub const MaxThreadInARowNumber: u32 = 3;
     pub const ThreadTitleLengthLimit: u32 = 200;
     pub const PostLengthLimit: u32 = 2000;
+    pub const MaxWhiteListSize: u32 = 3;
 }
 
 mod discussion {
@@ -84,6 +85,7 @@ impl membership::Trait for Test {
     type SubscriptionId = u64;
     type ActorId = u64;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator = ();
 }
 
 impl crate::Trait for Test {
@@ -95,6 +97,30 @@ impl crate::Trait for Test {
     type ThreadTitleLengthLimit = ThreadTitleLengthLimit;
     type PostLengthLimit = PostLengthLimit;
     type MaxThreadInARowNumber = MaxThreadInARowNumber;
+    type MaxWhiteListSize = MaxWhiteListSize;
+    type CouncilMembership = CouncilMock;
+    type ModeratorOriginValidator = ModeratorOriginValidatorMock;
+}
+
+pub const COUNCILOR_MEMBER_ID: u64 = 2;
+pub const FORUM_MODERATOR_MEMBER_ID: u64 = 3;
+
+pub struct CouncilMock;
+impl CouncilMembership<u64> for CouncilMock {
+    fn is_council_member(member_id: &u64) -> bool {
+        *member_id == COUNCILOR_MEMBER_ID
+    }
+}
+
+pub struct ModeratorOriginValidatorMock;
+impl ActorOriginValidator<Origin, u64, u64> for ModeratorOriginValidatorMock {
+    fn ensure_actor_origin(_origin: Origin, actor_id: u64) -> Result<u64, &'static str> {
+        if actor_id == COUNCILOR_MEMBER_ID || actor_id == FORUM_MODERATOR_MEMBER_ID {
+            return Ok(actor_id);
+        }
+
+        Err("Invalid moderator")
+    }
 }
 
 impl ActorOriginValidator<Origin, u64, u64> for () {
@@ -9,7 +9,7 @@ use mock::*;
 
 struct EventFixture;
 impl EventFixture {
-    fn assert_events(expected_raw_events: Vec<RawEvent<u64, u64, u64>>) {
+    fn assert_events(expected_raw_events: Vec<RawEvent<u64, u64, u64, ThreadMode<u64>>>) {
         let expected_events = expected_raw_events
             .iter()
             .map(|ev| EventRecord {
@@ -166,6 +166,46 @@ impl PostFixture {
     fn update_post_and_assert(&mut self, result: DispatchResult) {
         self.update_post_with_text_and_assert(self.text.clone(), result);
     }
+
+    fn delete_post_and_assert(&mut self, result: DispatchResult) {
+        let delete_post_result = Discussions::delete_post(
+            self.origin.clone().into(),
+            self.author_id,
+            self.thread_id,
+            self.post_id.unwrap(),
+        );
+
+        assert_eq!(delete_post_result, result);
+    }
+
+    fn hide_post_and_assert(
+        &mut self,
+        moderator_id: u64,
+        rationale: Vec<u8>,
+        result: DispatchResult,
+    ) {
+        let hide_post_result = Discussions::hide_post(
+            RawOrigin::Signed(moderator_id).into(),
+            moderator_id,
+            self.thread_id,
+            self.post_id.unwrap(),
+            rationale,
+        );
+
+        assert_eq!(hide_post_result, result);
+    }
+}
+
+fn change_thread_mode_and_assert(
+    member_id: u64,
+    thread_id: u64,
+    mode: ThreadMode<u64>,
+    result: DispatchResult,
+) {
+    let change_thread_mode_result =
+        Discussions::change_thread_mode(RawOrigin::None.into(), member_id, thread_id, mode);
+
+    assert_eq!(change_thread_mode_result, result);
 }
 
 #[test]
@@ -425,3 +465,206 @@ fn discussion_thread_and_post_counters_are_valid() {
         assert_eq!(Discussions::post_count(), 1);
     });
 }
+
+#[test]
+fn delete_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        let post_id = post_fixture.add_post_and_assert(Ok(())).unwrap();
+
+        post_fixture.delete_post_and_assert(Ok(()));
+
+        assert!(!<PostThreadIdByPostId<Test>>::contains_key(
+            thread_id, post_id
+        ));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostDeleted(1, 1),
+        ]);
+
+        post_fixture.delete_post_and_assert(Err(Error::<Test>::PostDoesntExist.into()));
+    });
+}
+
+#[test]
+fn delete_post_call_fails_because_of_the_wrong_author() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        post_fixture = post_fixture.with_author(2);
+        post_fixture.delete_post_and_assert(Err(DispatchError::Other("Invalid author")));
+
+        post_fixture = post_fixture.with_origin(RawOrigin::None).with_author(2);
+        post_fixture.delete_post_and_assert(Err(Error::<Test>::NotAuthor.into()));
+    });
+}
+
+#[test]
+fn hide_post_call_succeeds() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture1 = PostFixture::default_for_thread(thread_id);
+        let post_id1 = post_fixture1.add_post_and_assert(Ok(())).unwrap();
+
+        let mut post_fixture2 = PostFixture::default_for_thread(thread_id);
+        post_fixture2.add_post_and_assert(Ok(()));
+
+        let rationale = b"spam".to_vec();
+        post_fixture1.hide_post_and_assert(FORUM_MODERATOR_MEMBER_ID, rationale.clone(), Ok(()));
+        post_fixture2.hide_post_and_assert(COUNCILOR_MEMBER_ID, rationale.clone(), Ok(()));
+
+        assert_eq!(
+            Discussions::hidden_post_by_post_id(thread_id, post_id1),
+            Some(PostModeration {
+                moderator_id: FORUM_MODERATOR_MEMBER_ID,
+                hidden_at: 1,
+                rationale: rationale.clone(),
+            })
+        );
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostCreated(2, 1),
+            RawEvent::PostHidden(1, FORUM_MODERATOR_MEMBER_ID, rationale.clone()),
+            RawEvent::PostHidden(2, COUNCILOR_MEMBER_ID, rationale.clone()),
+        ]);
+
+        post_fixture1.update_post_and_assert(Err(Error::<Test>::PostIsHidden.into()));
+        post_fixture1.delete_post_and_assert(Err(Error::<Test>::PostIsHidden.into()));
+        assert!(Discussions::hidden_post_by_post_id(thread_id, post_id1).is_some());
+        post_fixture1.hide_post_and_assert(
+            COUNCILOR_MEMBER_ID,
+            rationale,
+            Err(Error::<Test>::PostIsHidden.into()),
+        );
+    });
+}
+
+#[test]
+fn hide_post_call_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let mut post_fixture = PostFixture::default_for_thread(thread_id);
+        post_fixture.add_post_and_assert(Ok(()));
+
+        post_fixture.hide_post_and_assert(
+            1,
+            b"spam".to_vec(),
+            Err(DispatchError::Other("Invalid moderator")),
+        );
+        post_fixture.hide_post_and_assert(
+            COUNCILOR_MEMBER_ID,
+            Vec::new(),
+            Err(Error::<Test>::EmptyRationaleProvided.into()),
+        );
+        post_fixture.hide_post_and_assert(
+            COUNCILOR_MEMBER_ID,
+            [0; 2001].to_vec(),
+            Err(Error::<Test>::RationaleIsTooLong.into()),
+        );
+    });
+}
+
+#[test]
+fn closed_thread_restricts_posting() {
+    initial_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        let whitelisted_member_id = 4;
+        let mode = ThreadMode::Closed(vec![whitelisted_member_id]);
+        change_thread_mode_and_assert(1, thread_id, mode.clone(), Ok(()));
+
+        assert_eq!(Discussions::thread_mode_by_id(thread_id), mode.clone());
+
+        let mut author_post_fixture = PostFixture::default_for_thread(thread_id);
+        author_post_fixture.add_post_and_assert(Ok(()));
+
+        let mut whitelisted_post_fixture = PostFixture::default_for_thread(thread_id)
+            .with_origin(RawOrigin::None)
+            .with_author(whitelisted_member_id);
+        whitelisted_post_fixture.add_post_and_assert(Ok(()));
+
+        let mut councilor_post_fixture = PostFixture::default_for_thread(thread_id)
+            .with_origin(RawOrigin::None)
+            .with_author(COUNCILOR_MEMBER_ID);
+        councilor_post_fixture.add_post_and_assert(Ok(()));
+
+        let mut other_post_fixture = PostFixture::default_for_thread(thread_id)
+            .with_origin(RawOrigin::None)
+            .with_author(5);
+        other_post_fixture.add_post_and_assert(Err(Error::<Test>::CannotPostOnClosedThread.into()));
+
+        change_thread_mode_and_assert(COUNCILOR_MEMBER_ID, thread_id, ThreadMode::Open, Ok(()));
+        other_post_fixture.add_post_and_assert(Ok(()));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ThreadCreated(1, 1),
+            RawEvent::ThreadModeChanged(1, mode),
+            RawEvent::PostCreated(1, 1),
+            RawEvent::PostCreated(2, whitelisted_member_id),
+            RawEvent::PostCreated(3, COUNCILOR_MEMBER_ID),
+            RawEvent::ThreadModeChanged(1, ThreadMode::Open),
+            RawEvent::PostCreated(4, 5),
+        ]);
+    });
+}
+
+#[test]
+fn change_thread_mode_call_fails_with_invalid_parameters() {
+    initial_test_ext().execute_with(|| {
+        let discussion_fixture = DiscussionFixture::default();
+        let thread_id = discussion_fixture
+            .create_discussion_and_assert(Ok(1))
+            .unwrap();
+
+        change_thread_mode_and_assert(
+            5,
+            thread_id,
+            ThreadMode::Closed(Vec::new()),
+            Err(Error::<Test>::NotAuthorOrCouncilor.into()),
+        );
+        change_thread_mode_and_assert(
+            1,
+            thread_id,
+            ThreadMode::Closed(vec![4, 5, 6, 7]),
+            Err(Error::<Test>::MaxWhiteListSizeExceeded.into()),
+        );
+        change_thread_mode_and_assert(
+            1,
+            2,
+            ThreadMode::Open,
+            Err(Error::<Test>::ThreadDoesntExist.into()),
+        );
+    });
+}
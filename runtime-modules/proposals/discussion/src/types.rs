@@ -42,6 +42,37 @@ pub struct DiscussionPost<PostAuthorId, BlockNumber, ThreadId> {
     pub edition_number: u32,
 }
 
+/// Defines who is allowed to post in the discussion thread.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq)]
+pub enum ThreadMode<MemberId> {
+    /// Every member can post.
+    Open,
+
+    /// Only councilors, the thread author and whitelisted members can post.
+    Closed(Vec<MemberId>),
+}
+
+impl<MemberId> Default for ThreadMode<MemberId> {
+    fn default() -> Self {
+        ThreadMode::Open
+    }
+}
+
+/// Moderation outcome applied to the discussion post.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq)]
+pub struct PostModeration<ModeratorId, BlockNumber> {
+    /// Member who hid the post.
+    pub moderator_id: ModeratorId,
+
+    /// When the post was hidden.
+    pub hidden_at: BlockNumber,
+
+    /// Reason for hiding the post.
+    pub rationale: Vec<u8>,
+}
+
 /// Post for the discussion thread
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize, Debug))]
 #[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq)]
@@ -27,6 +27,11 @@
 //! different statuses should be accomplished from the external handler from the _stake module_
 //! (_StakingEventsHandler_). Such a handler should call
 //! [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) callback function.
+//! - The proposal can be created with the exact execution block. Such a proposal is executed at
+//! that block instead of right after the _grace period_.
+//! - The proposal can depend on another proposal execution. Such a proposal stays approved after its
+//! _grace period_ until the dependency proposal gets executed. The execution fails if the dependency
+//! proposal is finalized without being executed.
 //! - If the _council_ got reelected during the proposal _voting period_ the external handler calls
 //! [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) function and
 //! all voting results get cleared.
@@ -40,12 +45,17 @@
 //! - _ProposerOriginValidator_ - ensure valid proposer identity. Proposers should have permissions
 //! to create a proposal: they should be members of the Joystream.
 //! - [StakeHandlerProvider](./trait.StakeHandlerProvider.html) - defines an interface for the staking.
+//! [LockStakeHandlerProvider](./struct.LockStakeHandlerProvider.html) keeps the stakes on the
+//! proposer account under a balance lock instead of the stake pool.
 //!
 //! A full list of the abstractions can be found [here](./trait.Trait.html).
 //!
 //! ### Supported extrinsics
-//! - [vote](./struct.Module.html#method.vote) - registers a vote for the proposal
+//! - [vote](./struct.Module.html#method.vote) - registers a vote for the proposal (with an optional
+//! rationale), or changes the vote while the proposal is active
 //! - [cancel_proposal](./struct.Module.html#method.cancel_proposal) - cancels the proposal (can be canceled only by owner)
+//! - [set_proposal_execution_dependency](./struct.Module.html#method.set_proposal_execution_dependency) - makes the proposal
+//! execution wait for another proposal execution (only by owner before the first vote)
 //! - [veto_proposal](./struct.Module.html#method.veto_proposal) - vetoes the proposal
 //!
 //! ### Public API
@@ -53,6 +63,8 @@
 //! - [ensure_create_proposal_parameters_are_valid](./struct.Module.html#method.ensure_create_proposal_parameters_are_valid) - ensures that we can create the proposal
 //! - [refund_proposal_stake](./struct.Module.html#method.refund_proposal_stake) - a callback for _StakingHandlerEvents_
 //! - [reset_active_proposals](./trait.Module.html#method.reset_active_proposals) - resets voting results for active proposals
+//! - [exclude_voter](./struct.Module.html#method.exclude_voter) - forbids the voter to vote on the proposal
+//! - [set_execution_dependency](./struct.Module.html#method.set_execution_dependency) - makes the proposal execution wait for another proposal execution
 //!
 //! ## Usage
 //!
@@ -89,6 +101,7 @@
 //!                 &description,
 //!                 None,
 //!                 &account_id,
+//!                 None,
 //!             )?;
 //!             <engine::Module<T>>::create_proposal(
 //!                 account_id,
@@ -97,7 +110,8 @@
 //!                 title,
 //!                 description,
 //!                 None,
-//!                 encoded_proposal_code
+//!                 encoded_proposal_code,
+//!                 None,
 //!             )?;
 //!         }
 //!     }
@@ -119,7 +133,9 @@ pub use types::{
     ProposalParameters, ProposalStatus, VotingResults,
 };
 pub use types::{BalanceOf, CurrencyOf, NegativeImbalance};
-pub use types::{DefaultStakeHandlerProvider, StakeHandler, StakeHandlerProvider};
+pub use types::{
+    DefaultStakeHandlerProvider, LockStakeHandlerProvider, StakeHandler, StakeHandlerProvider,
+};
 pub use types::{ProposalCodeDecoder, ProposalExecutable};
 pub use types::{VoteKind, VotersParameters};
 
@@ -181,6 +197,9 @@ pub trait Trait:
     /// Defines max allowed proposal description length.
     type DescriptionMaxLength: Get<u32>;
 
+    /// Defines max allowed vote rationale length.
+    type VoteRationaleMaxLength: Get<u32>;
+
     /// Defines max simultaneous active proposals number.
     type MaxActiveProposalLimit: Get<u32>;
 
@@ -215,7 +234,8 @@ decl_event!(
         /// - Voter - member id of a voter.
         /// - Id of a proposal.
         /// - Kind of vote.
-        Voted(MemberId, ProposalId, VoteKind),
+        /// - Rationale of the vote (empty if not provided).
+        Voted(MemberId, ProposalId, VoteKind, Vec<u8>),
     }
 );
 
@@ -269,6 +289,21 @@ decl_error! {
 
         /// Insufficient balance for operation.
         InsufficientBalance,
+
+        /// The voter is not allowed to vote on the proposal
+        VoterExcluded,
+
+        /// Vote rationale is too long
+        RationaleIsTooLong,
+
+        /// The dependency proposal does not exist or it depends on the proposal itself
+        InvalidExecutionDependency,
+
+        /// The exact execution block falls before the end of the voting and grace periods
+        InvalidExactExecutionBlock,
+
+        /// The proposal has already been voted on
+        ProposalHasVotes,
     }
 }
 
@@ -301,9 +336,26 @@ decl_storage! {
         pub VoteExistsByProposalByVoter get(fn vote_by_proposal_by_voter):
             double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => VoteKind;
 
+        /// Rationales of the votes. Only votes with a provided rationale have an entry.
+        pub VoteRationaleByProposalByVoter get(fn vote_rationale_by_proposal_by_voter):
+            double_map hasher(blake2_128_concat)  T::ProposalId, hasher(blake2_128_concat) MemberId<T> => Vec<u8>;
+
         /// Map proposal id by stake id. Required by StakingEventsHandler callback call
         pub StakesProposals get(fn stakes_proposals): map hasher(blake2_128_concat)
             T::StakeId =>  T::ProposalId;
+
+        /// Voters not allowed to vote on the active proposal. They are not counted in the total
+        /// voters number of the proposal. Cleaned on the proposal finalization.
+        pub ExcludedVoters get(fn excluded_voters): map hasher(blake2_128_concat)
+            T::ProposalId => Vec<MemberId<T>>;
+
+        /// Proposals that must be executed before the execution of the proposal.
+        pub ExecutionDependencies get(fn execution_dependency): map hasher(blake2_128_concat)
+            T::ProposalId => Option<T::ProposalId>;
+
+        /// Blocks to execute the approved proposals at, instead of right after the grace period.
+        pub ExactExecutionBlocks get(fn exact_execution_block): map hasher(blake2_128_concat)
+            T::ProposalId => Option<T::BlockNumber>;
     }
 }
 
@@ -328,12 +380,22 @@ decl_module! {
         /// Exports const -  max allowed proposal description length.
         const DescriptionMaxLength: u32 = T::DescriptionMaxLength::get();
 
+        /// Exports const -  max allowed vote rationale length.
+        const VoteRationaleMaxLength: u32 = T::VoteRationaleMaxLength::get();
+
         /// Exports const -  max simultaneous active proposals number.
         const MaxActiveProposalLimit: u32 = T::MaxActiveProposalLimit::get();
 
         /// Vote extrinsic. Conditions:  origin must allow votes.
+        /// A repeated vote replaces the previous vote (and its rationale) while the proposal is active.
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn vote(origin, voter_id: MemberId<T>, proposal_id: T::ProposalId, vote: VoteKind)  {
+        pub fn vote(
+            origin,
+            voter_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            vote: VoteKind,
+            rationale: Option<Vec<u8>>,
+        )  {
             T::VoterOriginValidator::ensure_actor_origin(
                 origin,
                 voter_id,
@@ -344,12 +406,22 @@ decl_module! {
 
             ensure!(matches!(proposal.status, ProposalStatus::Active{..}), Error::<T>::ProposalFinalized);
 
-            let did_not_vote_before = !<VoteExistsByProposalByVoter<T>>::contains_key(
-                proposal_id,
-                voter_id,
+            ensure!(
+                !Self::excluded_voters(proposal_id).contains(&voter_id),
+                Error::<T>::VoterExcluded
+            );
+
+            let rationale = rationale.unwrap_or_default();
+
+            ensure!(
+                rationale.len() as u32 <= T::VoteRationaleMaxLength::get(),
+                Error::<T>::RationaleIsTooLong
             );
 
-            ensure!(did_not_vote_before, Error::<T>::AlreadyVoted);
+            if <VoteExistsByProposalByVoter<T>>::contains_key(proposal_id, voter_id) {
+                let previous_vote = Self::vote_by_proposal_by_voter(proposal_id, voter_id);
+                proposal.voting_results.remove_vote(previous_vote);
+            }
 
             proposal.voting_results.add_vote(vote.clone());
 
@@ -357,7 +429,14 @@ decl_module! {
 
             <Proposals<T>>::insert(proposal_id, proposal);
             <VoteExistsByProposalByVoter<T>>::insert(proposal_id, voter_id, vote.clone());
-            Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote));
+
+            if rationale.is_empty() {
+                <VoteRationaleByProposalByVoter<T>>::remove(proposal_id, voter_id);
+            } else {
+                <VoteRationaleByProposalByVoter<T>>::insert(proposal_id, voter_id, rationale.clone());
+            }
+
+            Self::deposit_event(RawEvent::Voted(voter_id, proposal_id, vote, rationale));
         }
 
         /// Cancel a proposal by its original proposer.
@@ -379,6 +458,32 @@ decl_module! {
             Self::finalize_proposal(proposal_id, ProposalDecisionStatus::Canceled);
         }
 
+        /// Makes the proposal execution wait for the execution of the dependency proposal.
+        /// Can be called only by the proposal author before the first vote.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_proposal_execution_dependency(
+            origin,
+            proposer_id: MemberId<T>,
+            proposal_id: T::ProposalId,
+            dependency_id: T::ProposalId,
+        ) {
+            T::ProposerOriginValidator::ensure_actor_origin(
+                origin,
+                proposer_id,
+            )?;
+
+            ensure!(<Proposals<T>>::contains_key(proposal_id), Error::<T>::ProposalNotFound);
+            let proposal = Self::proposals(proposal_id);
+
+            ensure!(proposer_id == proposal.proposer_id, Error::<T>::NotAuthor);
+            ensure!(
+                proposal.voting_results == VotingResults::default(),
+                Error::<T>::ProposalHasVotes
+            );
+
+            Self::set_execution_dependency(proposal_id, dependency_id)?;
+        }
+
         /// Veto a proposal. Must be root.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn veto_proposal(origin, proposal_id: T::ProposalId) {
@@ -424,6 +529,8 @@ decl_module! {
 
 impl<T: Trait> Module<T> {
     /// Create proposal. Requires 'proposal origin' membership.
+    /// The approved proposal is executed at the `exactly_execute_at` block if provided.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_proposal(
         account_id: T::AccountId,
         proposer_id: MemberId<T>,
@@ -432,6 +539,7 @@ impl<T: Trait> Module<T> {
         description: Vec<u8>,
         stake_balance: Option<types::BalanceOf<T>>,
         encoded_dispatchable_call_code: Vec<u8>,
+        exactly_execute_at: Option<T::BlockNumber>,
     ) -> Result<T::ProposalId, DispatchError> {
         Self::ensure_create_proposal_parameters_are_valid(
             &parameters,
@@ -439,6 +547,7 @@ impl<T: Trait> Module<T> {
             &description,
             stake_balance,
             &account_id,
+            exactly_execute_at,
         )?;
 
         // checks passed
@@ -480,6 +589,11 @@ impl<T: Trait> Module<T> {
         <DispatchableCallCode<T>>::insert(proposal_id, encoded_dispatchable_call_code);
         <ActiveProposalIds<T>>::insert(proposal_id, ());
         ProposalCount::put(next_proposal_count_value);
+
+        if let Some(exact_execution_block) = exactly_execute_at {
+            <ExactExecutionBlocks<T>>::insert(proposal_id, exact_execution_block);
+        }
+
         Self::increase_active_proposal_counter();
 
         Self::deposit_event(RawEvent::ProposalCreated(proposer_id, proposal_id));
@@ -492,12 +606,14 @@ impl<T: Trait> Module<T> {
     /// - max active proposal
     /// - provided parameters: approval_threshold_percentage and slashing_threshold_percentage > 0
     /// - provided stake balance and parameters.required_stake are valid
+    /// - exact execution block (if any) is not earlier than the end of the voting and grace periods
     pub fn ensure_create_proposal_parameters_are_valid(
         parameters: &ProposalParameters<T::BlockNumber, types::BalanceOf<T>>,
         title: &[u8],
         description: &[u8],
         stake_balance: Option<types::BalanceOf<T>>,
         stake_account_id: &T::AccountId,
+        exactly_execute_at: Option<T::BlockNumber>,
     ) -> DispatchResult {
         ensure!(!title.is_empty(), Error::<T>::EmptyTitleProvided);
         ensure!(
@@ -551,6 +667,14 @@ impl<T: Trait> Module<T> {
             return Err(Error::<T>::StakeShouldBeEmpty.into());
         }
 
+        if let Some(exact_execution_block) = exactly_execute_at {
+            ensure!(
+                exact_execution_block
+                    >= Self::current_block() + parameters.voting_period + parameters.grace_period,
+                Error::<T>::InvalidExactExecutionBlock
+            );
+        }
+
         Ok(())
     }
 
@@ -586,6 +710,68 @@ impl<T: Trait> Module<T> {
         }
     }
 
+    /// Excludes the voter from voting on the active proposal. The total voters number of the
+    /// proposal is decreased accordingly.
+    pub fn exclude_voter(proposal_id: T::ProposalId, voter_id: MemberId<T>) -> DispatchResult {
+        ensure!(
+            <Proposals<T>>::contains_key(proposal_id),
+            Error::<T>::ProposalNotFound
+        );
+
+        let proposal = Self::proposals(proposal_id);
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::<T>::ProposalFinalized
+        );
+
+        ensure!(
+            !<VoteExistsByProposalByVoter<T>>::contains_key(proposal_id, voter_id),
+            Error::<T>::AlreadyVoted
+        );
+
+        <ExcludedVoters<T>>::mutate(proposal_id, |voters| {
+            if !voters.contains(&voter_id) {
+                voters.push(voter_id);
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Makes the execution of the active proposal wait for the execution of the dependency
+    /// proposal. The proposal execution fails if the dependency proposal is not executed.
+    pub fn set_execution_dependency(
+        proposal_id: T::ProposalId,
+        dependency_id: T::ProposalId,
+    ) -> DispatchResult {
+        ensure!(
+            <Proposals<T>>::contains_key(proposal_id),
+            Error::<T>::ProposalNotFound
+        );
+
+        let proposal = Self::proposals(proposal_id);
+        ensure!(
+            matches!(proposal.status, ProposalStatus::Active { .. }),
+            Error::<T>::ProposalFinalized
+        );
+
+        ensure!(
+            <Proposals<T>>::contains_key(dependency_id),
+            Error::<T>::InvalidExecutionDependency
+        );
+
+        // prevent dependency cycles
+        let mut next_dependency_id = Some(dependency_id);
+        while let Some(id) = next_dependency_id {
+            ensure!(id != proposal_id, Error::<T>::InvalidExecutionDependency);
+            next_dependency_id = Self::execution_dependency(id);
+        }
+
+        <ExecutionDependencies<T>>::insert(proposal_id, dependency_id);
+
+        Ok(())
+    }
+
     /// Resets voting results for active proposals.
     /// Possible application includes new council elections.
     pub fn reset_active_proposals() {
@@ -593,6 +779,7 @@ impl<T: Trait> Module<T> {
             <Proposals<T>>::mutate(proposal_id, |proposal| {
                 proposal.reset_proposal();
                 <VoteExistsByProposalByVoter<T>>::remove_prefix(&proposal_id);
+                <VoteRationaleByProposalByVoter<T>>::remove_prefix(&proposal_id);
             });
         });
     }
@@ -614,12 +801,14 @@ impl<T: Trait> Module<T> {
                 // load current proposal
                 let proposal = Self::proposals(proposal_id);
 
+                // Excluded voters cannot take part in the voting.
+                let total_voters_count = T::TotalVotersCounter::total_voters_count()
+                    .saturating_sub(Self::excluded_voters(proposal_id).len() as u32);
+
                 // Calculates votes, takes in account voting period expiration.
                 // If voting process is in progress, then decision status is None.
-                let decision_status = proposal.define_proposal_decision_status(
-                    T::TotalVotersCounter::total_voters_count(),
-                    Self::current_block(),
-                );
+                let decision_status = proposal
+                    .define_proposal_decision_status(total_voters_count, Self::current_block());
 
                 // map to FinalizedProposalData if decision for the proposal is made or return None
                 decision_status.map(|status| FinalizedProposalData {
@@ -659,20 +848,25 @@ impl<T: Trait> Module<T> {
 
         let proposal_code_result = T::DispatchableCallCode::decode(&mut &proposal_code[..]);
 
-        let approved_proposal_status = match proposal_code_result {
-            Ok(proposal_code) => {
-                if let Err(dispatch_error) =
-                    proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
-                {
-                    ApprovedProposalStatus::failed_execution(Self::parse_dispatch_error(
-                        dispatch_error.error,
-                    ))
-                } else {
-                    ApprovedProposalStatus::Executed
+        let approved_proposal_status =
+            if !Self::is_execution_dependency_executed(approved_proposal.proposal_id) {
+                ApprovedProposalStatus::failed_execution("Dependency proposal was not executed")
+            } else {
+                match proposal_code_result {
+                    Ok(proposal_code) => {
+                        if let Err(dispatch_error) =
+                            proposal_code.dispatch_bypass_filter(T::Origin::from(RawOrigin::Root))
+                        {
+                            ApprovedProposalStatus::failed_execution(Self::parse_dispatch_error(
+                                dispatch_error.error,
+                            ))
+                        } else {
+                            ApprovedProposalStatus::Executed
+                        }
+                    }
+                    Err(error) => ApprovedProposalStatus::failed_execution(error.what()),
                 }
-            }
-            Err(error) => ApprovedProposalStatus::failed_execution(error.what()),
-        };
+            };
 
         let proposal_execution_status = approved_proposal
             .finalisation_status_data
@@ -691,7 +885,7 @@ impl<T: Trait> Module<T> {
     }
 
     // Performs all actions on proposal finalization:
-    // - clean active proposal cache
+    // - clean active proposal cache and excluded voters
     // - update proposal status fields (status, finalized_at)
     // - add to pending execution proposal cache if approved
     // - slash and unstake proposal stake if stake exists
@@ -701,6 +895,7 @@ impl<T: Trait> Module<T> {
     fn finalize_proposal(proposal_id: T::ProposalId, decision_status: ProposalDecisionStatus) {
         Self::decrease_active_proposal_counter();
         <ActiveProposalIds<T>>::remove(&proposal_id.clone());
+        <ExcludedVoters<T>>::remove(&proposal_id);
 
         let mut proposal = Self::proposals(proposal_id);
 
@@ -779,7 +974,11 @@ impl<T: Trait> Module<T> {
             .filter_map(|(proposal_id, _)| {
                 let proposal = Self::proposals(proposal_id);
 
-                if proposal.is_grace_period_expired(Self::current_block()) {
+                // Proposals wait for their exact execution block and dependency proposal execution.
+                if proposal.is_grace_period_expired(Self::current_block())
+                    && Self::is_exact_execution_block_reached(proposal_id)
+                    && !Self::is_waiting_for_execution_dependency(proposal_id)
+                {
                     // this should be true, because it was tested inside is_grace_period_expired()
                     if let ProposalStatus::Finalized(finalisation_data) = proposal.status.clone() {
                         Some(ApprovedProposalData {
@@ -797,6 +996,43 @@ impl<T: Trait> Module<T> {
             .collect()
     }
 
+    // Checks whether the exact execution block of the proposal (if any) is reached.
+    fn is_exact_execution_block_reached(proposal_id: T::ProposalId) -> bool {
+        Self::exact_execution_block(proposal_id).map_or(true, |exact_execution_block| {
+            Self::current_block() >= exact_execution_block
+        })
+    }
+
+    // Checks whether the dependency proposal (if any) can still be executed: it is either active
+    // or approved and waits for its execution.
+    fn is_waiting_for_execution_dependency(proposal_id: T::ProposalId) -> bool {
+        Self::execution_dependency(proposal_id).map_or(
+            false,
+            |dependency_id| match Self::proposals(dependency_id).status {
+                ProposalStatus::Active(_) => true,
+                ProposalStatus::Finalized(finalization_data) => matches!(
+                    finalization_data.proposal_status,
+                    ProposalDecisionStatus::Approved(ApprovedProposalStatus::PendingExecution)
+                ),
+            },
+        )
+    }
+
+    // Checks whether the dependency proposal (if any) was executed.
+    fn is_execution_dependency_executed(proposal_id: T::ProposalId) -> bool {
+        Self::execution_dependency(proposal_id).map_or(true, |dependency_id| {
+            matches!(
+                Self::proposals(dependency_id).status,
+                ProposalStatus::Finalized(FinalizationData {
+                    proposal_status: ProposalDecisionStatus::Approved(
+                        ApprovedProposalStatus::Executed
+                    ),
+                    ..
+                })
+            )
+        })
+    }
+
     // Increases active proposal counter.
     fn increase_active_proposal_counter() {
         let next_active_proposal_count_value = Self::active_proposal_count() + 1;
@@ -46,6 +46,7 @@ impl_outer_event! {
         balances<T>,
         engine<T>,
         membership_mod<T>,
+        stake<T>,
         frame_system<T>,
     }
 }
@@ -71,6 +72,7 @@ impl common::currency::GovernanceCurrency for Test {
 impl proposals::Trait for Test {}
 
 impl stake::Trait for Test {
+    type Event = TestEvent;
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = BalanceManagerStakingEventsHandler;
@@ -83,7 +85,9 @@ parameter_types! {
     pub const RejectionFee: u64 = 3;
     pub const TitleMaxLength: u32 = 100;
     pub const DescriptionMaxLength: u32 = 10000;
+    pub const VoteRationaleMaxLength: u32 = 100;
     pub const MaxActiveProposalLimit: u32 = 100;
+    pub const ProposalsLockId: [u8; 8] = *b"proposal";
 }
 
 parameter_types! {
@@ -97,6 +101,7 @@ impl membership::Trait for Test {
     type SubscriptionId = u64;
     type ActorId = u64;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator = ();
 }
 
 impl crate::Trait for Test {
@@ -110,6 +115,7 @@ impl crate::Trait for Test {
     type RejectionFee = RejectionFee;
     type TitleMaxLength = TitleMaxLength;
     type DescriptionMaxLength = DescriptionMaxLength;
+    type VoteRationaleMaxLength = VoteRationaleMaxLength;
     type MaxActiveProposalLimit = MaxActiveProposalLimit;
     type DispatchableCallCode = proposals::Call<Test>;
 }
@@ -9,6 +9,7 @@ use frame_support::traits::{Currency, OnFinalize, OnInitialize};
 use frame_support::{StorageDoubleMap, StorageMap, StorageValue};
 use frame_system::RawOrigin;
 use frame_system::{EventRecord, Phase};
+use sp_std::marker::PhantomData;
 use sp_std::rc::Rc;
 
 pub(crate) fn increase_total_balance_issuance_using_account_id(account_id: u64, balance: u64) {
@@ -71,6 +72,7 @@ struct DummyProposalFixture {
     title: Vec<u8>,
     description: Vec<u8>,
     stake_balance: Option<BalanceOf<Test>>,
+    exactly_execute_at: Option<u64>,
 }
 
 impl Default for DummyProposalFixture {
@@ -96,6 +98,7 @@ impl Default for DummyProposalFixture {
             title,
             description,
             stake_balance: None,
+            exactly_execute_at: None,
         }
     }
 }
@@ -117,6 +120,13 @@ impl DummyProposalFixture {
         DummyProposalFixture { account_id, ..self }
     }
 
+    fn with_exact_execution_block(self, exactly_execute_at: u64) -> Self {
+        DummyProposalFixture {
+            exactly_execute_at: Some(exactly_execute_at),
+            ..self
+        }
+    }
+
     fn with_stake(self, stake_balance: BalanceOf<Test>) -> Self {
         DummyProposalFixture {
             stake_balance: Some(stake_balance),
@@ -140,6 +150,7 @@ impl DummyProposalFixture {
             self.description,
             self.stake_balance,
             self.proposal_code,
+            self.exactly_execute_at,
         );
         assert_eq!(proposal_id_result, result);
 
@@ -244,6 +255,7 @@ impl VoteGenerator {
             self.current_voter_id,
             self.proposal_id,
             vote_kind,
+            None,
         )
     }
 }
@@ -309,7 +321,8 @@ fn vote_fails_with_insufficient_rights() {
                 frame_system::RawOrigin::None.into(),
                 1,
                 1,
-                VoteKind::Approve
+                VoteKind::Approve,
+                None,
             ),
             Err(DispatchError::Other("Bad origin"))
         );
@@ -565,7 +578,7 @@ fn vote_fails_with_absent_proposal() {
 }
 
 #[test]
-fn vote_fails_on_double_voting() {
+fn vote_can_be_changed_while_proposal_is_active() {
     initial_test_ext().execute_with(|| {
         let dummy_proposal = DummyProposalFixture::default();
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
@@ -574,7 +587,162 @@ fn vote_fails_on_double_voting() {
         vote_generator.auto_increment_voter_id = false;
 
         vote_generator.vote_and_assert_ok(VoteKind::Approve);
-        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::<Test>::AlreadyVoted.into()));
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        let proposal = <crate::Proposals<Test>>::get(proposal_id);
+        assert_eq!(
+            proposal.voting_results,
+            VotingResults {
+                abstentions: 0,
+                approvals: 0,
+                rejections: 1,
+                slashes: 0,
+            }
+        );
+        assert_eq!(
+            <crate::VoteExistsByProposalByVoter<Test>>::get(proposal_id, 0),
+            VoteKind::Reject
+        );
+    });
+}
+
+#[test]
+fn vote_fails_after_proposal_finalization() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.auto_increment_voter_id = false;
+
+        vote_generator.vote_and_assert_ok(VoteKind::Slash);
+
+        assert_eq!(
+            ProposalsEngine::veto_proposal(RawOrigin::Root.into(), proposal_id),
+            Ok(())
+        );
+
+        vote_generator.vote_and_assert(
+            VoteKind::Approve,
+            Err(Error::<Test>::ProposalFinalized.into()),
+        );
+    });
+}
+
+#[test]
+fn vote_rationale_is_stored_and_emitted() {
+    initial_test_ext().execute_with(|| {
+        // Events start only from 1 first block. No events on block zero.
+        run_to_block_and_finalize(1);
+
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let rationale = b"rationale".to_vec();
+        assert_eq!(
+            ProposalsEngine::vote(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve,
+                Some(rationale.clone()),
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            <crate::VoteRationaleByProposalByVoter<Test>>::get(proposal_id, 1),
+            rationale
+        );
+
+        // changing the vote without a rationale drops the previous one
+        assert_eq!(
+            ProposalsEngine::vote(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Abstain,
+                None,
+            ),
+            Ok(())
+        );
+        assert!(!<crate::VoteRationaleByProposalByVoter<Test>>::contains_key(proposal_id, 1));
+
+        EventFixture::assert_events(vec![
+            RawEvent::ProposalCreated(1, 1),
+            RawEvent::Voted(1, 1, VoteKind::Approve, rationale),
+            RawEvent::Voted(1, 1, VoteKind::Abstain, Vec::new()),
+        ]);
+    });
+}
+
+#[test]
+fn vote_fails_with_too_long_rationale() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let too_long_rationale = vec![b'a'; VoteRationaleMaxLength::get() as usize + 1];
+        assert_eq!(
+            ProposalsEngine::vote(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                VoteKind::Approve,
+                Some(too_long_rationale),
+            ),
+            Err(Error::<Test>::RationaleIsTooLong.into())
+        );
+    });
+}
+
+#[test]
+fn excluded_voter_cannot_vote_and_is_not_counted() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let excluded_voter_id = 3;
+        assert_eq!(
+            ProposalsEngine::exclude_voter(proposal_id, excluded_voter_id),
+            Ok(())
+        );
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert(VoteKind::Approve, Err(Error::<Test>::VoterExcluded.into()));
+        vote_generator.vote_and_assert_ok(VoteKind::Abstain);
+
+        // all voters except the excluded one have voted
+        run_to_block_and_finalize(2);
+
+        let proposal = <Proposals<Test>>::get(proposal_id);
+
+        assert_eq!(
+            proposal.status,
+            ProposalStatus::finalized_successfully(ProposalDecisionStatus::Rejected, 0),
+        );
+        assert!(!<crate::ExcludedVoters<Test>>::contains_key(proposal_id));
+    });
+}
+
+#[test]
+fn exclude_voter_fails_after_voting() {
+    initial_test_ext().execute_with(|| {
+        let dummy_proposal = DummyProposalFixture::default();
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(
+            ProposalsEngine::exclude_voter(proposal_id, 1),
+            Err(Error::<Test>::AlreadyVoted.into())
+        );
+        assert_eq!(
+            ProposalsEngine::exclude_voter(2, 1),
+            Err(Error::<Test>::ProposalNotFound.into())
+        );
     });
 }
 
@@ -791,7 +959,7 @@ fn vote_proposal_event_emitted() {
 
         EventFixture::assert_events(vec![
             RawEvent::ProposalCreated(1, 1),
-            RawEvent::Voted(1, 1, VoteKind::Approve),
+            RawEvent::Voted(1, 1, VoteKind::Approve, Vec::new()),
         ]);
     });
 }
@@ -1275,6 +1443,71 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
     });
 }
 
+#[test]
+fn proposal_cancellation_with_lock_stake_handler_succeeds() {
+    handle_mock(|| {
+        initial_test_ext().execute_with(|| {
+            set_stake_handler_impl(Rc::new(crate::types::LockStakeHandler::<
+                Test,
+                ProposalsLockId,
+            > {
+                marker: PhantomData,
+            }));
+
+            let account_id = 1;
+
+            let stake_amount = 200;
+            let parameters = ProposalParameters {
+                voting_period: 3,
+                approval_quorum_percentage: 50,
+                approval_threshold_percentage: 60,
+                slashing_quorum_percentage: 60,
+                slashing_threshold_percentage: 60,
+                grace_period: 5,
+                required_stake: Some(stake_amount),
+            };
+            let dummy_proposal = DummyProposalFixture::default()
+                .with_parameters(parameters)
+                .with_account_id(account_id.clone())
+                .with_stake(stake_amount);
+
+            let account_balance = 500;
+            let _imbalance =
+                <Test as stake::Trait>::Currency::deposit_creating(&account_id, account_balance);
+
+            let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+            // the stake stays on the proposer account under the lock
+            assert_eq!(
+                <Test as stake::Trait>::Currency::total_balance(&account_id),
+                account_balance
+            );
+            assert_eq!(
+                Balances::usable_balance(&account_id),
+                account_balance - stake_amount
+            );
+            assert_eq!(stake::Module::<Test>::stake_pool_balance(), 0);
+
+            let cancel_proposal_fixture = CancelProposalFixture::new(proposal_id);
+            cancel_proposal_fixture.cancel_and_assert(Ok(()));
+
+            let cancellation_fee = CancellationFee::get();
+            assert_eq!(
+                <Test as stake::Trait>::Currency::total_balance(&account_id),
+                account_balance - cancellation_fee
+            );
+            assert_eq!(
+                Balances::usable_balance(&account_id),
+                account_balance - cancellation_fee
+            );
+            assert_eq!(
+                stake::Module::<Test>::locked_balance(&ProposalsLockId::get(), &account_id),
+                0
+            );
+        });
+    });
+}
+
 #[test]
 fn finalize_proposal_using_stake_mocks_succeeds() {
     handle_mock(|| {
@@ -1283,11 +1516,7 @@ fn finalize_proposal_using_stake_mocks_succeeds() {
                 let mut mock = crate::types::MockStakeHandler::<Test>::new();
                 mock.expect_create_stake().times(1).returning(|| Ok(1));
 
-                mock.expect_make_stake_imbalance()
-                    .times(1)
-                    .returning(|_, _| Ok(crate::types::NegativeImbalance::<Test>::new(200)));
-
-                mock.expect_stake().times(1).returning(|_, _| Ok(()));
+                mock.expect_stake().times(1).returning(|_, _, _| Ok(()));
 
                 mock.expect_remove_stake().times(1).returning(|_| Ok(()));
 
@@ -1371,11 +1600,7 @@ fn finalize_proposal_using_stake_mocks_failed() {
                     .times(1)
                     .returning(|_| Err("Cannot remove stake"));
 
-                mock.expect_make_stake_imbalance()
-                    .times(1)
-                    .returning(|_, _| Ok(crate::types::NegativeImbalance::<Test>::new(200)));
-
-                mock.expect_stake().times(1).returning(|_, _| Ok(()));
+                mock.expect_stake().times(1).returning(|_, _, _| Ok(()));
 
                 mock.expect_unstake().times(1).returning(|_| Ok(()));
 
@@ -1611,3 +1836,229 @@ fn slash_balance_is_calculated_correctly() {
         assert_eq!(slash_balance_with_stake, stake);
     });
 }
+
+#[test]
+fn proposal_execution_waits_for_dependency_execution() {
+    initial_test_ext().execute_with(|| {
+        let dependency_parameters = ProposalParametersFixture::default().with_grace_period(2);
+        let dependency_proposal =
+            DummyProposalFixture::default().with_parameters(dependency_parameters.params());
+        let dependency_id = dependency_proposal
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+
+        let parameters_fixture = ProposalParametersFixture::default();
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(2)).unwrap();
+
+        assert_eq!(
+            ProposalsEngine::set_execution_dependency(proposal_id, dependency_id),
+            Ok(())
+        );
+
+        for id in [dependency_id, proposal_id].iter() {
+            let mut vote_generator = VoteGenerator::new(*id);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+            vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        }
+
+        run_to_block_and_finalize(1);
+
+        // grace period has expired, but the dependency proposal is not executed yet
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0)
+        );
+
+        run_to_block_and_finalize(3);
+
+        assert_eq!(
+            <crate::Proposals<Test>>::get(dependency_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+    });
+}
+
+#[test]
+fn proposal_execution_fails_when_dependency_was_not_executed() {
+    initial_test_ext().execute_with(|| {
+        let dependency_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        assert_eq!(
+            ProposalsEngine::set_execution_dependency(proposal_id, dependency_id),
+            Ok(())
+        );
+
+        let mut vote_generator = VoteGenerator::new(dependency_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+        vote_generator.vote_and_assert_ok(VoteKind::Reject);
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block_and_finalize(1);
+
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(
+                ApprovedProposalStatus::failed_execution("Dependency proposal was not executed"),
+                0
+            )
+        );
+    });
+}
+
+#[test]
+fn set_execution_dependency_fails_with_invalid_dependency() {
+    initial_test_ext().execute_with(|| {
+        let first_proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let second_proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        assert_eq!(
+            ProposalsEngine::set_execution_dependency(3, first_proposal_id),
+            Err(Error::<Test>::ProposalNotFound.into())
+        );
+        assert_eq!(
+            ProposalsEngine::set_execution_dependency(first_proposal_id, 3),
+            Err(Error::<Test>::InvalidExecutionDependency.into())
+        );
+        assert_eq!(
+            ProposalsEngine::set_execution_dependency(first_proposal_id, first_proposal_id),
+            Err(Error::<Test>::InvalidExecutionDependency.into())
+        );
+
+        assert_eq!(
+            ProposalsEngine::set_execution_dependency(second_proposal_id, first_proposal_id),
+            Ok(())
+        );
+        assert_eq!(
+            ProposalsEngine::set_execution_dependency(first_proposal_id, second_proposal_id),
+            Err(Error::<Test>::InvalidExecutionDependency.into())
+        );
+    });
+}
+
+#[test]
+fn set_proposal_execution_dependency_succeeds_before_the_first_vote() {
+    initial_test_ext().execute_with(|| {
+        let dependency_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(1))
+            .unwrap();
+        let proposal_id = DummyProposalFixture::default()
+            .create_proposal_and_assert(Ok(2))
+            .unwrap();
+
+        assert_eq!(
+            ProposalsEngine::set_proposal_execution_dependency(
+                RawOrigin::Signed(2).into(),
+                2,
+                proposal_id,
+                dependency_id
+            ),
+            Err(Error::<Test>::NotAuthor.into())
+        );
+
+        assert_eq!(
+            ProposalsEngine::set_proposal_execution_dependency(
+                RawOrigin::Signed(1).into(),
+                1,
+                proposal_id,
+                dependency_id
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            ProposalsEngine::execution_dependency(proposal_id),
+            Some(dependency_id)
+        );
+
+        let mut vote_generator = VoteGenerator::new(dependency_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        assert_eq!(
+            ProposalsEngine::set_proposal_execution_dependency(
+                RawOrigin::Signed(1).into(),
+                1,
+                dependency_id,
+                proposal_id
+            ),
+            Err(Error::<Test>::ProposalHasVotes.into())
+        );
+    });
+}
+
+#[test]
+fn proposal_execution_waits_for_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
+        let dummy_proposal = DummyProposalFixture::default()
+            .with_parameters(parameters_fixture.params())
+            .with_exact_execution_block(5);
+        let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        // grace period has expired, but the exact execution block is not reached yet
+        run_to_block_and_finalize(4);
+
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::PendingExecution, 0)
+        );
+
+        run_to_block_and_finalize(5);
+
+        assert_eq!(
+            <crate::Proposals<Test>>::get(proposal_id).status,
+            ProposalStatus::approved(ApprovedProposalStatus::Executed, 0)
+        );
+    });
+}
+
+#[test]
+fn create_proposal_fails_with_early_exact_execution_block() {
+    initial_test_ext().execute_with(|| {
+        run_to_block_and_finalize(1);
+
+        // voting period (3) and grace period (2) end at the block 6
+        let parameters_fixture = ProposalParametersFixture::default().with_grace_period(2);
+        let dummy_proposal =
+            DummyProposalFixture::default().with_parameters(parameters_fixture.params());
+
+        dummy_proposal
+            .clone()
+            .with_exact_execution_block(5)
+            .create_proposal_and_assert(Err(Error::<Test>::InvalidExactExecutionBlock.into()));
+
+        dummy_proposal
+            .with_exact_execution_block(6)
+            .create_proposal_and_assert(Ok(1));
+
+        assert_eq!(ProposalsEngine::exact_execution_block(1), Some(6));
+    });
+}
@@ -21,10 +21,12 @@ pub use proposal_statuses::{
     ApprovedProposalStatus, FinalizationData, ProposalDecisionStatus, ProposalStatus,
 };
 pub(crate) use stakes::ProposalStakeManager;
-pub use stakes::{DefaultStakeHandlerProvider, StakeHandler, StakeHandlerProvider};
+pub use stakes::{
+    DefaultStakeHandlerProvider, LockStakeHandlerProvider, StakeHandler, StakeHandlerProvider,
+};
 
 #[cfg(test)]
-pub(crate) use stakes::DefaultStakeHandler;
+pub(crate) use stakes::{DefaultStakeHandler, LockStakeHandler};
 
 #[cfg(test)]
 pub(crate) use stakes::MockStakeHandler;
@@ -110,6 +112,16 @@ impl VotingResults {
         }
     }
 
+    /// Remove vote from the related counter
+    pub fn remove_vote(&mut self, vote: VoteKind) {
+        match vote {
+            VoteKind::Abstain => self.abstentions = self.abstentions.saturating_sub(1),
+            VoteKind::Approve => self.approvals = self.approvals.saturating_sub(1),
+            VoteKind::Reject => self.rejections = self.rejections.saturating_sub(1),
+            VoteKind::Slash => self.slashes = self.slashes.saturating_sub(1),
+        }
+    }
+
     /// Calculates number of votes so far
     pub fn votes_number(&self) -> u32 {
         self.abstentions + self.approvals + self.rejections + self.slashes
@@ -1,8 +1,8 @@
 #![warn(missing_docs)]
 
-use super::{BalanceOf, CurrencyOf, NegativeImbalance};
+use super::BalanceOf;
 use crate::Trait;
-use frame_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
+use frame_support::traits::{Get, LockIdentifier};
 use sp_std::convert::From;
 use sp_std::marker::PhantomData;
 use sp_std::rc::Rc;
@@ -31,17 +31,34 @@ impl<T: Trait> StakeHandlerProvider<T> for DefaultStakeHandlerProvider {
     }
 }
 
+/// Balance lock based implementation of the stake module logic provider. Stakes stay on the
+/// source account under the balance lock with the 'LockId' identifier.
+pub struct LockStakeHandlerProvider<LockId> {
+    marker: PhantomData<LockId>,
+}
+impl<T: Trait, LockId: Get<LockIdentifier> + 'static> StakeHandlerProvider<T>
+    for LockStakeHandlerProvider<LockId>
+{
+    /// Returns stake logic handler
+    fn stakes() -> Rc<dyn StakeHandler<T>> {
+        Rc::new(LockStakeHandler {
+            marker: PhantomData::<(T, LockId)>::default(),
+        })
+    }
+}
+
 /// Stake logic handler.
 #[cfg_attr(test, automock)] // attributes creates mocks in testing environment
 pub trait StakeHandler<T: Trait> {
     /// Creates a stake. Returns created stake id or an error.
     fn create_stake(&self) -> Result<T::StakeId, &'static str>;
 
-    /// Stake the imbalance
+    /// Stake the balance from the source account
     fn stake(
         &self,
         stake_id: &T::StakeId,
-        stake_imbalance: NegativeImbalance<T>,
+        stake_balance: BalanceOf<T>,
+        source_account_id: &T::AccountId,
     ) -> Result<(), &'static str>;
 
     /// Removes stake
@@ -52,13 +69,6 @@ pub trait StakeHandler<T: Trait> {
 
     /// Slash balance from the existing stake
     fn slash(&self, stake_id: T::StakeId, slash_balance: BalanceOf<T>) -> Result<(), &'static str>;
-
-    /// Withdraw some balance from the source account and create stake imbalance
-    fn make_stake_imbalance(
-        &self,
-        balance: BalanceOf<T>,
-        source_account_id: &T::AccountId,
-    ) -> Result<NegativeImbalance<T>, &'static str>;
 }
 
 /// Default implementation of the stake logic. Uses actual stake module.
@@ -73,13 +83,15 @@ impl<T: Trait> StakeHandler<T> for DefaultStakeHandler<T> {
         Ok(stake::Module::<T>::create_stake())
     }
 
-    /// Stake the imbalance
+    /// Stake the balance from the source account
     fn stake(
         &self,
         stake_id: &<T as stake::Trait>::StakeId,
-        stake_imbalance: NegativeImbalance<T>,
+        stake_balance: BalanceOf<T>,
+        source_account_id: &T::AccountId,
     ) -> Result<(), &'static str> {
-        stake::Module::<T>::stake(&stake_id, stake_imbalance).map_err(WrappedError)?;
+        stake::Module::<T>::stake_from_account(&stake_id, source_account_id, stake_balance)
+            .map_err(WrappedError)?;
 
         Ok(())
     }
@@ -110,20 +122,63 @@ impl<T: Trait> StakeHandler<T> for DefaultStakeHandler<T> {
 
         Ok(())
     }
+}
 
-    /// Withdraw some balance from the source account and create stake imbalance
-    fn make_stake_imbalance(
+/// Balance lock based implementation of the stake logic. Uses actual stake module.
+/// 'marker' responsible for the 'Trait' and 'LockId' binding.
+pub(crate) struct LockStakeHandler<T, LockId> {
+    pub marker: PhantomData<(T, LockId)>,
+}
+
+impl<T: Trait, LockId: Get<LockIdentifier>> StakeHandler<T> for LockStakeHandler<T, LockId> {
+    /// Creates a stake. Returns created stake id or an error.
+    fn create_stake(&self) -> Result<<T as stake::Trait>::StakeId, &'static str> {
+        Ok(stake::Module::<T>::create_stake())
+    }
+
+    /// Stake the balance by locking it on the source account
+    fn stake(
         &self,
-        balance: BalanceOf<T>,
+        stake_id: &<T as stake::Trait>::StakeId,
+        stake_balance: BalanceOf<T>,
         source_account_id: &T::AccountId,
-    ) -> Result<NegativeImbalance<T>, &'static str> {
-        CurrencyOf::<T>::withdraw(
+    ) -> Result<(), &'static str> {
+        stake::Module::<T>::stake_with_lock(
+            &stake_id,
             source_account_id,
-            balance,
-            WithdrawReasons::all(),
-            ExistenceRequirement::AllowDeath,
+            stake_balance,
+            LockId::get(),
         )
-        .map_err(<&str>::from)
+        .map_err(WrappedError)?;
+
+        Ok(())
+    }
+
+    /// Removes stake
+    fn remove_stake(&self, stake_id: <T as stake::Trait>::StakeId) -> Result<(), &'static str> {
+        stake::Module::<T>::remove_stake(&stake_id).map_err(WrappedError)?;
+
+        Ok(())
+    }
+
+    /// Execute unstaking. Unlocks the stake balance on the source account.
+    fn unstake(&self, stake_id: <T as stake::Trait>::StakeId) -> Result<(), &'static str> {
+        stake::Module::<T>::initiate_unstaking(&stake_id, None).map_err(WrappedError)?;
+
+        Ok(())
+    }
+
+    /// Slash balance from the existing stake
+    fn slash(
+        &self,
+        stake_id: <T as stake::Trait>::StakeId,
+        slash_balance: BalanceOf<T>,
+    ) -> Result<(), &'static str> {
+        let _ignored_successful_result =
+            stake::Module::<T>::slash_immediate(&stake_id, slash_balance, false)
+                .map_err(WrappedError)?;
+
+        Ok(())
     }
 }
 
@@ -142,10 +197,7 @@ impl<T: Trait> ProposalStakeManager<T> {
     ) -> Result<T::StakeId, &'static str> {
         let stake_id = T::StakeHandlerProvider::stakes().create_stake()?;
 
-        let stake_imbalance = T::StakeHandlerProvider::stakes()
-            .make_stake_imbalance(stake_balance, &source_account_id)?;
-
-        T::StakeHandlerProvider::stakes().stake(&stake_id, stake_imbalance)?;
+        T::StakeHandlerProvider::stakes().stake(&stake_id, stake_balance, &source_account_id)?;
 
         Ok(stake_id)
     }
@@ -192,18 +244,25 @@ impl From<WrappedError<stake::StakeActionError<stake::InitiateUnstakingError>>>
     }
 }
 
-// error conversion for the Wrapped StakeActionError with the inner StakingError
-impl From<WrappedError<stake::StakeActionError<stake::StakingError>>> for &str {
-    fn from(wrapper: WrappedError<stake::StakeActionError<stake::StakingError>>) -> Self {
+// error conversion for the Wrapped StakeActionError with the inner StakingFromAccountError
+impl From<WrappedError<stake::StakeActionError<stake::StakingFromAccountError>>> for &str {
+    fn from(
+        wrapper: WrappedError<stake::StakeActionError<stake::StakingFromAccountError>>,
+    ) -> Self {
         {
             match wrapper.0 {
                 stake::StakeActionError::StakeNotFound => "StakeNotFound",
                 stake::StakeActionError::Error(err) => match err {
-                    stake::StakingError::CannotStakeZero => "CannotStakeZero",
-                    stake::StakingError::CannotStakeLessThanMinimumBalance => {
-                        "CannotStakeLessThanMinimumBalance"
+                    stake::StakingFromAccountError::StakingError(e) => match e {
+                        stake::StakingError::CannotStakeZero => "CannotStakeZero",
+                        stake::StakingError::CannotStakeLessThanMinimumBalance => {
+                            "CannotStakeLessThanMinimumBalance"
+                        }
+                        stake::StakingError::AlreadyStaked => "AlreadyStaked",
+                    },
+                    stake::StakingFromAccountError::InsufficientBalanceInSourceAccount => {
+                        "InsufficientBalanceInSourceAccount"
                     }
-                    stake::StakingError::AlreadyStaked => "AlreadyStaked",
                 },
             }
         }
@@ -10,9 +10,12 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::storage::IterableStorageMap;
-use frame_support::{decl_module, decl_storage, ensure, Parameter};
+use frame_support::traits::Get;
+use frame_support::{decl_error, decl_module, decl_storage, ensure, Parameter};
+use frame_system::ensure_signed;
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_std::vec::Vec;
 
 use minting::BalanceOf;
 
@@ -41,6 +44,9 @@ pub trait Trait: frame_system::Trait + minting::Trait {
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Maximum owed reward amount paid out in addition to a successful scheduled payout.
+    type CatchUpPayoutLimit: Get<BalanceOf<Self>>;
 }
 
 /// Handler for aftermath of a payout attempt
@@ -152,15 +158,68 @@ decl_storage! {
             T::RewardRelationshipId => RewardRelationship<T::AccountId, BalanceOf<T>, T::BlockNumber, T::MintId, T::RecipientId>;
 
         RewardRelationshipsCreated get(fn reward_relationships_created): T::RewardRelationshipId;
+
+        /// Reward relationships with the pending payout indexed by the payout block number.
+        pub PayoutSchedule get(fn payout_schedule): map hasher(blake2_128_concat)
+            T::BlockNumber => Vec<T::RewardRelationshipId>;
+
+        /// Reward owed to the relationship recipient for the missed payouts.
+        pub OwedRewards get(fn owed_rewards): map hasher(blake2_128_concat)
+            T::RewardRelationshipId => BalanceOf<T>;
+
+        /// Removed reward relationships kept until their owed reward is claimed.
+        pub RemovedWithOwedReward get(fn removed_with_owed_reward): map hasher(blake2_128_concat)
+            T::RewardRelationshipId => ();
+    }
+}
+
+decl_error! {
+    /// Recurring reward module predefined errors
+    pub enum Error for Module<T: Trait> {
+        /// Reward relationship doesn't exist.
+        RewardRelationshipNotFound,
+
+        /// Origin is not the reward account of the relationship.
+        OriginIsNotRewardAccount,
+
+        /// Reward relationship has no owed reward.
+        NoOwedReward,
+
+        /// Reward source mint has insufficient capacity to pay the owed reward.
+        InsufficientRewardSourceCapacity,
     }
 }
 
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        /// Predefined errors
+        type Error = Error<T>;
 
         fn on_finalize(now: T::BlockNumber) {
             Self::do_payouts(now);
         }
+
+        /// Pays the owed reward to the reward account of the relationship. The owed reward of the
+        /// removed relationship stays claimable until paid. Requires the reward account signature.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn claim_owed_reward(origin, relationship_id: T::RewardRelationshipId) {
+            let account_id = ensure_signed(origin)?;
+
+            ensure!(
+                <RewardRelationships<T>>::contains_key(&relationship_id),
+                Error::<T>::RewardRelationshipNotFound
+            );
+
+            ensure!(
+                Self::reward_relationships(&relationship_id).account == account_id,
+                Error::<T>::OriginIsNotRewardAccount
+            );
+
+            Self::pay_owed_reward(relationship_id).map_err(|err| match err {
+                RewardsError::NoOwedReward => Error::<T>::NoOwedReward,
+                _ => Error::<T>::InsufficientRewardSourceCapacity,
+            })?;
+        }
     }
 }
 
@@ -170,6 +229,8 @@ pub enum RewardsError {
     RewardSourceNotFound,
     NextPaymentNotInFuture,
     RewardRelationshipNotFound,
+    NoOwedReward,
+    InsufficientRewardSourceCapacity,
 }
 
 impl<T: Trait> Module<T> {
@@ -205,6 +266,7 @@ impl<T: Trait> Module<T> {
 
         let relationship_id = Self::reward_relationships_created();
         <RewardRelationshipsCreated<T>>::put(relationship_id + One::one());
+        Self::schedule_payout(relationship_id, next_payment_at_block);
         <RewardRelationships<T>>::insert(
             relationship_id,
             RewardRelationship {
@@ -222,12 +284,35 @@ impl<T: Trait> Module<T> {
     }
 
     /// Removes a relationship from RewardRelashionships and its recipient.
+    /// The owed reward is paid if the mint has enough capacity. Otherwise the relationship is
+    /// deactivated and kept until the owed reward is claimed.
     pub fn remove_reward_relationship(id: T::RewardRelationshipId) {
         if <RewardRelationships<T>>::contains_key(&id) {
-            <Recipients<T>>::remove(<RewardRelationships<T>>::take(&id).recipient);
+            if Self::pay_owed_reward(id).is_err() && <OwedRewards<T>>::contains_key(&id) {
+                let _ = Self::try_to_deactivate_relationship(id);
+
+                <RemovedWithOwedReward<T>>::insert(id, ());
+
+                return;
+            }
+
+            Self::do_remove_reward_relationship(id);
         }
     }
 
+    // Removes the relationship, its payout schedule and its recipient.
+    fn do_remove_reward_relationship(id: T::RewardRelationshipId) {
+        let relationship = <RewardRelationships<T>>::take(&id);
+
+        if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+            Self::unschedule_payout(id, next_payment_at_block);
+        }
+
+        <OwedRewards<T>>::remove(id);
+        <RemovedWithOwedReward<T>>::remove(id);
+        <Recipients<T>>::remove(relationship.recipient);
+    }
+
     /// Will attempt to activat a deactivated reward relationship.
     pub fn try_to_activate_relationship(
         id: T::RewardRelationshipId,
@@ -245,6 +330,7 @@ impl<T: Trait> Module<T> {
                 reward_relationship.clone_activated(&next_payment_at_block);
 
             RewardRelationships::<T>::insert(id, activated_relationship);
+            Self::schedule_payout(id, next_payment_at_block);
 
             // We activated
             true
@@ -263,6 +349,10 @@ impl<T: Trait> Module<T> {
 
             RewardRelationships::<T>::insert(id, deactivated_relationship);
 
+            if let Some(next_payment_at_block) = reward_relationship.next_payment_at_block {
+                Self::unschedule_payout(id, next_payment_at_block);
+            }
+
             // Was deactivated
             true
         } else {
@@ -290,6 +380,7 @@ impl<T: Trait> Module<T> {
         );
 
         let mut relationship = Self::reward_relationships(&id);
+        let old_next_payment_at_block = relationship.next_payment_at_block;
 
         if let Some(account) = new_account {
             relationship.account = account;
@@ -310,20 +401,82 @@ impl<T: Trait> Module<T> {
             relationship.payout_interval = payout_interval;
         }
 
+        if relationship.next_payment_at_block != old_next_payment_at_block {
+            if let Some(blocknumber) = old_next_payment_at_block {
+                Self::unschedule_payout(id, blocknumber);
+            }
+            if let Some(blocknumber) = relationship.next_payment_at_block {
+                Self::schedule_payout(id, blocknumber);
+            }
+        }
+
         <RewardRelationships<T>>::insert(&id, relationship);
         Ok(())
     }
 
+    /// Pays the whole owed reward of the relationship from its mint. The removed relationship
+    /// is deleted once its owed reward is paid. Returns the paid amount.
+    pub fn pay_owed_reward(id: T::RewardRelationshipId) -> Result<BalanceOf<T>, RewardsError> {
+        ensure!(
+            <RewardRelationships<T>>::contains_key(&id),
+            RewardsError::RewardRelationshipNotFound
+        );
+
+        let owed = Self::owed_rewards(&id);
+
+        ensure!(owed != Zero::zero(), RewardsError::NoOwedReward);
+
+        let mut relationship = Self::reward_relationships(&id);
+
+        <minting::Module<T>>::transfer_tokens(relationship.mint_id, owed, &relationship.account)
+            .map_err(|_| RewardsError::InsufficientRewardSourceCapacity)?;
+
+        relationship.total_reward_received += owed;
+        <Recipients<T>>::mutate(relationship.recipient, |recipient| {
+            recipient.total_reward_received += owed;
+        });
+        <RewardRelationships<T>>::insert(&id, relationship);
+        <OwedRewards<T>>::remove(&id);
+
+        if <RemovedWithOwedReward<T>>::contains_key(&id) {
+            Self::do_remove_reward_relationship(id);
+        }
+
+        Ok(owed)
+    }
+
+    /// Builds the payout schedule for the existing active reward relationships.
+    /// Should be called on the migration to the new runtime version.
+    pub fn migrate_payout_schedule() {
+        let now = <frame_system::Module<T>>::block_number();
+
+        for (relationship_id, relationship) in <RewardRelationships<T>>::iter() {
+            if let Some(next_payment_at_block) = relationship.next_payment_at_block {
+                // Past payouts could not be processed by the scan either.
+                if next_payment_at_block >= now {
+                    Self::schedule_payout(relationship_id, next_payment_at_block);
+                }
+            }
+        }
+    }
+
     /*
-    For all relationships where next_payment_at_block is set and matches current block height,
+    For all relationships scheduled for the current block height in the payout schedule,
     a call to pay_reward is made for the suitable amount, recipient and source.
     The next_payment_in_block is updated based on payout_interval.
     If the call succeeds, total_reward_received is incremented on both
     recipient and dependency with amount_per_payout, and a call to T::PayoutStatusHandler is made.
-    Otherwise, analogous steps for failure.
+    The owed reward is then paid out, capped by the T::CatchUpPayoutLimit.
+    Otherwise, analogous steps for failure and the payout is added to the owed reward.
     */
     fn do_payouts(now: T::BlockNumber) {
-        for (relationship_id, ref mut relationship) in <RewardRelationships<T>>::iter() {
+        for relationship_id in <PayoutSchedule<T>>::take(now) {
+            if !<RewardRelationships<T>>::contains_key(&relationship_id) {
+                continue;
+            }
+
+            let mut relationship = Self::reward_relationships(&relationship_id);
+
             assert!(<Recipients<T>>::contains_key(&relationship.recipient));
 
             let mut recipient = Self::recipients(relationship.recipient);
@@ -333,8 +486,6 @@ impl<T: Trait> Module<T> {
                     continue;
                 }
 
-                // Add the missed payout and try to pay those in addition to scheduled payout?
-                // let payout = relationship.total_reward_missed + relationship.amount_per_payout;
                 let payout = relationship.amount_per_payout;
 
                 // try to make payment
@@ -351,6 +502,9 @@ impl<T: Trait> Module<T> {
                     // update recipient stats
                     recipient.total_reward_missed += relationship.amount_per_payout;
 
+                    // owe the missed payout to the recipient
+                    <OwedRewards<T>>::mutate(relationship_id, |owed| *owed += payout);
+
                     T::PayoutStatusHandler::payout_failed(
                         relationship_id,
                         &relationship.account,
@@ -361,24 +515,45 @@ impl<T: Trait> Module<T> {
                     relationship.total_reward_received += payout;
                     recipient.total_reward_received += payout;
 
-                    // update missed payout stats
-                    // if relationship.total_reward_missed != Zero::zero() {
-                    //     // update recipient stats
-                    //     recipient.total_reward_missed -= relationship.total_reward_missed;
-
-                    //     // clear missed reward on relationship
-                    //     relationship.total_reward_missed = Zero::zero();
-                    // }
                     T::PayoutStatusHandler::payout_succeeded(
                         relationship_id,
                         &relationship.account,
                         payout,
                     );
+
+                    // try to catch up with the owed reward
+                    let owed = Self::owed_rewards(relationship_id);
+                    let catch_up_payout = owed.min(T::CatchUpPayoutLimit::get());
+
+                    if catch_up_payout != Zero::zero()
+                        && <minting::Module<T>>::transfer_tokens(
+                            relationship.mint_id,
+                            catch_up_payout,
+                            &relationship.account,
+                        )
+                        .is_ok()
+                    {
+                        relationship.total_reward_received += catch_up_payout;
+                        recipient.total_reward_received += catch_up_payout;
+
+                        if owed == catch_up_payout {
+                            <OwedRewards<T>>::remove(relationship_id);
+                        } else {
+                            <OwedRewards<T>>::insert(relationship_id, owed - catch_up_payout);
+                        }
+
+                        T::PayoutStatusHandler::payout_succeeded(
+                            relationship_id,
+                            &relationship.account,
+                            catch_up_payout,
+                        );
+                    }
                 }
 
                 // update next payout blocknumber at interval if set
                 if let Some(payout_interval) = relationship.payout_interval {
                     relationship.next_payment_at_block = Some(now + payout_interval);
+                    Self::schedule_payout(relationship_id, now + payout_interval);
                 } else {
                     relationship.next_payment_at_block = None;
                 }
@@ -391,6 +566,20 @@ impl<T: Trait> Module<T> {
 }
 
 impl<T: Trait> Module<T> {
+    // Adds the relationship to the payout schedule for the given block.
+    fn schedule_payout(id: T::RewardRelationshipId, payout_at_block: T::BlockNumber) {
+        <PayoutSchedule<T>>::mutate(payout_at_block, |ids| ids.push(id));
+    }
+
+    // Removes the relationship from the payout schedule for the given block.
+    fn unschedule_payout(id: T::RewardRelationshipId, payout_at_block: T::BlockNumber) {
+        <PayoutSchedule<T>>::mutate(payout_at_block, |ids| ids.retain(|item| *item != id));
+
+        if <PayoutSchedule<T>>::get(payout_at_block).is_empty() {
+            <PayoutSchedule<T>>::remove(payout_at_block);
+        }
+    }
+
     fn ensure_reward_relationship_exists(
         id: &T::RewardRelationshipId,
     ) -> Result<
@@ -60,6 +60,7 @@ impl frame_system::Trait for Test {
 
 parameter_types! {
     pub const ExistentialDeposit: u32 = 0;
+    pub const CatchUpPayoutLimit: u64 = 500;
 }
 
 impl balances::Trait for Test {
@@ -76,11 +77,18 @@ impl Trait for Test {
     type PayoutStatusHandler = MockStatusHandler;
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type CatchUpPayoutLimit = CatchUpPayoutLimit;
+}
+
+parameter_types! {
+    pub const MaxTransferLogLength: u32 = 10;
 }
 
 impl minting::Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type MintId = u64;
+    type MaxTransferLogLength = MaxTransferLogLength;
 }
 
 pub fn build_test_externalities() -> sp_io::TestExternalities {
@@ -210,6 +210,8 @@ fn track_missed_payouts() {
         let recipient = Rewards::recipients(&recipient_id);
         assert_eq!(recipient.total_reward_received, 0);
         assert_eq!(recipient.total_reward_missed, payout);
+
+        assert_eq!(Rewards::owed_rewards(&relationship_id), payout);
     });
 }
 
@@ -257,3 +259,342 @@ fn activate_and_deactivate_relationship() {
         assert!(!Rewards::try_to_activate_relationship(relationship_id, next_payout_at).unwrap());
     });
 }
+
+#[test]
+fn payout_schedule_is_maintained() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let interval: u64 = 600;
+
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            Some(interval),
+        )
+        .unwrap();
+        assert_eq!(
+            Rewards::payout_schedule(next_payout_at),
+            vec![relationship_id]
+        );
+
+        // Moving the next payment reschedules the payout
+        let new_next_payout_at: u64 = 13333;
+        assert!(Rewards::set_reward_relationship(
+            relationship_id,
+            None,
+            None,
+            Some(Some(new_next_payout_at)),
+            None
+        )
+        .is_ok());
+        assert!(!<PayoutSchedule<Test>>::contains_key(next_payout_at));
+        assert_eq!(
+            Rewards::payout_schedule(new_next_payout_at),
+            vec![relationship_id]
+        );
+
+        // Deactivation removes the scheduled payout
+        assert!(Rewards::try_to_deactivate_relationship(relationship_id).unwrap());
+        assert!(!<PayoutSchedule<Test>>::contains_key(new_next_payout_at));
+
+        // Activation schedules the payout again
+        assert!(Rewards::try_to_activate_relationship(relationship_id, next_payout_at).unwrap());
+        assert_eq!(
+            Rewards::payout_schedule(next_payout_at),
+            vec![relationship_id]
+        );
+
+        // Payout schedules the next payout at the interval
+        Rewards::do_payouts(next_payout_at);
+        assert!(!<PayoutSchedule<Test>>::contains_key(next_payout_at));
+        assert_eq!(
+            Rewards::payout_schedule(next_payout_at + interval),
+            vec![relationship_id]
+        );
+
+        // Removal clears the scheduled payout
+        Rewards::remove_reward_relationship(relationship_id);
+        assert!(!<PayoutSchedule<Test>>::contains_key(
+            next_payout_at + interval
+        ));
+    });
+}
+
+#[test]
+fn migrate_payout_schedule_succeeds() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let _ = Balances::deposit_creating(&recipient_account, 400);
+        let mint_id = create_new_mint_with_capacity(1000000);
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+
+        let active_relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            Rewards::add_recipient(),
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        let inactive_relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            Rewards::add_recipient(),
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+        assert!(Rewards::try_to_deactivate_relationship(inactive_relationship_id).unwrap());
+
+        // Relationships created before the payout schedule existed
+        <PayoutSchedule<Test>>::remove(next_payout_at);
+
+        Rewards::migrate_payout_schedule();
+
+        assert_eq!(
+            Rewards::payout_schedule(next_payout_at),
+            vec![active_relationship_id]
+        );
+
+        let starting_balance = Balances::free_balance(&recipient_account);
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + payout
+        );
+    });
+}
+
+#[test]
+fn catch_up_owed_rewards() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let _ = Balances::deposit_creating(&recipient_account, 400);
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let interval: u64 = 600;
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            Some(interval),
+        )
+        .unwrap();
+
+        let starting_balance = Balances::free_balance(&recipient_account);
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Balances::free_balance(&recipient_account), starting_balance);
+        assert_eq!(Rewards::owed_rewards(&relationship_id), payout);
+
+        // Capacity returns
+        assert!(Minting::set_mint_capacity(mint_id, 1000000).is_ok());
+
+        let catch_up_limit = <Test as Trait>::CatchUpPayoutLimit::get();
+
+        Rewards::do_payouts(next_payout_at + interval);
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + payout + catch_up_limit
+        );
+        assert_eq!(
+            Rewards::owed_rewards(&relationship_id),
+            payout - catch_up_limit
+        );
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, payout + catch_up_limit);
+        assert_eq!(relationship.total_reward_missed, payout);
+
+        let recipient = Rewards::recipients(&recipient_id);
+        assert_eq!(recipient.total_reward_received, payout + catch_up_limit);
+
+        Rewards::do_payouts(next_payout_at + 2 * interval);
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + 3 * payout
+        );
+        assert!(!<OwedRewards<Test>>::contains_key(&relationship_id));
+    });
+}
+
+#[test]
+fn pay_owed_reward() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let _ = Balances::deposit_creating(&recipient_account, 400);
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Rewards::pay_owed_reward(relationship_id),
+            Err(RewardsError::NoOwedReward)
+        );
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::owed_rewards(&relationship_id), payout);
+
+        assert_eq!(
+            Rewards::pay_owed_reward(relationship_id),
+            Err(RewardsError::InsufficientRewardSourceCapacity)
+        );
+
+        assert!(Minting::set_mint_capacity(mint_id, 1000000).is_ok());
+
+        let starting_balance = Balances::free_balance(&recipient_account);
+
+        assert_eq!(Rewards::pay_owed_reward(relationship_id), Ok(payout));
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + payout
+        );
+        assert!(!<OwedRewards<Test>>::contains_key(&relationship_id));
+
+        let relationship = Rewards::reward_relationships(&relationship_id);
+        assert_eq!(relationship.total_reward_received, payout);
+
+        let recipient = Rewards::recipients(&recipient_id);
+        assert_eq!(recipient.total_reward_received, payout);
+
+        assert_eq!(
+            Rewards::pay_owed_reward(relationship_id + 1),
+            Err(RewardsError::RewardRelationshipNotFound)
+        );
+    });
+}
+
+#[test]
+fn remove_reward_relationship_pays_owed_reward() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let _ = Balances::deposit_creating(&recipient_account, 400);
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::owed_rewards(&relationship_id), payout);
+
+        assert!(Minting::set_mint_capacity(mint_id, 1000000).is_ok());
+
+        let starting_balance = Balances::free_balance(&recipient_account);
+
+        Rewards::remove_reward_relationship(relationship_id);
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + payout
+        );
+        assert!(!<OwedRewards<Test>>::contains_key(&relationship_id));
+        assert!(!<RewardRelationships<Test>>::contains_key(&relationship_id));
+    });
+}
+
+#[test]
+fn removed_reward_relationship_keeps_unpaid_owed_reward_claimable() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(10000);
+        let recipient_account: u64 = 1;
+        let _ = Balances::deposit_creating(&recipient_account, 400);
+        let mint_id = create_new_mint_with_capacity(0);
+        let recipient_id = Rewards::add_recipient();
+        let payout: u64 = 1000;
+        let next_payout_at: u64 = 12222;
+        let relationship_id = Rewards::add_reward_relationship(
+            mint_id,
+            recipient_id,
+            recipient_account,
+            payout,
+            next_payout_at,
+            None,
+        )
+        .unwrap();
+
+        Rewards::do_payouts(next_payout_at);
+        assert_eq!(Rewards::owed_rewards(&relationship_id), payout);
+
+        // the mint cannot pay the owed reward on removal
+        Rewards::remove_reward_relationship(relationship_id);
+        assert_eq!(Rewards::owed_rewards(&relationship_id), payout);
+        assert!(<RemovedWithOwedReward<Test>>::contains_key(
+            &relationship_id
+        ));
+        assert!(!Rewards::reward_relationships(&relationship_id).is_active());
+
+        assert_eq!(
+            Rewards::claim_owed_reward(frame_system::RawOrigin::Signed(2).into(), relationship_id),
+            Err(Error::<Test>::OriginIsNotRewardAccount.into())
+        );
+        assert_eq!(
+            Rewards::claim_owed_reward(
+                frame_system::RawOrigin::Signed(recipient_account).into(),
+                relationship_id
+            ),
+            Err(Error::<Test>::InsufficientRewardSourceCapacity.into())
+        );
+
+        assert!(Minting::set_mint_capacity(mint_id, 1000000).is_ok());
+
+        let starting_balance = Balances::free_balance(&recipient_account);
+
+        assert_eq!(
+            Rewards::claim_owed_reward(
+                frame_system::RawOrigin::Signed(recipient_account).into(),
+                relationship_id
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            Balances::free_balance(&recipient_account),
+            starting_balance + payout
+        );
+
+        // the relationship is removed once the owed reward is paid
+        assert!(!<OwedRewards<Test>>::contains_key(&relationship_id));
+        assert!(!<RemovedWithOwedReward<Test>>::contains_key(
+            &relationship_id
+        ));
+        assert!(!<RewardRelationships<Test>>::contains_key(&relationship_id));
+        assert!(!<Recipients<Test>>::contains_key(&recipient_id));
+    });
+}
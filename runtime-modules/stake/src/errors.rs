@@ -104,6 +104,13 @@ pub enum ResumeUnstakingError {
     NotPaused,
 }
 
+#[derive(Debug, Eq, PartialEq)]
+pub enum ChangingStakeLockError {
+    NotStaked,
+    NotLocked,
+    InsufficientBalance,
+}
+
 impl<ErrorType> From<ErrorType> for StakeActionError<ErrorType> {
     fn from(e: ErrorType) -> StakeActionError<ErrorType> {
         StakeActionError::Error(e)
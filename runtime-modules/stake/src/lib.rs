@@ -2,10 +2,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Codec, Decode, Encode};
-use frame_support::storage::IterableStorageMap;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
-use frame_support::{decl_module, decl_storage, ensure, Parameter};
-use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, LockableCurrency,
+    WithdrawReasons,
+};
+use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
+use sp_arithmetic::traits::{BaseArithmetic, One, Saturating, Zero};
 use sp_runtime::traits::{AccountIdConversion, MaybeSerialize, Member};
 use sp_runtime::ModuleId;
 use sp_std::collections::btree_map::BTreeMap;
@@ -24,8 +27,11 @@ pub type NegativeImbalance<T> =
     <<T as Trait>::Currency as Currency<<T as frame_system::Trait>::AccountId>>::NegativeImbalance;
 
 pub trait Trait: frame_system::Trait + Sized {
+    /// The overarching event type.
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
     /// The currency that is managed by the module
-    type Currency: Currency<Self::AccountId>;
+    type Currency: LockableCurrency<Self::AccountId>;
 
     /// ModuleId for computing deterministic AccountId for the module
     type StakePoolId: Get<[u8; 8]>;
@@ -146,7 +152,8 @@ pub struct Slash<BlockNumber, Balance> {
     /// Blocks are only counted towards slashing execution delay when active.
     pub is_active: bool,
 
-    /// The number blocks which must be finalised while in the active period before the slashing can be executed
+    /// The number blocks which must be finalised while in the active period before the slashing can be executed.
+    /// Updated on pausing, the execution block of the active slashing is kept in the slashing schedule.
     pub blocks_remaining_in_active_period_for_slashing: BlockNumber,
 
     /// Amount to slash
@@ -162,7 +169,8 @@ pub struct UnstakingState<BlockNumber> {
     /// Blocks are only counted towards unstaking period when active.
     pub is_active: bool,
 
-    /// The number blocks which must be finalised while in the active period before the unstaking is finished
+    /// The number blocks which must be finalised while in the active period before the unstaking is finished.
+    /// Updated on pausing, the finalization block of the active unstaking is kept in the unstaking schedule.
     pub blocks_remaining_in_active_period_for_unstaking: BlockNumber,
 }
 
@@ -203,44 +211,6 @@ where
     Balance: BaseArithmetic + Copy,
     SlashId: Ord + Copy,
 {
-    /// Iterates over all ongoing slashes and decrements blocks_remaining_in_active_period_for_slashing of active slashes (advancing the timer).
-    /// Returns true if there was at least one slashe that was active and had its timer advanced.
-    fn advance_slashing_timer(&mut self) -> bool {
-        let mut did_advance_timers = false;
-
-        for (_slash_id, slash) in self.ongoing_slashes.iter_mut() {
-            if slash.is_active
-                && slash.blocks_remaining_in_active_period_for_slashing > Zero::zero()
-            {
-                slash.blocks_remaining_in_active_period_for_slashing -= One::one();
-                did_advance_timers = true;
-            }
-        }
-
-        did_advance_timers
-    }
-
-    /// Returns pair of slash_id and slashes that should be executed
-    fn get_slashes_to_finalize(&mut self) -> Vec<(SlashId, Slash<BlockNumber, Balance>)> {
-        let slashes_to_finalize = self
-            .ongoing_slashes
-            .iter()
-            .filter(|(_, slash)| {
-                slash.blocks_remaining_in_active_period_for_slashing == Zero::zero()
-            })
-            .map(|(slash_id, _)| *slash_id)
-            .collect::<Vec<_>>();
-
-        // remove and return the slashes
-        slashes_to_finalize
-            .iter()
-            .map(|slash_id| {
-                // assert!(self.ongoing_slashes.contains_key(slash_id))
-                (*slash_id, self.ongoing_slashes.remove(slash_id).unwrap())
-            })
-            .collect()
-    }
-
     /// Executes a Slash. If remaining at stake drops below the minimum_balance, it will slash the entire staked amount.
     /// Returns the actual slashed amount.
     fn apply_slash(&mut self, slash_amount: Balance, minimum_balance: Balance) -> Balance {
@@ -262,21 +232,6 @@ where
 
         slash_amount
     }
-
-    /// For all slahes that should be executed, will apply the Slash to the staked amount, and drop it from the ongoing slashes map.
-    /// Returns a vector of the executed slashes outcome: (SlashId, Slashed Amount, Remaining Staked Amount)
-    fn finalize_slashes(&mut self, minimum_balance: Balance) -> Vec<(SlashId, Balance, Balance)> {
-        let mut finalized_slashes: Vec<(SlashId, Balance, Balance)> = Vec::new();
-
-        for (slash_id, slash) in self.get_slashes_to_finalize().iter() {
-            // apply the slashing and get back actual amount slashed
-            let slashed_amount = self.apply_slash(slash.slash_amount, minimum_balance);
-
-            finalized_slashes.push((*slash_id, slashed_amount, self.staked_amount));
-        }
-
-        finalized_slashes
-    }
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq)]
@@ -622,79 +577,57 @@ where
         }
     }
 
+    /// Executes the active ongoing slash with given id, and resumes unstaking if it was the last ongoing slash.
+    /// Returns the actually slashed amount and the remaining staked amount.
     fn finalize_slashing(
         &mut self,
+        slash_id: &SlashId,
         minimum_balance: Balance,
-    ) -> (bool, Vec<(SlashId, Balance, Balance)>) {
+    ) -> Option<(Balance, Balance)> {
         match self.staking_status {
             StakingStatus::Staked(ref mut staked_state) => {
-                // tick the slashing timer
-                let did_update = staked_state.advance_slashing_timer();
+                let slash = match staked_state.ongoing_slashes.get(slash_id) {
+                    Some(slash) if slash.is_active => *slash,
+                    _ => return None,
+                };
 
-                // finalize and apply slashes
-                let slashed = staked_state.finalize_slashes(minimum_balance);
+                staked_state.ongoing_slashes.remove(slash_id);
 
-                (did_update, slashed)
-            }
-            _ => (false, Vec::new()),
-        }
-    }
+                let slashed_amount = staked_state.apply_slash(slash.slash_amount, minimum_balance);
 
-    fn finalize_unstaking(&mut self) -> (bool, Option<Balance>) {
-        let (did_update, unstaked) = match self.staking_status {
-            StakingStatus::Staked(ref mut staked_state) => match staked_state.staked_status {
-                StakedStatus::Unstaking(ref mut unstaking_state) => {
-                    // if all slashes were processed and there are no more active slashes
-                    // resume unstaking
-                    if staked_state.ongoing_slashes.is_empty() {
+                // if all slashes were processed resume unstaking
+                if staked_state.ongoing_slashes.is_empty() {
+                    if let StakedStatus::Unstaking(ref mut unstaking_state) =
+                        staked_state.staked_status
+                    {
                         unstaking_state.is_active = true;
                     }
+                }
 
-                    // tick the unstaking timer
-                    if unstaking_state.is_active
-                        && unstaking_state.blocks_remaining_in_active_period_for_unstaking
-                            > Zero::zero()
-                    {
-                        // tick the unstaking timer
-                        unstaking_state.blocks_remaining_in_active_period_for_unstaking -=
-                            One::one();
-                    }
+                Some((slashed_amount, staked_state.staked_amount))
+            }
+            _ => None,
+        }
+    }
 
-                    // finalize unstaking
-                    if unstaking_state.blocks_remaining_in_active_period_for_unstaking
-                        == Zero::zero()
-                    {
-                        (true, Some(staked_state.staked_amount))
-                    } else {
-                        (unstaking_state.is_active, None)
-                    }
+    /// Finalizes the active unstaking, transitioning the stake to NotStaked state.
+    /// Returns the unstaked amount.
+    fn finalize_unstaking(&mut self) -> Option<Balance> {
+        let unstaked = match self.staking_status {
+            StakingStatus::Staked(ref staked_state) => match staked_state.staked_status {
+                StakedStatus::Unstaking(ref unstaking_state) if unstaking_state.is_active => {
+                    Some(staked_state.staked_amount)
                 }
-                _ => (false, None),
+                _ => None,
             },
-            _ => (false, None),
+            _ => None,
         };
 
-        // if unstaking was finalized transition to NotStaked state
         if unstaked.is_some() {
             self.staking_status = StakingStatus::NotStaked;
         }
 
-        (did_update, unstaked)
-    }
-
-    fn finalize_slashing_and_unstaking(
-        &mut self,
-        minimum_balance: Balance,
-    ) -> (bool, Vec<(SlashId, Balance, Balance)>, Option<Balance>) {
-        let (did_update_slashing_timers, slashed) = self.finalize_slashing(minimum_balance);
-
-        let (did_update_unstaking_timer, unstaked) = self.finalize_unstaking();
-
-        (
-            did_update_slashing_timers || did_update_unstaking_timer,
-            slashed,
-            unstaked,
-        )
+        unstaked
     }
 }
 
@@ -715,13 +648,133 @@ decl_storage! {
         /// Identifier value for next stake, and count of total stakes created (not necessarily the number of current
         /// stakes in the Stakes map as stakes can be removed.)
         pub StakesCreated get(fn stakes_created): T::StakeId;
+
+        /// Active ongoing slashes indexed by the block number of their execution.
+        pub SlashingSchedule get(fn slashing_schedule): map hasher(blake2_128_concat)
+            T::BlockNumber => Vec<(T::StakeId, T::SlashId)>;
+
+        /// Active unstakings indexed by the block number of their finalization.
+        pub UnstakingSchedule get(fn unstaking_schedule): map hasher(blake2_128_concat)
+            T::BlockNumber => Vec<T::StakeId>;
+
+        /// Execution block number of the active ongoing slash.
+        pub SlashExecutionBlock get(fn slash_execution_block): double_map
+            hasher(blake2_128_concat) T::StakeId,
+            hasher(blake2_128_concat) T::SlashId => Option<T::BlockNumber>;
+
+        /// Finalization block number of the active unstaking.
+        pub UnstakingFinalizationBlock get(fn unstaking_finalization_block):
+            map hasher(blake2_128_concat) T::StakeId => Option<T::BlockNumber>;
+
+        /// Lock id and staker account of the stakes backed by a balance lock instead of the pool.
+        pub StakeLocks get(fn stake_lock): map hasher(blake2_128_concat)
+            T::StakeId => Option<(LockIdentifier, T::AccountId)>;
+
+        /// Total balance locked on the staker account by the stakes of the given lock id.
+        pub LockedBalances get(fn locked_balance): double_map
+            hasher(blake2_128_concat) LockIdentifier,
+            hasher(blake2_128_concat) T::AccountId => BalanceOf<T>;
     }
 }
 
+decl_event!(
+    /// _Stake_ events
+    pub enum Event<T>
+    where
+        <T as Trait>::StakeId,
+        <T as Trait>::SlashId,
+        Balance = BalanceOf<T>,
+    {
+        /// Emits on creating a new stake.
+        /// Params:
+        /// - Id of the stake.
+        StakeCreated(StakeId),
+
+        /// Emits on removing the stake.
+        /// Params:
+        /// - Id of the stake.
+        StakeRemoved(StakeId),
+
+        /// Emits on staking.
+        /// Params:
+        /// - Id of the stake.
+        /// - Staked amount.
+        Staked(StakeId, Balance),
+
+        /// Emits on increasing the stake.
+        /// Params:
+        /// - Id of the stake.
+        /// - Total staked amount.
+        StakeIncreased(StakeId, Balance),
+
+        /// Emits on decreasing the stake.
+        /// Params:
+        /// - Id of the stake.
+        /// - Remaining staked amount.
+        StakeDecreased(StakeId, Balance),
+
+        /// Emits on initiating the slashing.
+        /// Params:
+        /// - Id of the stake.
+        /// - Id of the slash.
+        SlashingInitiated(StakeId, SlashId),
+
+        /// Emits on pausing the slashing.
+        /// Params:
+        /// - Id of the stake.
+        /// - Id of the slash.
+        SlashingPaused(StakeId, SlashId),
+
+        /// Emits on resuming the slashing.
+        /// Params:
+        /// - Id of the stake.
+        /// - Id of the slash.
+        SlashingResumed(StakeId, SlashId),
+
+        /// Emits on cancelling the slashing.
+        /// Params:
+        /// - Id of the stake.
+        /// - Id of the slash.
+        SlashingCancelled(StakeId, SlashId),
+
+        /// Emits on slashing the stake.
+        /// Params:
+        /// - Id of the stake.
+        /// - Id of the slash (none for the immediate slashing).
+        /// - Slashed amount.
+        /// - Remaining staked amount.
+        Slashed(StakeId, Option<SlashId>, Balance, Balance),
+
+        /// Emits on initiating the unstaking.
+        /// Params:
+        /// - Id of the stake.
+        UnstakingInitiated(StakeId),
+
+        /// Emits on pausing the unstaking.
+        /// Params:
+        /// - Id of the stake.
+        UnstakingPaused(StakeId),
+
+        /// Emits on resuming the unstaking.
+        /// Params:
+        /// - Id of the stake.
+        UnstakingResumed(StakeId),
+
+        /// Emits on unstaking.
+        /// Params:
+        /// - Id of the stake.
+        /// - Unstaked amount.
+        Unstaked(StakeId, Balance),
+    }
+);
+
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
-        fn on_finalize(_now: T::BlockNumber) {
-            Self::finalize_slashing_and_unstaking();
+        /// Default deposit_event() handler
+        fn deposit_event() = default;
+
+        fn on_finalize(now: T::BlockNumber) {
+            Self::finalize_slashing_and_unstaking(now);
         }
     }
 }
@@ -749,6 +802,8 @@ impl<T: Trait> Module<T> {
             Stake::new(<frame_system::Module<T>>::block_number()),
         );
 
+        Self::deposit_event(RawEvent::StakeCreated(stake_id));
+
         stake_id
     }
 
@@ -763,6 +818,8 @@ impl<T: Trait> Module<T> {
 
         <Stakes<T>>::remove(stake_id);
 
+        Self::deposit_event(RawEvent::StakeRemoved(*stake_id));
+
         Ok(())
     }
 
@@ -798,6 +855,8 @@ impl<T: Trait> Module<T> {
 
         Self::deposit_funds_into_stake_pool(imbalance);
 
+        Self::deposit_event(RawEvent::Staked(*stake_id, value));
+
         Ok(())
     }
 
@@ -815,6 +874,82 @@ impl<T: Trait> Module<T> {
 
         <Stakes<T>>::insert(stake_id, stake);
 
+        Self::deposit_event(RawEvent::Staked(*stake_id, value));
+
+        Ok(())
+    }
+
+    /// Dry run to see if the value can be staked by locking it on the staker account. This should
+    /// be called before create_stake() and stake_with_lock() to make sure no stake is left unstaked.
+    pub fn ensure_can_stake_with_lock(
+        staker_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+        lock_id: LockIdentifier,
+    ) -> Result<(), StakeActionError<StakingFromAccountError>> {
+        Stake::<T::BlockNumber, BalanceOf<T>, T::SlashId>::new(
+            <frame_system::Module<T>>::block_number(),
+        )
+        .start_staking(value, T::Currency::minimum_balance())?;
+
+        Self::ensure_can_lock_funds(&lock_id, staker_account_id, value)?;
+
+        Ok(())
+    }
+
+    /// Provided the stake exists and is in state NotStaked, the value is locked on the staker account
+    /// under the given lock id instead of being transferred to the module's account. The stakes sharing
+    /// the lock id and the staker account are covered by a single lock of their total value.
+    /// Balance locks of different lock ids overlap, so the same funds can back stakes of different purposes.
+    pub fn stake_with_lock(
+        stake_id: &T::StakeId,
+        staker_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+        lock_id: LockIdentifier,
+    ) -> Result<(), StakeActionError<StakingFromAccountError>> {
+        let mut stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
+
+        stake.start_staking(value, T::Currency::minimum_balance())?;
+
+        Self::lock_funds(&lock_id, staker_account_id, value)?;
+
+        <StakeLocks<T>>::insert(stake_id, (lock_id, staker_account_id.clone()));
+
+        <Stakes<T>>::insert(stake_id, stake);
+
+        Self::deposit_event(RawEvent::Staked(*stake_id, value));
+
+        Ok(())
+    }
+
+    /// Moves the staked value of the stake backed by a balance lock to the balance lock of the given
+    /// id on the same staker account, eg.: when the purpose of the stake changes. Fails if the free
+    /// balance of the staker account doesn't cover the new locked total.
+    pub fn change_stake_lock(
+        stake_id: &T::StakeId,
+        new_lock_id: LockIdentifier,
+    ) -> Result<(), StakeActionError<ChangingStakeLockError>> {
+        let stake = ensure_stake_exists!(T, stake_id, StakeActionError::StakeNotFound)?;
+
+        let staked_amount = match stake.staking_status {
+            StakingStatus::Staked(ref staked_state) => staked_state.staked_amount,
+            StakingStatus::NotStaked => return Err(ChangingStakeLockError::NotStaked.into()),
+        };
+
+        let (lock_id, staker_account_id) =
+            Self::stake_lock(stake_id).ok_or(ChangingStakeLockError::NotLocked)?;
+
+        Self::ensure_can_lock_funds(&new_lock_id, &staker_account_id, staked_amount)
+            .map_err(|_| ChangingStakeLockError::InsufficientBalance)?;
+
+        Self::unlock_funds(&lock_id, &staker_account_id, staked_amount);
+        Self::set_locked_balance(
+            &new_lock_id,
+            &staker_account_id,
+            Self::locked_balance(&new_lock_id, &staker_account_id) + staked_amount,
+        );
+
+        <StakeLocks<T>>::insert(stake_id, (new_lock_id, staker_account_id));
+
         Ok(())
     }
 
@@ -862,6 +997,139 @@ impl<T: Trait> Module<T> {
         .expect("pool had less than expected funds!")
     }
 
+    /// Adds the value to the balance lock of the staker account. Fails if the free balance of the
+    /// account doesn't cover the new locked total.
+    fn lock_funds(
+        lock_id: &LockIdentifier,
+        staker_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<(), TransferFromAccountError> {
+        Self::ensure_can_lock_funds(lock_id, staker_account_id, value)?;
+
+        let locked_balance = Self::locked_balance(lock_id, staker_account_id) + value;
+
+        Self::set_locked_balance(lock_id, staker_account_id, locked_balance);
+
+        Ok(())
+    }
+
+    /// Ensures the free balance of the staker account covers the locked total with the value added.
+    fn ensure_can_lock_funds(
+        lock_id: &LockIdentifier,
+        staker_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<(), TransferFromAccountError> {
+        let locked_balance = Self::locked_balance(lock_id, staker_account_id) + value;
+
+        ensure!(
+            T::Currency::free_balance(staker_account_id) >= locked_balance,
+            TransferFromAccountError::InsufficientBalance
+        );
+
+        Ok(())
+    }
+
+    /// Subtracts the value from the balance lock of the staker account. The lock is removed when
+    /// no locked balance remains.
+    fn unlock_funds(
+        lock_id: &LockIdentifier,
+        staker_account_id: &T::AccountId,
+        value: BalanceOf<T>,
+    ) {
+        let locked_balance = Self::locked_balance(lock_id, staker_account_id).saturating_sub(value);
+
+        Self::set_locked_balance(lock_id, staker_account_id, locked_balance);
+    }
+
+    /// Sets the balance lock of the staker account to the locked total.
+    fn set_locked_balance(
+        lock_id: &LockIdentifier,
+        staker_account_id: &T::AccountId,
+        locked_balance: BalanceOf<T>,
+    ) {
+        if locked_balance.is_zero() {
+            T::Currency::remove_lock(*lock_id, staker_account_id);
+            <LockedBalances<T>>::remove(lock_id, staker_account_id);
+        } else {
+            T::Currency::set_lock(
+                *lock_id,
+                staker_account_id,
+                locked_balance,
+                WithdrawReasons::all(),
+            );
+            <LockedBalances<T>>::insert(lock_id, staker_account_id, locked_balance);
+        }
+    }
+
+    /// Adds the funds of the imbalance to the stake: into the pool, or onto the staker account under
+    /// the balance lock for the stakes backed by a lock.
+    fn deposit_stake_funds(stake_id: &T::StakeId, imbalance: NegativeImbalance<T>) {
+        if let Some((lock_id, staker_account_id)) = Self::stake_lock(stake_id) {
+            let value = imbalance.peek();
+            T::Currency::resolve_creating(&staker_account_id, imbalance);
+            Self::set_locked_balance(
+                &lock_id,
+                &staker_account_id,
+                Self::locked_balance(&lock_id, &staker_account_id) + value,
+            );
+        } else {
+            Self::deposit_funds_into_stake_pool(imbalance);
+        }
+    }
+
+    /// Adds the value from the source account to the stake. For the stakes backed by a lock,
+    /// the value is moved to the staker account (if it differs from the source) and locked.
+    fn transfer_funds_from_account_into_stake(
+        stake_id: &T::StakeId,
+        source: &T::AccountId,
+        value: BalanceOf<T>,
+    ) -> Result<(), TransferFromAccountError> {
+        match Self::stake_lock(stake_id) {
+            Some((lock_id, ref staker_account_id)) if staker_account_id == source => {
+                Self::lock_funds(&lock_id, staker_account_id, value)
+            }
+            Some(_) => {
+                let negative_imbalance = T::Currency::withdraw(
+                    source,
+                    value,
+                    WithdrawReasons::all(),
+                    ExistenceRequirement::AllowDeath,
+                )
+                .map_err(|_err| TransferFromAccountError::InsufficientBalance)?;
+
+                Self::deposit_stake_funds(stake_id, negative_imbalance);
+                Ok(())
+            }
+            None => Self::transfer_funds_from_account_into_stake_pool(source, value),
+        }
+    }
+
+    /// Takes the value out of the stake and returns a NegativeImbalance. For the stakes backed by a lock,
+    /// the value is unlocked and taken from the staker account.
+    fn withdraw_stake_funds(stake_id: &T::StakeId, value: BalanceOf<T>) -> NegativeImbalance<T> {
+        if let Some((lock_id, staker_account_id)) = Self::stake_lock(stake_id) {
+            Self::unlock_funds(&lock_id, &staker_account_id, value);
+
+            // Slashing ignores the other balance locks of the account.
+            let (imbalance, _) = T::Currency::slash(&staker_account_id, value);
+            imbalance
+        } else {
+            Self::withdraw_funds_from_stake_pool(value)
+        }
+    }
+
+    /// Releases the unstaked value of the stake. Funds of the stakes backed by a lock are unlocked and
+    /// stay on the staker account, so the returned NegativeImbalance is empty in that case.
+    fn release_stake_funds(stake_id: &T::StakeId, value: BalanceOf<T>) -> NegativeImbalance<T> {
+        if let Some((lock_id, staker_account_id)) = <StakeLocks<T>>::take(stake_id) {
+            Self::unlock_funds(&lock_id, &staker_account_id, value);
+
+            NegativeImbalance::<T>::zero()
+        } else {
+            Self::withdraw_funds_from_stake_pool(value)
+        }
+    }
+
     /// Dry run to see if the state of stake allows for increasing stake. This should be called
     /// to make sure increasing stake is possible before withdrawing funds.
     pub fn ensure_can_increase_stake(
@@ -889,7 +1157,9 @@ impl<T: Trait> Module<T> {
         let total_staked_amount = stake.increase_stake(imbalance.peek())?;
         <Stakes<T>>::insert(stake_id, stake);
 
-        Self::deposit_funds_into_stake_pool(imbalance);
+        Self::deposit_stake_funds(stake_id, imbalance);
+
+        Self::deposit_event(RawEvent::StakeIncreased(*stake_id, total_staked_amount));
 
         Ok(total_staked_amount)
     }
@@ -910,10 +1180,12 @@ impl<T: Trait> Module<T> {
 
         let total_staked_amount = stake.increase_stake(value)?;
 
-        Self::transfer_funds_from_account_into_stake_pool(&source_account_id, value)?;
+        Self::transfer_funds_from_account_into_stake(stake_id, &source_account_id, value)?;
 
         <Stakes<T>>::insert(stake_id, stake);
 
+        Self::deposit_event(RawEvent::StakeIncreased(*stake_id, total_staked_amount));
+
         Ok(total_staked_amount)
     }
 
@@ -940,7 +1212,9 @@ impl<T: Trait> Module<T> {
 
         <Stakes<T>>::insert(stake_id, stake);
 
-        let imbalance = Self::withdraw_funds_from_stake_pool(deduct_from_pool);
+        let imbalance = Self::withdraw_stake_funds(stake_id, deduct_from_pool);
+
+        Self::deposit_event(RawEvent::StakeDecreased(*stake_id, staked_amount));
 
         Ok((staked_amount, imbalance))
     }
@@ -960,7 +1234,23 @@ impl<T: Trait> Module<T> {
 
         <Stakes<T>>::insert(stake_id, stake);
 
-        Self::transfer_funds_from_pool_into_account(&destination_account_id, deduct_from_pool);
+        match Self::stake_lock(stake_id) {
+            Some((lock_id, ref staker_account_id))
+                if staker_account_id == destination_account_id =>
+            {
+                Self::unlock_funds(&lock_id, staker_account_id, deduct_from_pool);
+            }
+            Some(_) => {
+                let imbalance = Self::withdraw_stake_funds(stake_id, deduct_from_pool);
+                T::Currency::resolve_creating(destination_account_id, imbalance);
+            }
+            None => Self::transfer_funds_from_pool_into_account(
+                &destination_account_id,
+                deduct_from_pool,
+            ),
+        }
+
+        Self::deposit_event(RawEvent::StakeDecreased(*stake_id, staked_amount));
 
         Ok(staked_amount)
     }
@@ -993,12 +1283,19 @@ impl<T: Trait> Module<T> {
             stake.staking_status = StakingStatus::NotStaked;
         }
 
+        // Remove the slashed amount from the pool or the staker account
+        let slashed_imbalance = Self::withdraw_stake_funds(stake_id, actually_slashed);
+
+        if caused_unstake {
+            <StakeLocks<T>>::remove(stake_id);
+        }
+
+        // Drop the ongoing slashes and unstaking from the schedule on unstaking
+        Self::update_schedule(stake_id, &mut stake);
+
         // Update state before calling handlers!
         <Stakes<T>>::insert(stake_id, stake);
 
-        // Remove the slashed amount from the pool
-        let slashed_imbalance = Self::withdraw_funds_from_stake_pool(actually_slashed);
-
         // Notify slashing event handler before unstaked handler.
         let remaining_imbalance_after_slash_handler = T::StakingEventsHandler::slashed(
             stake_id,
@@ -1008,7 +1305,16 @@ impl<T: Trait> Module<T> {
             slashed_imbalance,
         );
 
+        Self::deposit_event(RawEvent::Slashed(
+            *stake_id,
+            None,
+            actually_slashed,
+            remaining_stake,
+        ));
+
         let remaining_imbalance = if caused_unstake {
+            Self::deposit_event(RawEvent::Unstaked(*stake_id, staked_amount_before_slash));
+
             // Notify unstaked handler with any remaining unused imbalance
             // from the slashing event handler
             T::StakingEventsHandler::unstaked(
@@ -1042,7 +1348,12 @@ impl<T: Trait> Module<T> {
             <frame_system::Module<T>>::block_number(),
         )?;
 
+        Self::update_schedule(stake_id, &mut stake);
+
         <Stakes<T>>::insert(stake_id, stake);
+
+        Self::deposit_event(RawEvent::SlashingInitiated(*stake_id, slash_id));
+
         Ok(slash_id)
     }
 
@@ -1055,8 +1366,12 @@ impl<T: Trait> Module<T> {
 
         stake.pause_slashing(slash_id)?;
 
+        Self::update_schedule(stake_id, &mut stake);
+
         <Stakes<T>>::insert(stake_id, stake);
 
+        Self::deposit_event(RawEvent::SlashingPaused(*stake_id, *slash_id));
+
         Ok(())
     }
 
@@ -1069,7 +1384,12 @@ impl<T: Trait> Module<T> {
 
         stake.resume_slashing(slash_id)?;
 
+        Self::update_schedule(stake_id, &mut stake);
+
         <Stakes<T>>::insert(stake_id, stake);
+
+        Self::deposit_event(RawEvent::SlashingResumed(*stake_id, *slash_id));
+
         Ok(())
     }
 
@@ -1082,8 +1402,12 @@ impl<T: Trait> Module<T> {
 
         stake.cancel_slashing(slash_id)?;
 
+        Self::update_schedule(stake_id, &mut stake);
+
         <Stakes<T>>::insert(stake_id, stake);
 
+        Self::deposit_event(RawEvent::SlashingCancelled(*stake_id, *slash_id));
+
         Ok(())
     }
 
@@ -1097,13 +1421,18 @@ impl<T: Trait> Module<T> {
         if let Some(unstaking_period) = unstaking_period {
             stake
                 .initiate_unstaking(unstaking_period, <frame_system::Module<T>>::block_number())?;
+            Self::update_schedule(stake_id, &mut stake);
             <Stakes<T>>::insert(stake_id, stake);
+
+            Self::deposit_event(RawEvent::UnstakingInitiated(*stake_id));
         } else {
             let staked_amount = stake.unstake()?;
             <Stakes<T>>::insert(stake_id, stake);
 
-            let imbalance = Self::withdraw_funds_from_stake_pool(staked_amount);
+            let imbalance = Self::release_stake_funds(stake_id, staked_amount);
             let _ = T::StakingEventsHandler::unstaked(stake_id, staked_amount, imbalance);
+
+            Self::deposit_event(RawEvent::Unstaked(*stake_id, staked_amount));
         }
 
         Ok(())
@@ -1117,8 +1446,12 @@ impl<T: Trait> Module<T> {
 
         stake.pause_unstaking()?;
 
+        Self::update_schedule(stake_id, &mut stake);
+
         <Stakes<T>>::insert(stake_id, stake);
 
+        Self::deposit_event(RawEvent::UnstakingPaused(*stake_id));
+
         Ok(())
     }
 
@@ -1130,29 +1463,49 @@ impl<T: Trait> Module<T> {
 
         stake.resume_unstaking()?;
 
+        Self::update_schedule(stake_id, &mut stake);
+
         <Stakes<T>>::insert(stake_id, stake);
 
+        Self::deposit_event(RawEvent::UnstakingResumed(*stake_id));
+
         Ok(())
     }
 
-    /// Handle timers for finalizing unstaking and slashing.
+    /// Builds the slashing and unstaking schedules for the existing stakes.
+    /// Should be called on the migration to the new runtime version.
+    pub fn migrate_slashing_and_unstaking_schedules() {
+        for (stake_id, mut stake) in <Stakes<T>>::iter() {
+            Self::update_schedule(&stake_id, &mut stake);
+        }
+    }
+
+    /// Finalizes the slashes and unstakings scheduled for the given block.
     /// Finalised unstaking results in the staked_balance in the given stake to removed from the pool, the corresponding
     /// imbalance is provided to the unstaked() hook in the StakingEventsHandler.
     /// Finalised slashing results in the staked_balance in the given stake being correspondingly reduced, and the imbalance
     /// is provided to the slashed() hook in the StakingEventsHandler.
-    fn finalize_slashing_and_unstaking() {
-        for (stake_id, ref mut stake) in <Stakes<T>>::iter() {
-            let (updated, slashed, unstaked) =
-                stake.finalize_slashing_and_unstaking(T::Currency::minimum_balance());
-
-            // update the state before making external calls to StakingEventsHandler
-            if updated {
-                <Stakes<T>>::insert(stake_id, stake)
+    fn finalize_slashing_and_unstaking(now: T::BlockNumber) {
+        for (stake_id, slash_id) in <SlashingSchedule<T>>::take(now) {
+            <SlashExecutionBlock<T>>::remove(&stake_id, &slash_id);
+
+            if !<Stakes<T>>::contains_key(&stake_id) {
+                continue;
             }
 
-            for (slash_id, slashed_amount, staked_amount) in slashed.into_iter() {
-                // remove the slashed amount from the pool
-                let imbalance = Self::withdraw_funds_from_stake_pool(slashed_amount);
+            let mut stake = Self::stakes(&stake_id);
+
+            if let Some((slashed_amount, staked_amount)) =
+                stake.finalize_slashing(&slash_id, T::Currency::minimum_balance())
+            {
+                // schedule the possibly resumed unstaking
+                Self::update_schedule(&stake_id, &mut stake);
+
+                // update the state before making external calls to StakingEventsHandler
+                <Stakes<T>>::insert(&stake_id, stake);
+
+                // remove the slashed amount from the pool or the staker account
+                let imbalance = Self::withdraw_stake_funds(&stake_id, slashed_amount);
 
                 let _ = T::StakingEventsHandler::slashed(
                     &stake_id,
@@ -1161,14 +1514,157 @@ impl<T: Trait> Module<T> {
                     staked_amount,
                     imbalance,
                 );
+
+                Self::deposit_event(RawEvent::Slashed(
+                    stake_id,
+                    Some(slash_id),
+                    slashed_amount,
+                    staked_amount,
+                ));
             }
+        }
 
-            if let Some(staked_amount) = unstaked {
-                // remove the unstaked amount from the pool
-                let imbalance = Self::withdraw_funds_from_stake_pool(staked_amount);
+        for stake_id in <UnstakingSchedule<T>>::take(now) {
+            <UnstakingFinalizationBlock<T>>::remove(&stake_id);
+
+            if !<Stakes<T>>::contains_key(&stake_id) {
+                continue;
+            }
+
+            let mut stake = Self::stakes(&stake_id);
+
+            if let Some(staked_amount) = stake.finalize_unstaking() {
+                // drop the ongoing slashes from the schedule
+                Self::update_schedule(&stake_id, &mut stake);
+
+                // update the state before making external calls to StakingEventsHandler
+                <Stakes<T>>::insert(&stake_id, stake);
+
+                // release the unstaked amount from the pool or the balance lock
+                let imbalance = Self::release_stake_funds(&stake_id, staked_amount);
 
                 let _ = T::StakingEventsHandler::unstaked(&stake_id, staked_amount, imbalance);
+
+                Self::deposit_event(RawEvent::Unstaked(stake_id, staked_amount));
+            }
+        }
+    }
+
+    /// Schedules the active slashes and unstaking of the stake, and drops the paused, finished or
+    /// cancelled ones from the schedule. Remaining blocks of the paused timers are updated.
+    /// The block of activation is counted as the first block of the active period.
+    fn update_schedule(
+        stake_id: &T::StakeId,
+        stake: &mut Stake<T::BlockNumber, BalanceOf<T>, T::SlashId>,
+    ) {
+        let now = <frame_system::Module<T>>::block_number();
+
+        let (mut ongoing_slashes, mut unstaking_state) = match stake.staking_status {
+            StakingStatus::Staked(ref mut staked_state) => {
+                let unstaking_state = match staked_state.staked_status {
+                    StakedStatus::Unstaking(ref mut unstaking_state) => Some(unstaking_state),
+                    StakedStatus::Normal => None,
+                };
+
+                (Some(&mut staked_state.ongoing_slashes), unstaking_state)
+            }
+            StakingStatus::NotStaked => (None, None),
+        };
+
+        // drop the slashes which are no longer active
+        let scheduled_slashes = <SlashExecutionBlock<T>>::iter_prefix(stake_id).collect::<Vec<_>>();
+        for (slash_id, execution_block) in scheduled_slashes {
+            let slash = ongoing_slashes
+                .as_mut()
+                .and_then(|slashes| slashes.get_mut(&slash_id));
+
+            match slash {
+                Some(slash) if slash.is_active => continue,
+                Some(slash) => {
+                    slash.blocks_remaining_in_active_period_for_slashing =
+                        (execution_block + One::one()).saturating_sub(now);
+                }
+                None => {}
             }
+
+            Self::unschedule_slashing(stake_id, &slash_id, execution_block);
         }
+
+        // schedule the newly activated slashes
+        if let Some(slashes) = ongoing_slashes {
+            for (slash_id, slash) in slashes.iter() {
+                if slash.is_active && !<SlashExecutionBlock<T>>::contains_key(stake_id, slash_id) {
+                    let execution_block = now
+                        + slash
+                            .blocks_remaining_in_active_period_for_slashing
+                            .saturating_sub(One::one());
+
+                    <SlashingSchedule<T>>::mutate(execution_block, |schedule| {
+                        schedule.push((*stake_id, *slash_id))
+                    });
+                    <SlashExecutionBlock<T>>::insert(stake_id, slash_id, execution_block);
+                }
+            }
+        }
+
+        // update the unstaking schedule
+        let is_active_unstaking = unstaking_state
+            .as_ref()
+            .map_or(false, |unstaking_state| unstaking_state.is_active);
+
+        match Self::unstaking_finalization_block(stake_id) {
+            Some(finalization_block) if !is_active_unstaking => {
+                if let Some(ref mut unstaking_state) = unstaking_state {
+                    unstaking_state.blocks_remaining_in_active_period_for_unstaking =
+                        (finalization_block + One::one()).saturating_sub(now);
+                }
+
+                Self::unschedule_unstaking(stake_id, finalization_block);
+            }
+            None if is_active_unstaking => {
+                if let Some(unstaking_state) = unstaking_state {
+                    let finalization_block = now
+                        + unstaking_state
+                            .blocks_remaining_in_active_period_for_unstaking
+                            .saturating_sub(One::one());
+
+                    <UnstakingSchedule<T>>::mutate(finalization_block, |schedule| {
+                        schedule.push(*stake_id)
+                    });
+                    <UnstakingFinalizationBlock<T>>::insert(stake_id, finalization_block);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Removes the slash from the slashing schedule.
+    fn unschedule_slashing(
+        stake_id: &T::StakeId,
+        slash_id: &T::SlashId,
+        execution_block: T::BlockNumber,
+    ) {
+        <SlashingSchedule<T>>::mutate(execution_block, |schedule| {
+            schedule.retain(|item| *item != (*stake_id, *slash_id))
+        });
+
+        if <SlashingSchedule<T>>::get(execution_block).is_empty() {
+            <SlashingSchedule<T>>::remove(execution_block);
+        }
+
+        <SlashExecutionBlock<T>>::remove(stake_id, slash_id);
+    }
+
+    // Removes the stake from the unstaking schedule.
+    fn unschedule_unstaking(stake_id: &T::StakeId, finalization_block: T::BlockNumber) {
+        <UnstakingSchedule<T>>::mutate(finalization_block, |schedule| {
+            schedule.retain(|item| item != stake_id)
+        });
+
+        if <UnstakingSchedule<T>>::get(finalization_block).is_empty() {
+            <UnstakingSchedule<T>>::remove(finalization_block);
+        }
+
+        <UnstakingFinalizationBlock<T>>::remove(stake_id);
     }
 }
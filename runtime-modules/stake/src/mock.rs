@@ -69,6 +69,7 @@ impl balances::Trait for Test {
 }
 
 impl Trait for Test {
+    type Event = ();
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
@@ -529,6 +529,13 @@ fn initiating_pausing_resuming_cancelling_slashes() {
         slash_id += 1;
         assert!(StakePool::initiate_slashing(&stake_id, slashing_amount, 2).is_ok());
 
+        // slashing is executed at the second block of the slashing period
+        assert_eq!(
+            StakePool::slash_execution_block(&stake_id, &slash_id),
+            Some(1)
+        );
+        assert_eq!(StakePool::unstaking_finalization_block(&stake_id), None);
+
         StakePool::on_finalize(System::block_number());
 
         expected_ongoing_slashes.insert(
@@ -536,7 +543,7 @@ fn initiating_pausing_resuming_cancelling_slashes() {
             Slash {
                 started_at_block: System::block_number(),
                 is_active: true,
-                blocks_remaining_in_active_period_for_slashing: 1,
+                blocks_remaining_in_active_period_for_slashing: 2,
                 slash_amount: slashing_amount,
             },
         );
@@ -557,24 +564,33 @@ fn initiating_pausing_resuming_cancelling_slashes() {
             }
         );
 
+        System::set_block_number(1);
         StakePool::on_finalize(System::block_number());
         assert_eq!(
             StakePool::stakes(&stake_id),
             Stake {
-                created: System::block_number(),
+                created: 0,
                 staking_status: StakingStatus::Staked(StakedState {
                     staked_amount: staked_amount - slashing_amount,
                     ongoing_slashes: BTreeMap::new(),
                     next_slash_id: slash_id + 1,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: 0,
-                        blocks_remaining_in_active_period_for_unstaking: 99,
+                        blocks_remaining_in_active_period_for_unstaking: 100,
                         is_active: true
                     })
                 })
             }
         );
 
+        // resumed unstaking is scheduled
+        assert_eq!(StakePool::slash_execution_block(&stake_id, &slash_id), None);
+        assert_eq!(
+            StakePool::unstaking_finalization_block(&stake_id),
+            Some(100)
+        );
+        assert_eq!(StakePool::unstaking_schedule(100), vec![stake_id]);
+
         assert_eq!(
             StakePool::stake_pool_balance(),
             staked_amount - slashing_amount
@@ -671,6 +687,8 @@ fn initiating_pausing_resuming_unstaking() {
             }
         );
 
+        assert_eq!(StakePool::unstaking_finalization_block(&stake_id), Some(1));
+
         StakePool::on_finalize(System::block_number());
         assert_eq!(
             StakePool::stakes(&stake_id),
@@ -682,21 +700,24 @@ fn initiating_pausing_resuming_unstaking() {
                     next_slash_id: 2,
                     staked_status: StakedStatus::Unstaking(UnstakingState {
                         started_at_block: System::block_number(),
-                        blocks_remaining_in_active_period_for_unstaking: 1,
+                        blocks_remaining_in_active_period_for_unstaking: 2,
                         is_active: true
                     })
                 })
             }
         );
 
-        StakePool::finalize_slashing_and_unstaking();
+        System::set_block_number(1);
+        StakePool::on_finalize(System::block_number());
         assert_eq!(
             StakePool::stakes(&stake_id),
             Stake {
-                created: System::block_number(),
+                created: 0,
                 staking_status: StakingStatus::NotStaked
             }
         );
+        assert_eq!(StakePool::unstaking_finalization_block(&stake_id), None);
+        assert!(!<UnstakingSchedule<Test>>::contains_key(1));
 
         assert_eq!(StakePool::stake_pool_balance(), starting_stake_fund_balance);
 
@@ -977,3 +998,227 @@ fn immediate_slashing_cannot_slash_zero() {
         );
     });
 }
+
+#[test]
+fn slashing_and_unstaking_schedules_are_maintained() {
+    build_test_externalities().execute_with(|| {
+        let staked_amount = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&StakePool::stake_pool_account_id(), staked_amount);
+
+        System::set_block_number(10);
+
+        let stake_id = StakePool::create_stake();
+        <Stakes<Test>>::insert(
+            &stake_id,
+            Stake {
+                created: System::block_number(),
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount,
+                    staked_status: StakedStatus::Normal,
+                    next_slash_id: 0,
+                    ongoing_slashes: BTreeMap::new(),
+                }),
+            },
+        );
+
+        let slash_id = StakePool::initiate_slashing(&stake_id, 100, 5).unwrap();
+        assert_eq!(StakePool::slashing_schedule(14), vec![(stake_id, slash_id)]);
+
+        // Paused slashing keeps the remaining blocks and leaves the schedule
+        System::set_block_number(12);
+        assert_ok!(StakePool::pause_slashing(&stake_id, &slash_id));
+        assert!(!<SlashingSchedule<Test>>::contains_key(14));
+        assert_eq!(StakePool::slash_execution_block(&stake_id, &slash_id), None);
+
+        match StakePool::stakes(&stake_id).staking_status {
+            StakingStatus::Staked(staked_state) => assert_eq!(
+                staked_state.ongoing_slashes[&slash_id]
+                    .blocks_remaining_in_active_period_for_slashing,
+                3
+            ),
+            _ => panic!("stake should be staked"),
+        }
+
+        // Resumed slashing is rescheduled
+        System::set_block_number(20);
+        assert_ok!(StakePool::resume_slashing(&stake_id, &slash_id));
+        assert_eq!(StakePool::slashing_schedule(22), vec![(stake_id, slash_id)]);
+
+        // Immediate slashing causing the unstaking drops the ongoing slashes from the schedule
+        assert!(StakePool::slash_immediate(&stake_id, staked_amount, true).is_ok());
+        assert!(StakePool::stakes(&stake_id).is_not_staked());
+        assert!(!<SlashingSchedule<Test>>::contains_key(22));
+        assert_eq!(StakePool::slash_execution_block(&stake_id, &slash_id), None);
+    });
+}
+
+#[test]
+fn migrate_slashing_and_unstaking_schedules_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let staked_amount = Balances::minimum_balance() + 10000;
+
+        System::set_block_number(10);
+
+        let mut ongoing_slashes = BTreeMap::new();
+        ongoing_slashes.insert(
+            0,
+            Slash {
+                started_at_block: 0,
+                is_active: true,
+                blocks_remaining_in_active_period_for_slashing: 5,
+                slash_amount: 100,
+            },
+        );
+
+        let stake_id = StakePool::create_stake();
+        <Stakes<Test>>::insert(
+            &stake_id,
+            Stake {
+                created: 0,
+                staking_status: StakingStatus::Staked(StakedState {
+                    staked_amount,
+                    staked_status: StakedStatus::Unstaking(UnstakingState {
+                        started_at_block: 0,
+                        is_active: true,
+                        blocks_remaining_in_active_period_for_unstaking: 20,
+                    }),
+                    next_slash_id: 1,
+                    ongoing_slashes,
+                }),
+            },
+        );
+
+        StakePool::migrate_slashing_and_unstaking_schedules();
+
+        assert_eq!(StakePool::slashing_schedule(14), vec![(stake_id, 0)]);
+        assert_eq!(StakePool::unstaking_schedule(29), vec![stake_id]);
+    });
+}
+
+#[test]
+fn staking_with_balance_lock() {
+    build_test_externalities().execute_with(|| {
+        const LOCK_ID: LockIdentifier = *b"testlock";
+        let staker_account_id = 1;
+        let balance = Balances::minimum_balance() + 10000;
+        let _ = Balances::deposit_creating(&staker_account_id, balance);
+
+        System::set_block_number(1);
+
+        let first_stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake_with_lock(
+            &first_stake_id,
+            &staker_account_id,
+            5000,
+            LOCK_ID
+        ));
+
+        // funds stay on the staker account
+        assert_eq!(StakePool::stake_pool_balance(), 0);
+        assert_eq!(Balances::free_balance(&staker_account_id), balance);
+        assert_eq!(Balances::usable_balance(&staker_account_id), balance - 5000);
+        assert_eq!(
+            StakePool::stake_lock(&first_stake_id),
+            Some((LOCK_ID, staker_account_id))
+        );
+
+        // stakes with the same lock id share the lock
+        let second_stake_id = StakePool::create_stake();
+        assert_ok!(StakePool::stake_with_lock(
+            &second_stake_id,
+            &staker_account_id,
+            5000,
+            LOCK_ID
+        ));
+        assert_eq!(
+            StakePool::locked_balance(&LOCK_ID, &staker_account_id),
+            10000
+        );
+        assert_eq!(
+            Balances::usable_balance(&staker_account_id),
+            balance - 10000
+        );
+
+        // cannot lock more than the free balance
+        assert_err!(
+            StakePool::ensure_can_stake_with_lock(&staker_account_id, 1000, LOCK_ID),
+            StakeActionError::Error(StakingFromAccountError::InsufficientBalanceInSourceAccount)
+        );
+        assert_err!(
+            StakePool::ensure_can_stake_with_lock(&staker_account_id, 0, LOCK_ID),
+            StakeActionError::Error(StakingFromAccountError::StakingError(
+                StakingError::CannotStakeZero
+            ))
+        );
+
+        let third_stake_id = StakePool::create_stake();
+        assert_err!(
+            StakePool::stake_with_lock(&third_stake_id, &staker_account_id, 1000, LOCK_ID),
+            StakeActionError::Error(StakingFromAccountError::InsufficientBalanceInSourceAccount)
+        );
+        assert!(StakePool::stakes(&third_stake_id).is_not_staked());
+
+        // decreasing the stake to the staker account unlocks the funds
+        assert_eq!(
+            StakePool::decrease_stake_to_account(&first_stake_id, &staker_account_id, 1000),
+            Ok(4000)
+        );
+        assert_eq!(
+            StakePool::locked_balance(&LOCK_ID, &staker_account_id),
+            9000
+        );
+        assert_eq!(Balances::free_balance(&staker_account_id), balance);
+
+        // slashing takes the funds from the staker account
+        assert!(StakePool::slash_immediate(&first_stake_id, 1000, false).is_ok());
+        assert_eq!(
+            StakePool::locked_balance(&LOCK_ID, &staker_account_id),
+            8000
+        );
+        assert_eq!(Balances::free_balance(&staker_account_id), balance - 1000);
+
+        // the stake can be moved to the lock of another purpose
+        const OTHER_LOCK_ID: LockIdentifier = *b"otherlck";
+        assert_ok!(StakePool::change_stake_lock(&first_stake_id, OTHER_LOCK_ID));
+        assert_eq!(
+            StakePool::stake_lock(&first_stake_id),
+            Some((OTHER_LOCK_ID, staker_account_id))
+        );
+        assert_eq!(
+            StakePool::locked_balance(&LOCK_ID, &staker_account_id),
+            5000
+        );
+        assert_eq!(
+            StakePool::locked_balance(&OTHER_LOCK_ID, &staker_account_id),
+            3000
+        );
+        assert_err!(
+            StakePool::change_stake_lock(&third_stake_id, LOCK_ID),
+            StakeActionError::Error(ChangingStakeLockError::NotStaked)
+        );
+
+        // unstaking releases the lock of the stake
+        assert_ok!(StakePool::initiate_unstaking(&first_stake_id, None));
+        assert_eq!(StakePool::stake_lock(&first_stake_id), None);
+        assert!(!<LockedBalances<Test>>::contains_key(
+            &OTHER_LOCK_ID,
+            &staker_account_id
+        ));
+        assert_eq!(
+            StakePool::locked_balance(&LOCK_ID, &staker_account_id),
+            5000
+        );
+        assert_eq!(Balances::free_balance(&staker_account_id), balance - 1000);
+
+        assert_ok!(StakePool::initiate_unstaking(&second_stake_id, Some(2)));
+        StakePool::on_finalize(2);
+
+        assert!(StakePool::stakes(&second_stake_id).is_not_staked());
+        assert!(!<LockedBalances<Test>>::contains_key(
+            &LOCK_ID,
+            &staker_account_id
+        ));
+        assert_eq!(Balances::usable_balance(&staker_account_id), balance - 1000);
+        assert_eq!(StakePool::stake_pool_balance(), 0);
+    });
+}
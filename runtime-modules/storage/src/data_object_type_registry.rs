@@ -4,7 +4,8 @@
 //! ## Comments
 //!
 //! Data object type registry module uses  working group module to authorize actions. Only leader can
-//! call extrinsics.
+//! call extrinsics. Data object types can also be activated or deactivated by the root (council
+//! proposals).
 //!
 //! ## Supported extrinsics
 //!
@@ -23,9 +24,10 @@
 //#![warn(missing_docs)]
 
 use codec::{Decode, Encode};
-use frame_support::dispatch::DispatchError;
+use frame_support::dispatch::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use frame_support::{decl_error, decl_event, decl_module, decl_storage};
+use frame_system::ensure_root;
 use sp_std::vec::Vec;
 
 use crate::{DataObjectTypeId, StorageWorkingGroup, StorageWorkingGroupInstance};
@@ -171,10 +173,10 @@ decl_module! {
             Self::deposit_event(RawEvent::DataObjectTypeUpdated(id, data_object_type));
         }
 
-        /// Activates existing data object type. Requires leader privileges.
+        /// Activates existing data object type. Requires leader or root privileges.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn activate_data_object_type(origin, id: T::DataObjectTypeId) {
-            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+            Self::ensure_origin_is_leader_or_root(origin)?;
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
@@ -189,10 +191,10 @@ decl_module! {
             Self::deposit_event(RawEvent::DataObjectTypeUpdated(id, do_type));
         }
 
-        /// Deactivates existing data object type. Requires leader privileges.
+        /// Deactivates existing data object type. Requires leader or root privileges.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn deactivate_data_object_type(origin, id: T::DataObjectTypeId) {
-            <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(origin)?;
+            Self::ensure_origin_is_leader_or_root(origin)?;
 
             let mut do_type = Self::ensure_data_object_type(id)?;
 
@@ -210,6 +212,17 @@ decl_module! {
 }
 
 impl<T: Trait> Module<T> {
+    // Ensures the origin is the storage working group leader or the root.
+    fn ensure_origin_is_leader_or_root(origin: T::Origin) -> DispatchResult {
+        let (cloned_origin1, cloned_origin2) = common::origin::double_origin::<T>(origin);
+
+        if ensure_root(cloned_origin1).is_ok() {
+            return Ok(());
+        }
+
+        <StorageWorkingGroup<T>>::ensure_origin_is_active_leader(cloned_origin2)
+    }
+
     fn ensure_data_object_type(id: T::DataObjectTypeId) -> Result<DataObjectType, DispatchError> {
         Self::data_object_types(&id).ok_or_else(|| Error::<T>::DataObjectTypeNotFound.into())
     }
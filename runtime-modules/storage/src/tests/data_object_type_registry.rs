@@ -327,3 +327,42 @@ fn activate_existing() {
         assert!(!data.unwrap().active);
     });
 }
+
+#[test]
+fn activate_and_deactivate_data_object_type_succeeds_with_root() {
+    with_default_mock_builder(|| {
+        run_to_block(1);
+
+        SetLeadFixture::set_default_lead();
+
+        let data: TestDataObjectType = TestDataObjectType {
+            description: "foo".as_bytes().to_vec(),
+            active: false,
+        };
+        let id_res = TestDataObjectTypeRegistry::register_data_object_type(
+            RawOrigin::Signed(DEFAULT_LEADER_ACCOUNT_ID).into(),
+            data,
+        );
+        assert!(id_res.is_ok());
+
+        let dot_id = get_last_data_object_type_id();
+
+        let res =
+            TestDataObjectTypeRegistry::activate_data_object_type(RawOrigin::Root.into(), dot_id);
+        assert!(res.is_ok());
+        assert!(
+            TestDataObjectTypeRegistry::data_object_types(dot_id)
+                .unwrap()
+                .active
+        );
+
+        let res =
+            TestDataObjectTypeRegistry::deactivate_data_object_type(RawOrigin::Root.into(), dot_id);
+        assert!(res.is_ok());
+        assert!(
+            !TestDataObjectTypeRegistry::data_object_types(dot_id)
+                .unwrap()
+                .active
+        );
+    });
+}
@@ -46,6 +46,8 @@ impl_outer_event! {
         data_object_storage_registry<T>,
         balances<T>,
         members<T>,
+        stake<T>,
+        minting<T>,
         working_group_mod StorageWorkingGroupInstance <T>,
         frame_system<T>,
     }
@@ -197,11 +199,17 @@ impl GovernanceCurrency for Test {
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const WorkingGroupRoleLockId: [u8; 8] = *b"wg-role ";
+    pub const WorkingGroupApplicationLockId: [u8; 8] = *b"wg-apply";
+    pub const WorkingGroupRewardPeriod: u32 = 10;
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Test {
     type Event = MetaEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = WorkingGroupRoleLockId;
+    type ApplicationLockId = WorkingGroupApplicationLockId;
+    type RewardPeriod = WorkingGroupRewardPeriod;
 }
 
 impl data_object_type_registry::Trait for Test {
@@ -222,6 +230,12 @@ impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
     }
 }
 
+impl common::origin::ActorOriginValidator<Origin, u32, u64> for () {
+    fn ensure_actor_origin(_: Origin, _: u32) -> Result<u64, &'static str> {
+        Err("Actor origin validation is not supported")
+    }
+}
+
 impl data_object_storage_registry::Trait for Test {
     type Event = MetaEvent;
     type DataObjectStorageRelationshipId = u64;
@@ -239,9 +253,11 @@ impl membership::Trait for Test {
     type PaidTermId = u32;
     type ActorId = u32;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator = ();
 }
 
 impl stake::Trait for Test {
+    type Event = MetaEvent;
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = ();
@@ -249,15 +265,26 @@ impl stake::Trait for Test {
     type SlashId = u64;
 }
 
+parameter_types! {
+    pub const MaxTransferLogLength: u32 = 10;
+}
+
 impl minting::Trait for Test {
+    type Event = MetaEvent;
     type Currency = Balances;
     type MintId = u64;
+    type MaxTransferLogLength = MaxTransferLogLength;
+}
+
+parameter_types! {
+    pub const CatchUpPayoutLimit: u64 = 1000;
 }
 
 impl recurringrewards::Trait for Test {
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type CatchUpPayoutLimit = CatchUpPayoutLimit;
 }
 
 impl hiring::Trait for Test {
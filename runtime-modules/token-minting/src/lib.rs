@@ -8,10 +8,13 @@
 
 use codec::{Codec, Decode, Encode};
 use frame_support::storage::IterableStorageMap;
-use frame_support::traits::Currency;
-use frame_support::{decl_module, decl_storage, ensure, Parameter};
+use frame_support::traits::{Currency, Get};
+use frame_support::{decl_event, decl_module, decl_storage, ensure, Parameter};
 use sp_arithmetic::traits::{BaseArithmetic, One, Zero};
 use sp_runtime::traits::{MaybeSerialize, Member};
+use sp_std::vec::Vec;
+
+pub mod runtime_api;
 
 mod mint;
 mod mock;
@@ -20,6 +23,9 @@ mod tests;
 pub use mint::*;
 
 pub trait Trait: frame_system::Trait {
+    /// The overarching event type.
+    type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
+
     /// The currency to mint.
     type Currency: Currency<Self::AccountId>;
 
@@ -32,6 +38,9 @@ pub trait Trait: frame_system::Trait {
         + Copy
         + MaybeSerialize
         + PartialEq;
+
+    /// Maximum number of the recent transfers kept in the transfer log of each mint.
+    type MaxTransferLogLength: Get<u32>;
 }
 
 pub type BalanceOf<T> =
@@ -103,6 +112,26 @@ pub enum Adjustment<Balance: Zero, BlockNumber> {
     IntervalAfterFirstAdjustmentRelative(AdjustOnInterval<Balance, BlockNumber>, BlockNumber),
 }
 
+/// Record of the tokens transferred from a mint.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq)]
+pub struct MintTransfer<AccountId, Balance, BlockNumber> {
+    /// Recipient account of the transfer.
+    pub destination: AccountId,
+
+    /// Transferred amount.
+    pub amount: Balance,
+
+    /// Block of the transfer.
+    pub block: BlockNumber,
+}
+
+/// Alias for the mint transfer record.
+pub type MintTransferOf<T> = MintTransfer<
+    <T as frame_system::Trait>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Trait>::BlockNumber,
+>;
+
 decl_storage! {
     trait Store for Module<T: Trait> as TokenMint {
         /// Mints
@@ -110,11 +139,63 @@ decl_storage! {
 
         /// The number of mints created.
         pub MintsCreated get(fn mints_created): T::MintId;
+
+        /// Rolling log of the recent transfers from the mint, oldest first. The log length is
+        /// bounded by the MaxTransferLogLength.
+        pub MintTransfers get(fn mint_transfers):
+            map hasher(blake2_128_concat) T::MintId => Vec<MintTransferOf<T>>;
     }
 }
+
+decl_event!(
+    /// Token minting events
+    pub enum Event<T>
+    where
+        MintId = <T as Trait>::MintId,
+        AccountId = <T as frame_system::Trait>::AccountId,
+        Balance = BalanceOf<T>,
+    {
+        /// Emits on adding a mint.
+        /// Params:
+        /// - mint id
+        /// - initial capacity
+        MintAdded(MintId, Balance),
+
+        /// Emits on removing a mint.
+        /// Params:
+        /// - mint id
+        MintRemoved(MintId),
+
+        /// Emits on setting the mint capacity or on the scheduled capacity adjustment.
+        /// Params:
+        /// - mint id
+        /// - new capacity
+        MintCapacityChanged(MintId, Balance),
+
+        /// Emits on transferring capacity between mints.
+        /// Params:
+        /// - source mint id
+        /// - destination mint id
+        /// - transferred capacity
+        MintCapacityTransferred(MintId, MintId, Balance),
+
+        /// Emits on transferring the minted tokens to an account.
+        /// Params:
+        /// - mint id
+        /// - recipient account id
+        /// - transferred amount
+        TokensTransferred(MintId, AccountId, Balance),
+    }
+);
+
 // pub Account: map hasher(blake2_128_concat) T::AccountId => AccountData<T::Balance>;
 decl_module! {
     pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+        fn deposit_event() = default;
+
+        /// Maximum number of the recent transfers kept in the transfer log of each mint.
+        const MaxTransferLogLength: u32 = T::MaxTransferLogLength::get();
+
         fn on_finalize(now: T::BlockNumber) {
             Self::update_mints(now);
         }
@@ -126,7 +207,11 @@ impl<T: Trait> Module<T> {
         // Are we reading value from storage twice?
         for (mint_id, ref mut mint) in <Mints<T>>::iter() {
             if mint.maybe_do_capacity_adjustment(now) {
+                let capacity = mint.capacity();
+
                 <Mints<T>>::insert(&mint_id, mint);
+
+                Self::deposit_event(RawEvent::MintCapacityChanged(mint_id, capacity));
             }
         }
     }
@@ -178,12 +263,21 @@ impl<T: Trait> Module<T> {
 
         <Mints<T>>::insert(mint_id, Mint::new(initial_capacity, next_adjustment, now));
 
+        Self::deposit_event(RawEvent::MintAdded(mint_id, initial_capacity));
+
         Ok(mint_id)
     }
 
-    /// Removes a mint. Passing a non existent mint has no side effects.
+    /// Removes a mint with its transfer log. Passing a non existent mint has no side effects.
     pub fn remove_mint(mint_id: T::MintId) {
+        if !<Mints<T>>::contains_key(&mint_id) {
+            return;
+        }
+
         <Mints<T>>::remove(&mint_id);
+        <MintTransfers<T>>::remove(&mint_id);
+
+        Self::deposit_event(RawEvent::MintRemoved(mint_id));
     }
 
     /// Tries to transfer exact requested amount from mint to a recipient account id.
@@ -213,9 +307,39 @@ impl<T: Trait> Module<T> {
         // Deposit into recipient account
         T::Currency::deposit_creating(recipient, requested_amount);
 
+        Self::log_transfer(
+            mint_id,
+            MintTransfer {
+                destination: recipient.clone(),
+                amount: requested_amount,
+                block: <frame_system::Module<T>>::block_number(),
+            },
+        );
+
+        Self::deposit_event(RawEvent::TokensTransferred(
+            mint_id,
+            recipient.clone(),
+            requested_amount,
+        ));
+
         Ok(())
     }
 
+    // Appends the transfer to the mint transfer log dropping the oldest transfers
+    // beyond the MaxTransferLogLength.
+    fn log_transfer(mint_id: T::MintId, transfer: MintTransferOf<T>) {
+        let max_length = T::MaxTransferLogLength::get() as usize;
+
+        <MintTransfers<T>>::mutate(&mint_id, |transfers| {
+            transfers.push(transfer);
+
+            if transfers.len() > max_length {
+                let excess = transfers.len() - max_length;
+                transfers.drain(..excess);
+            }
+        });
+    }
+
     /// Provided mint exists, sets its capacity to specied value, return error otherwise.
     pub fn set_mint_capacity(
         mint_id: T::MintId,
@@ -230,6 +354,8 @@ impl<T: Trait> Module<T> {
             mint.set_capacity(capacity);
         });
 
+        Self::deposit_event(RawEvent::MintCapacityChanged(mint_id, capacity));
+
         Ok(())
     }
 
@@ -256,9 +382,28 @@ impl<T: Trait> Module<T> {
             })
         })?;
 
+        Self::deposit_event(RawEvent::MintCapacityTransferred(
+            source,
+            destination,
+            capacity_to_transfer,
+        ));
+
         Ok(())
     }
 
+    /// Returns a mint with its capacity, total minted amount and next scheduled adjustment
+    /// if it exists, error otherwise.
+    pub fn get_mint(
+        mint_id: T::MintId,
+    ) -> Result<Mint<BalanceOf<T>, T::BlockNumber>, GeneralError> {
+        ensure!(
+            <Mints<T>>::contains_key(&mint_id),
+            GeneralError::MintNotFound
+        );
+
+        Ok(Self::mints(&mint_id))
+    }
+
     /// Returns a mint's capacity if it exists, error otherwise.
     pub fn get_mint_capacity(mint_id: T::MintId) -> Result<BalanceOf<T>, GeneralError> {
         ensure!(
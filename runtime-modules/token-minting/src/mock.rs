@@ -2,7 +2,7 @@
 
 use crate::{Module, Trait};
 use balances;
-use frame_support::{impl_outer_origin, parameter_types};
+use frame_support::{impl_outer_event, impl_outer_origin, parameter_types};
 use sp_core::H256;
 use sp_runtime::{
     testing::Header,
@@ -14,6 +14,18 @@ impl_outer_origin! {
     pub enum Origin for Test {}
 }
 
+mod minting {
+    pub use crate::Event;
+}
+
+impl_outer_event! {
+    pub enum TestEvent for Test {
+        minting<T>,
+        balances<T>,
+        frame_system<T>,
+    }
+}
+
 // Workaround for https://github.com/rust-lang/rust/issues/26925 . Remove when sorted.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Test;
@@ -35,7 +47,7 @@ impl frame_system::Trait for Test {
     type AccountId = u64;
     type Lookup = IdentityLookup<Self::AccountId>;
     type Header = Header;
-    type Event = ();
+    type Event = TestEvent;
     type BlockHashCount = BlockHashCount;
     type MaximumBlockWeight = MaximumBlockWeight;
     type DbWeight = ();
@@ -58,12 +70,13 @@ parameter_types! {
     pub const CreationFee: u32 = 0;
     pub const TransactionBaseFee: u32 = 1;
     pub const TransactionByteFee: u32 = 0;
+    pub const MaxTransferLogLength: u32 = 3;
 }
 
 impl balances::Trait for Test {
     type Balance = u64;
     type DustRemoval = ();
-    type Event = ();
+    type Event = TestEvent;
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = ();
@@ -71,8 +84,10 @@ impl balances::Trait for Test {
 }
 
 impl Trait for Test {
+    type Event = TestEvent;
     type Currency = Balances;
     type MintId = u64;
+    type MaxTransferLogLength = MaxTransferLogLength;
 }
 
 pub fn build_test_externalities() -> sp_io::TestExternalities {
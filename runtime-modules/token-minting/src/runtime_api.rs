@@ -0,0 +1,23 @@
+//! Runtime API of the token minting module.
+
+use crate::{Mint, MintTransfer};
+use codec::Codec;
+use sp_arithmetic::traits::BaseArithmetic;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Mint queries.
+    pub trait MintApi<MintId, AccountId, Balance, BlockNumber> where
+        MintId: Codec,
+        AccountId: Codec,
+        Balance: Codec + Copy + BaseArithmetic,
+        BlockNumber: Codec + Copy + BaseArithmetic,
+    {
+        /// Mint with its capacity, total minted amount and next scheduled adjustment,
+        /// `None` when the mint doesn't exist.
+        fn mint(mint_id: MintId) -> Option<Mint<Balance, BlockNumber>>;
+
+        /// Recent transfers from the mint, oldest first.
+        fn recent_transfers(mint_id: MintId) -> Vec<MintTransfer<AccountId, Balance, BlockNumber>>;
+    }
+}
@@ -2,6 +2,17 @@
 
 use super::*;
 use crate::mock::*;
+use frame_system::{EventRecord, Phase};
+
+fn assert_last_event(expected_raw_event: RawEvent<u64, u64, u64>) {
+    let expected_event = EventRecord {
+        phase: Phase::Initialization,
+        event: TestEvent::minting(expected_raw_event),
+        topics: vec![],
+    };
+
+    assert_eq!(System::events().pop().unwrap(), expected_event);
+}
 
 #[test]
 fn adding_and_removing_mints() {
@@ -200,3 +211,142 @@ fn adjustment_first_interval() {
         );
     });
 }
+
+#[test]
+fn transfers_are_logged_and_log_is_bounded() {
+    build_test_externalities().execute_with(|| {
+        let mint_id = Minting::add_mint(5000, None).ok().unwrap();
+
+        for (block, destination) in (1..=4).zip(10..) {
+            System::set_block_number(block);
+            assert!(Minting::transfer_tokens(mint_id, 100 * block, &destination).is_ok());
+        }
+
+        // zero amount transfers are not logged
+        assert!(Minting::transfer_tokens(mint_id, 0, &20).is_ok());
+
+        // the oldest transfer is dropped
+        assert_eq!(
+            Minting::mint_transfers(mint_id),
+            vec![
+                MintTransfer {
+                    destination: 11,
+                    amount: 200,
+                    block: 2,
+                },
+                MintTransfer {
+                    destination: 12,
+                    amount: 300,
+                    block: 3,
+                },
+                MintTransfer {
+                    destination: 13,
+                    amount: 400,
+                    block: 4,
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn failed_transfers_are_not_logged() {
+    build_test_externalities().execute_with(|| {
+        let mint_id = Minting::add_mint(1000, None).ok().unwrap();
+
+        assert_eq!(
+            Minting::transfer_tokens(mint_id, 2000, &1),
+            Err(TransferError::NotEnoughCapacity)
+        );
+
+        assert!(Minting::mint_transfers(mint_id).is_empty());
+    });
+}
+
+#[test]
+fn removing_mint_removes_transfer_log() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(1);
+
+        let mint_id = Minting::add_mint(1000, None).ok().unwrap();
+        assert!(Minting::transfer_tokens(mint_id, 100, &1).is_ok());
+
+        Minting::remove_mint(mint_id);
+
+        assert!(!<MintTransfers<Test>>::contains_key(mint_id));
+        assert_last_event(RawEvent::MintRemoved(mint_id));
+    });
+}
+
+#[test]
+fn get_mint_returns_mint_with_adjustment_schedule() {
+    build_test_externalities().execute_with(|| {
+        System::set_block_number(1);
+
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Setting(1000),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(5000, Some(Adjustment::Interval(adjustment)))
+            .ok()
+            .unwrap();
+        assert!(Minting::transfer_tokens(mint_id, 1000, &1).is_ok());
+
+        let mint = Minting::get_mint(mint_id).ok().unwrap();
+
+        assert_eq!(mint.capacity(), 4000);
+        assert_eq!(mint.total_minted(), 1000);
+        assert_eq!(
+            mint.next_adjustment(),
+            Some(NextAdjustment {
+                adjustment,
+                at_block: 101,
+            })
+        );
+
+        assert_eq!(
+            Minting::get_mint(mint_id + 1).err(),
+            Some(GeneralError::MintNotFound)
+        );
+    });
+}
+
+#[test]
+fn minting_events_are_emitted() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        System::set_block_number(1);
+
+        let adjustment = AdjustOnInterval {
+            adjustment_type: AdjustCapacityBy::Adding(500),
+            block_interval: 100,
+        };
+
+        let mint_id = Minting::add_mint(5000, Some(Adjustment::Interval(adjustment)))
+            .ok()
+            .unwrap();
+        assert_last_event(RawEvent::MintAdded(mint_id, 5000));
+
+        assert!(Minting::transfer_tokens(mint_id, 1000, &1).is_ok());
+        assert_last_event(RawEvent::TokensTransferred(mint_id, 1, 1000));
+
+        assert!(Minting::set_mint_capacity(mint_id, 3000).is_ok());
+        assert_last_event(RawEvent::MintCapacityChanged(mint_id, 3000));
+
+        Minting::update_mints(101);
+        assert_last_event(RawEvent::MintCapacityChanged(mint_id, 3500));
+
+        let destination_mint_id = Minting::add_mint(0, None).ok().unwrap();
+        assert!(Minting::transfer_capacity(mint_id, destination_mint_id, 500).is_ok());
+        assert_last_event(RawEvent::MintCapacityTransferred(
+            mint_id,
+            destination_mint_id,
+            500,
+        ));
+    });
+}
@@ -96,6 +96,9 @@ decl_error! {
         /// Worker does not exist.
         WorkerDoesNotExist,
 
+        /// Performance review does not exist for the worker.
+        PerformanceReviewDoesNotExist,
+
         /// Opening does not exist.
         AcceptWorkerApplicationsOpeningDoesNotExist,
 
@@ -207,6 +210,12 @@ decl_error! {
         /// Reward relationship not found.
         RecurringRewardsRewardRelationshipNotFound,
 
+        /// Reward relationship has no owed reward.
+        RecurringRewardsNoOwedReward,
+
+        /// Reward source has insufficient capacity to pay the owed reward.
+        RecurringRewardsInsufficientRewardSourceCapacity,
+
         /// Stake not found.
         StakingErrorStakeNotFound,
 
@@ -303,11 +312,77 @@ decl_error! {
         ApplicationStakingPolicyReviewPeriodUnstakingPeriodIsZero,
 
         /// Invalid OpeningPolicyCommitment parameter (application_rationing_policy):
-        /// max_active_applicants should be non-zero.
-        ApplicationRationingPolicyMaxActiveApplicantsIsZero,
+        /// applications are ranked by the review scores, the rationing policy is not supported.
+        ApplicationRationingPolicyIsNotSupported,
 
         /// Minting error: NextAdjustmentInPast
         MintingErrorNextAdjustmentInPast,
+
+        /// Minting error: NotEnoughCapacity
+        MintingErrorNotEnoughCapacity,
+
+        /// Working group budget is not set.
+        BudgetNotSet,
+
+        /// Budget period length cannot be zero.
+        BudgetPeriodLengthCannotBeZero,
+
+        /// Discretionary payment exceeds the remaining budget of the period.
+        InsufficientBudget,
+
+        /// Discretionary payment amount cannot be zero.
+        DiscretionaryPaymentAmountCannotBeZero,
+
+        /// Discretionary payment rationale text is too short.
+        DiscretionaryPaymentRationaleTextTooShort,
+
+        /// Discretionary payment rationale text is too long.
+        DiscretionaryPaymentRationaleTextTooLong,
+
+        /// Worker exit is already scheduled.
+        WorkerExitAlreadyScheduled,
+
+        /// Worker exit block must be in the future.
+        WorkerExitBlockMustBeInFuture,
+
+        /// Worker is neither the lead nor the reviewer of the opening.
+        WorkerIsNotApplicationReviewer,
+
+        /// Opening is not in the review period.
+        OpeningIsNotInReviewPeriod,
+
+        /// Application is not active.
+        ApplicationIsNotActive,
+
+        /// Application review comment text is too short.
+        ApplicationReviewCommentTextTooShort,
+
+        /// Application review comment text is too long.
+        ApplicationReviewCommentTextTooLong,
+
+        /// Number of the applications to select cannot be zero.
+        NumberOfApplicationsToSelectCannotBeZero,
+
+        /// Not enough active scored applications on the opening.
+        NotEnoughScoredApplications,
+
+        /// Simplified hiring mode opening does not exist.
+        SimpleOpeningDoesNotExist,
+
+        /// Simplified hiring mode application does not exist.
+        SimpleApplicationDoesNotExist,
+
+        /// Stake policy unstaking period should be non-zero.
+        StakePolicyUnstakingPeriodIsZero,
+
+        /// Cannot stake zero.
+        StakingErrorCannotStakeZero,
+
+        /// Cannot stake less than minimum balance.
+        StakingErrorCannotStakeLessThanMinimumBalance,
+
+        /// Stake is already staked.
+        StakingErrorAlreadyStaked,
     }
 }
 
@@ -543,6 +618,10 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<recurringrewar
             recurringrewards::RewardsError::RewardRelationshipNotFound => {
                 Error::RecurringRewardsRewardRelationshipNotFound
             }
+            recurringrewards::RewardsError::NoOwedReward => Error::RecurringRewardsNoOwedReward,
+            recurringrewards::RewardsError::InsufficientRewardSourceCapacity => {
+                Error::RecurringRewardsInsufficientRewardSourceCapacity
+            }
         }
     }
 }
@@ -576,6 +655,37 @@ impl<T: Trait<I>, I: Instance>
     }
 }
 
+impl<T: Trait<I>, I: Instance>
+    sp_std::convert::From<WrappedError<stake::StakeActionError<stake::StakingFromAccountError>>>
+    for Error<T, I>
+{
+    fn from(
+        wrapper: WrappedError<stake::StakeActionError<stake::StakingFromAccountError>>,
+    ) -> Self {
+        match wrapper.error {
+            stake::StakeActionError::StakeNotFound => Error::StakingErrorStakeNotFound,
+            stake::StakeActionError::Error(staking_error_from_account) => {
+                match staking_error_from_account {
+                    stake::StakingFromAccountError::InsufficientBalanceInSourceAccount => {
+                        Error::StakingErrorInsufficientBalanceInSourceAccount
+                    }
+                    stake::StakingFromAccountError::StakingError(staking_error) => {
+                        match staking_error {
+                            stake::StakingError::CannotStakeZero => {
+                                Error::StakingErrorCannotStakeZero
+                            }
+                            stake::StakingError::CannotStakeLessThanMinimumBalance => {
+                                Error::StakingErrorCannotStakeLessThanMinimumBalance
+                            }
+                            stake::StakingError::AlreadyStaked => Error::StakingErrorAlreadyStaked,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl<T: Trait<I>, I: Instance>
     sp_std::convert::From<
         WrappedError<stake::StakeActionError<stake::IncreasingStakeFromAccountError>>,
@@ -685,3 +795,14 @@ impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<minting::Gener
         }
     }
 }
+
+impl<T: Trait<I>, I: Instance> sp_std::convert::From<WrappedError<minting::TransferError>>
+    for Error<T, I>
+{
+    fn from(wrapper: WrappedError<minting::TransferError>) -> Self {
+        match wrapper.error {
+            minting::TransferError::MintNotFound => Error::CannotFindMint,
+            minting::TransferError::NotEnoughCapacity => Error::MintingErrorNotEnoughCapacity,
+        }
+    }
+}
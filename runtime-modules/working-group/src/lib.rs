@@ -23,6 +23,16 @@
 //! - [withdraw_application](./struct.Module.html#method.withdraw_application) - Withdraw the worker/lead application.
 //! - [terminate_application](./struct.Module.html#method.terminate_application) - Terminate the worker/lead application.
 //! - [apply_on_opening](./struct.Module.html#method.apply_on_opening) - Apply on a worker/lead opening.
+//! - [set_opening_reviewers](./struct.Module.html#method.set_opening_reviewers) - Set the workers reviewing the worker/lead applications.
+//! - [score_application](./struct.Module.html#method.score_application) - Score the worker/lead application during the review.
+//! - [fill_opening_with_top_scored_applications](./struct.Module.html#method.fill_opening_with_top_scored_applications) - Fill opening with the top scored applications.
+//!
+//! ### Simplified hiring flow
+//!
+//! - [add_simple_opening](./struct.Module.html#method.add_simple_opening) - Add a simplified opening with a single stake amount and unstaking period.
+//! - [apply_on_simple_opening](./struct.Module.html#method.apply_on_simple_opening) - Apply on a simplified worker/lead opening.
+//! - [withdraw_simple_application](./struct.Module.html#method.withdraw_simple_application) - Withdraw the simplified opening application.
+//! - [fill_simple_opening](./struct.Module.html#method.fill_simple_opening) - Fill the simplified opening, the rewards are paid from the group mint.
 //!
 //! ### Roles lifecycle
 //!
@@ -30,14 +40,20 @@
 //! - [update_reward_account](./struct.Module.html#method.update_reward_account) -  Update the reward account of the worker/lead.
 //! - [update_reward_amount](./struct.Module.html#method.update_reward_amount) -  Update the reward amount of the worker/lead.
 //! - [leave_role](./struct.Module.html#method.leave_role) - Leave the role by the active worker/lead.
+//! - [leave_role_with_notice](./struct.Module.html#method.leave_role_with_notice) - Schedule leaving the role by the active worker/lead.
 //! - [terminate_role](./struct.Module.html#method.terminate_role) - Terminate the worker/lead role.
+//! - [add_performance_review](./struct.Module.html#method.add_performance_review) - File a performance review of the worker/lead.
 //! - [set_mint_capacity](./struct.Module.html#method.set_mint_capacity) -  Sets the capacity to enable working group budget.
+//! Updates the spending ceiling of the budget when the budget is set.
+//! - [set_budget](./struct.Module.html#method.set_budget) -  Sets the working group budget with the per-period spending ceiling.
+//! The mint capacity is renewed to the spending ceiling at the start of each budget period.
+//! - [make_discretionary_payment](./struct.Module.html#method.make_discretionary_payment) -  Pays from the budget for the bounties or one-off work.
 //!
 //! ### Stakes
 //!
 //! - [slash_stake](./struct.Module.html#method.slash_stake) - Slashes the worker/lead stake.
 //! - [decrease_stake](./struct.Module.html#method.decrease_stake) - Decreases the worker/lead stake and returns the remainder to the worker _role_account_.
-//! - [increase_stake](./struct.Module.html#method.increase_stake) - Increases the worker/lead stake.
+//! - [increase_stake](./struct.Module.html#method.increase_stake) - Increases the worker/lead stake by the worker/lead.
 //!
 
 // Ensure we're `no_std` when compiling for Wasm.
@@ -53,11 +69,14 @@ mod types;
 mod errors;
 
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::storage::IterableStorageMap;
-use frame_support::traits::{Currency, ExistenceRequirement, Get, Imbalance, WithdrawReasons};
+use frame_support::storage::{IterableStorageDoubleMap, IterableStorageMap};
+use frame_support::traits::{
+    Currency, ExistenceRequirement, Get, Imbalance, LockIdentifier, WithdrawReasons,
+};
+use frame_support::weights::Weight;
 use frame_support::{decl_event, decl_module, decl_storage, ensure, print, StorageValue};
 use frame_system::{ensure_root, ensure_signed};
-use sp_arithmetic::traits::{Bounded, One, Zero};
+use sp_arithmetic::traits::{Bounded, One, Saturating, UniqueSaturatedInto, Zero};
 use sp_std::collections::{btree_map::BTreeMap, btree_set::BTreeSet};
 use sp_std::vec;
 use sp_std::vec::Vec;
@@ -68,8 +87,9 @@ use errors::WrappedError;
 
 pub use errors::Error;
 pub use types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, ApplicationScore, Budget, Opening, OpeningPolicyCommitment, OpeningType,
+    PerformanceRating, PerformanceReview, ReviewAction, RewardPolicy, RoleStakeProfile,
+    SimpleApplication, SimpleOpening, SimpleWorkerTerms, StakePolicy, Worker,
 };
 
 /// Stake identifier in staking module
@@ -112,6 +132,53 @@ pub type WorkerId<T> = <T as membership::Trait>::ActorId;
 /// Alias for the application id from the hiring module.
 pub type HiringApplicationId<T> = <T as hiring::Trait>::ApplicationId;
 
+/// Alias for the working group budget.
+pub type BudgetOf<T> = Budget<<T as frame_system::Trait>::BlockNumber, BalanceOfMint<T>>;
+
+/// Performance review identifier.
+pub type PerformanceReviewId = u64;
+
+/// Alias for the worker performance review.
+pub type PerformanceReviewOf<T> = PerformanceReview<
+    <T as frame_system::Trait>::BlockNumber,
+    <T as frame_system::Trait>::Hash,
+    BalanceOf<T>,
+    BalanceOfMint<T>,
+>;
+
+/// Simplified hiring mode opening identifier.
+pub type SimpleOpeningId = u64;
+
+/// Simplified hiring mode application identifier.
+pub type SimpleApplicationId = u64;
+
+/// Alias for the simplified hiring mode opening.
+pub type SimpleOpeningOf<T> = SimpleOpening<
+    <T as frame_system::Trait>::BlockNumber,
+    BalanceOf<T>,
+    BalanceOfMint<T>,
+    SimpleApplicationId,
+>;
+
+/// Alias for the simplified hiring mode application.
+pub type SimpleApplicationOf<T> = SimpleApplication<
+    <T as frame_system::Trait>::AccountId,
+    MemberId<T>,
+    SimpleOpeningId,
+    <T as stake::Trait>::StakeId,
+>;
+
+/// Alias for the stake and reward terms of the worker hired in the simplified hiring mode.
+pub type SimpleWorkerTermsOf<T> = SimpleWorkerTerms<
+    <T as frame_system::Trait>::AccountId,
+    <T as frame_system::Trait>::BlockNumber,
+    <T as stake::Trait>::StakeId,
+    BalanceOfMint<T>,
+>;
+
+/// Alias for the simplified hiring mode application id to the worker id dictionary
+pub type SimpleApplicationIdToWorkerIdMap<T> = BTreeMap<SimpleApplicationId, WorkerId<T>>;
+
 // Type simplification
 type OpeningInfo<T> = (
     OpeningOf<T>,
@@ -166,6 +233,16 @@ pub trait Trait<I: Instance>:
 
     /// Defines max workers number in the working group.
     type MaxWorkerNumberLimit: Get<u32>;
+
+    /// Stake module balance lock identifier of the simplified hiring mode worker stakes.
+    type StakingLockId: Get<LockIdentifier>;
+
+    /// Stake module balance lock identifier of the simplified hiring mode application stakes.
+    /// The stakes of the hired applicants are moved to the worker stake lock.
+    type ApplicationLockId: Get<LockIdentifier>;
+
+    /// Reward payment period (in blocks) of the workers hired in the simplified hiring mode.
+    type RewardPeriod: Get<u32>;
 }
 
 decl_event!(
@@ -180,6 +257,8 @@ decl_event!(
         RationaleText = Vec<u8>,
         MintBalanceOf = minting::BalanceOf<T>,
         <T as minting::Trait>::MintId,
+        <T as frame_system::Trait>::BlockNumber,
+        SimpleApplicationIdToWorkerIdMap = SimpleApplicationIdToWorkerIdMap<T>,
     {
         /// Emits on setting the leader.
         /// Params:
@@ -208,6 +287,12 @@ decl_event!(
         /// - exit rationale text
         WorkerExited(WorkerId, RationaleText),
 
+        /// Emits on scheduling the exit of the worker.
+        /// Params:
+        /// - worker id.
+        /// - block number of the exit.
+        WorkerExitScheduled(WorkerId, BlockNumber),
+
         /// Emits on updating the role account of the worker.
         /// Params:
         /// - Id of the worker.
@@ -231,6 +316,18 @@ decl_event!(
         /// - Id of the worker.
         WorkerRewardAmountUpdated(WorkerId),
 
+        /// Emits on paying the missed rewards owed to the worker.
+        /// Params:
+        /// - Id of the worker.
+        /// - Paid reward amount.
+        MissedRewardsClaimed(WorkerId, MintBalanceOf),
+
+        /// Emits on filing the performance review of the worker/lead.
+        /// Params:
+        /// - Id of the worker.
+        /// - Id of the performance review.
+        PerformanceReviewAdded(WorkerId, PerformanceReviewId),
+
         /// Emits on adding new worker opening.
         /// Params:
         /// - Opening id
@@ -262,12 +359,53 @@ decl_event!(
         /// - Opening id
         BeganApplicationReview(OpeningId),
 
+        /// Emits on setting the reviewers of the worker/lead opening.
+        /// Params:
+        /// - Opening id
+        OpeningReviewersSet(OpeningId),
+
+        /// Emits on scoring the application for the worker/lead opening.
+        /// Params:
+        /// - Worker application id
+        /// - Reviewer worker id
+        /// - Score
+        /// - Reviewer comment
+        ApplicationScored(ApplicationId, WorkerId, u32, RationaleText),
+
         /// Emits on filling the worker opening.
         /// Params:
         /// - Worker opening id
         /// - Worker application id to the worker id dictionary
         OpeningFilled(OpeningId, ApplicationIdToWorkerIdMap),
 
+        /// Emits on adding the simplified hiring mode opening.
+        /// Params:
+        /// - Opening id
+        SimpleOpeningAdded(SimpleOpeningId),
+
+        /// Emits on adding the application for the simplified hiring mode opening.
+        /// Params:
+        /// - Opening id
+        /// - Application id
+        AppliedOnSimpleOpening(SimpleOpeningId, SimpleApplicationId),
+
+        /// Emits on withdrawing the application for the simplified hiring mode opening.
+        /// Params:
+        /// - Application id
+        SimpleApplicationWithdrawn(SimpleApplicationId),
+
+        /// Emits on filling the simplified hiring mode opening.
+        /// Params:
+        /// - Opening id
+        /// - Application id to the worker id dictionary
+        SimpleOpeningFilled(SimpleOpeningId, SimpleApplicationIdToWorkerIdMap),
+
+        /// Emits on paying the reward to the worker/lead hired in the simplified hiring mode.
+        /// Params:
+        /// - Id of the worker.
+        /// - Paid reward amount.
+        RewardPaid(WorkerId, MintBalanceOf),
+
         /// Emits on increasing the worker/lead stake.
         /// Params:
         /// - worker/lead id.
@@ -288,6 +426,25 @@ decl_event!(
         /// - mint id.
         /// - new mint balance.
         MintCapacityChanged(MintId, MintBalanceOf),
+
+        /// Emits on setting the working group budget.
+        /// Params:
+        /// - spending ceiling per period.
+        /// - period length in blocks.
+        BudgetSet(MintBalanceOf, BlockNumber),
+
+        /// Emits on the end of the budget period.
+        /// Params:
+        /// - reward spending in the period.
+        /// - discretionary spending in the period.
+        BudgetPeriodEnded(MintBalanceOf, MintBalanceOf),
+
+        /// Emits on making the discretionary payment by the leader.
+        /// Params:
+        /// - recipient account id.
+        /// - paid amount.
+        /// - payment rationale text.
+        DiscretionaryPaymentMade(AccountId, MintBalanceOf, RationaleText),
     }
 );
 
@@ -343,6 +500,65 @@ decl_storage! {
         /// Required by StakingEventsHandler callback call to refund the balance on unstaking.
         pub MemberIdByHiringApplicationId get(fn member_id_by_hiring_application_id):
             map hasher(blake2_128_concat) HiringApplicationId<T> =>  MemberId<T>;
+
+        /// Workers allowed to score the applications on the opening in addition to the lead.
+        pub OpeningReviewers get(fn opening_reviewers): map hasher(blake2_128_concat)
+            OpeningId<T> => BTreeSet<WorkerId<T>>;
+
+        /// Application scores given by the reviewers. Kept after the opening is filled.
+        pub ApplicationScores get(fn application_score): double_map
+            hasher(blake2_128_concat) ApplicationId<T>,
+            hasher(blake2_128_concat) WorkerId<T> => Option<ApplicationScore>;
+
+        /// Application review comment text length limits.
+        pub ApplicationReviewCommentText get(fn application_review_comment_text) :
+            InputValidationLengthConstraint = default_text_constraint();
+
+        /// Next identifier for new simplified hiring mode opening.
+        pub NextSimpleOpeningId get(fn next_simple_opening_id): SimpleOpeningId;
+
+        /// Maps identifier to simplified hiring mode opening.
+        pub SimpleOpeningById get(fn simple_opening_by_id): map hasher(blake2_128_concat)
+            SimpleOpeningId => SimpleOpeningOf<T>;
+
+        /// Next identifier for new simplified hiring mode application.
+        pub NextSimpleApplicationId get(fn next_simple_application_id): SimpleApplicationId;
+
+        /// Maps identifier to simplified hiring mode application.
+        pub SimpleApplicationById get(fn simple_application_by_id): map hasher(blake2_128_concat)
+            SimpleApplicationId => SimpleApplicationOf<T>;
+
+        /// Stake and reward terms of the workers hired in the simplified hiring mode.
+        pub SimpleWorkerTermsById get(fn simple_worker_terms): map hasher(blake2_128_concat)
+            WorkerId<T> => Option<SimpleWorkerTermsOf<T>>;
+
+        /// Stakes of the former simplified hiring mode workers in the unstaking period.
+        /// Required by StakingEventsHandler callback call to remove the stake on unstaking.
+        pub UnstakingSimpleStakes get(fn unstaking_simple_stakes): map hasher(blake2_128_concat)
+            StakeId<T> => ();
+
+        /// Scheduled worker exits indexed by the block number of the exit.
+        pub WorkerExitSchedule get(fn worker_exit_schedule): map hasher(blake2_128_concat)
+            T::BlockNumber => Vec<WorkerId<T>>;
+
+        /// Exit block number and rationale text of the worker leaving with notice.
+        pub WorkerExitNotices get(fn worker_exit_notice): map hasher(blake2_128_concat)
+            WorkerId<T> => Option<(T::BlockNumber, Vec<u8>)>;
+
+        /// Working group budget. Not set by default.
+        pub CurrentBudget get(fn budget): Option<BudgetOf<T>>;
+
+        /// Discretionary payment rationale text length limits.
+        pub DiscretionaryPaymentRationaleText get(fn discretionary_payment_rationale_text) :
+            InputValidationLengthConstraint = default_text_constraint();
+
+        /// Next identifier for new performance review.
+        pub NextPerformanceReviewId get(fn next_performance_review_id) : PerformanceReviewId;
+
+        /// Performance reviews of the workers. Kept after the worker leaves the role.
+        pub PerformanceReviews get(fn performance_review): double_map
+            hasher(blake2_128_concat) WorkerId<T>,
+            hasher(blake2_128_concat) PerformanceReviewId => PerformanceReviewOf<T>;
     }
         add_extra_genesis {
         config(phantom): sp_std::marker::PhantomData<I>;
@@ -374,6 +590,17 @@ decl_module! {
         /// Exports const -  max simultaneous active worker number.
         const MaxWorkerNumberLimit: u32 = T::MaxWorkerNumberLimit::get();
 
+        /// Exports const - reward payment period of the simplified hiring mode workers.
+        const RewardPeriod: u32 = T::RewardPeriod::get();
+
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            Self::maybe_start_new_budget_period(now);
+            Self::process_scheduled_worker_exits(now);
+            Self::maybe_pay_simple_rewards(now);
+
+            10_000_000 //TODO: adjust weight
+        }
+
         // ****************** Roles lifecycle **********************
 
         /// Update the associated role account of the active worker/lead.
@@ -459,13 +686,39 @@ decl_module! {
             Self::deposit_event(RawEvent::WorkerRewardAccountUpdated(worker_id, new_reward_account_id));
         }
 
+        /// Pay the rewards missed by the active worker because of the insufficient mint capacity.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn claim_missed_rewards(
+            origin,
+            worker_id: WorkerId<T>,
+        ) {
+            // Ensure there is a signer which matches role account of worker corresponding to provided id.
+            let worker = Self::ensure_worker_signed(origin, &worker_id)?;
+
+            // Ensure the worker actually has a recurring reward
+            let relationship_id = Self::ensure_worker_has_recurring_reward(&worker)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // Pay the owed reward from the relationship mint (working group mint).
+            let paid_amount = ensure_on_wrapped_error!(
+                recurringrewards::Module::<T>::pay_owed_reward(relationship_id)
+            )?;
+
+            // Trigger event
+            Self::deposit_event(RawEvent::MissedRewardsClaimed(worker_id, paid_amount));
+        }
+
         /// Update the reward amount associated with a set reward relationship for the active worker.
         /// Require signed leader origin or the root (to update leader reward amount).
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn update_reward_amount(
             origin,
             worker_id: WorkerId<T>,
-            new_amount: BalanceOfMint<T>
+            new_amount: BalanceOfMint<T>,
+            review_id: Option<PerformanceReviewId>,
         ) {
             // Ensure lead is set and is origin signer or it is the council.
             Self::ensure_origin_for_leader(origin, worker_id)?;
@@ -476,6 +729,8 @@ decl_module! {
             // Ensure the worker actually has a recurring reward
             let relationship_id = Self::ensure_worker_has_recurring_reward(&worker)?;
 
+            Self::ensure_performance_review_reference_is_valid(&worker_id, review_id)?;
+
             //
             // == MUTATION SAFE ==
             //
@@ -490,6 +745,12 @@ decl_module! {
                     None) //new_payout_interval
             )?;
 
+            Self::add_performance_review_action(
+                &worker_id,
+                review_id,
+                ReviewAction::RewardAmountUpdated(new_amount)
+            );
+
             // Trigger event
             Self::deposit_event(RawEvent::WorkerRewardAmountUpdated(worker_id));
         }
@@ -516,6 +777,41 @@ decl_module! {
             )?;
         }
 
+        /// Schedule leaving the role by the active worker. The worker keeps the role and the reward
+        /// until the exit block, giving the lead time to hire the replacement.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn leave_role_with_notice(
+            origin,
+            worker_id: WorkerId<T>,
+            exit_at: T::BlockNumber,
+            rationale_text: Vec<u8>
+        ) {
+            // Ensure there is a signer which matches role account of worker corresponding to provided id.
+            Self::ensure_worker_signed(origin, &worker_id)?;
+
+            ensure!(
+                !<WorkerExitNotices<T, I>>::contains_key(worker_id),
+                Error::<T, I>::WorkerExitAlreadyScheduled
+            );
+
+            ensure!(
+                exit_at > <frame_system::Module<T>>::block_number(),
+                Error::<T, I>::WorkerExitBlockMustBeInFuture
+            );
+
+            Self::ensure_worker_exit_rationale_text_is_valid(&rationale_text)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            <WorkerExitSchedule<T, I>>::mutate(exit_at, |worker_ids| worker_ids.push(worker_id));
+            <WorkerExitNotices<T, I>>::insert(worker_id, (exit_at, rationale_text));
+
+            // Trigger event
+            Self::deposit_event(RawEvent::WorkerExitScheduled(worker_id, exit_at));
+        }
+
         /// Terminate the active worker by the lead.
         /// Require signed leader origin or the root (to terminate the leader role).
         #[weight = 10_000_000] // TODO: adjust weight
@@ -541,7 +837,7 @@ decl_module! {
             //
 
             if slash_stake {
-                Self::slash_stake(cloned_origin2, worker_id, BalanceOf::<T>::max_value())?;
+                Self::slash_stake(cloned_origin2, worker_id, BalanceOf::<T>::max_value(), None)?;
             }
 
             Self::deactivate_worker(
@@ -552,6 +848,42 @@ decl_module! {
             )?;
         }
 
+        /// File a performance review of the active worker. Reward updates and slashes of the worker
+        /// can reference the review.
+        /// Require signed leader origin or the root (to review the leader).
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_performance_review(
+            origin,
+            worker_id: WorkerId<T>,
+            rating: PerformanceRating,
+            rationale_hash: T::Hash,
+        ) {
+            // Ensure lead is set and is origin signer or it is the council.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            // Ensuring worker actually exists
+            Self::ensure_worker_exists(&worker_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let review_id = Self::next_performance_review_id();
+
+            let review = PerformanceReview {
+                rating,
+                rationale_hash,
+                reviewed_at: <frame_system::Module<T>>::block_number(),
+                actions: Vec::new(),
+            };
+
+            PerformanceReviews::<T, I>::insert(worker_id, review_id, review);
+            NextPerformanceReviewId::<I>::put(review_id + 1);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::PerformanceReviewAdded(worker_id, review_id));
+        }
+
         // ****************** Hiring flow **********************
 
         /// Add an opening for a worker role.
@@ -826,159 +1158,503 @@ decl_module! {
             Self::deposit_event(RawEvent::BeganApplicationReview(opening_id));
         }
 
-        /// Fill opening for worker/lead.
-        /// Require signed leader origin or the root (to fill opening for the leader position).
+        /// Set the workers allowed to score the applications on the opening in addition to the lead.
+        /// Require signed leader origin or the root (to set the reviewers for the leader opening).
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn fill_opening(
+        pub fn set_opening_reviewers(
             origin,
             opening_id: OpeningId<T>,
-            successful_application_ids: ApplicationIdSet<T>,
-            reward_policy: Option<RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>>
+            reviewer_ids: BTreeSet<WorkerId<T>>
         ) {
             // Ensure worker opening exists
             let (opening, _) = Self::ensure_opening_exists(&opening_id)?;
 
             Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
 
-            let potential_worker_number =
-                Self::active_worker_count() + (successful_application_ids.len() as u32);
-
-            ensure!(
-                potential_worker_number <= T::MaxWorkerNumberLimit::get(),
-                Error::<T, I>::MaxActiveWorkerNumberExceeded
-            );
-
-            // Cannot hire a lead when another leader exists.
-            if matches!(opening.opening_type, OpeningType::Leader) {
-                ensure!(!<CurrentLead<T,I>>::exists(), Error::<T, I>::CannotHireLeaderWhenLeaderExists);
+            for reviewer_id in reviewer_ids.iter() {
+                Self::ensure_worker_exists(reviewer_id)?;
             }
 
-            // Ensure a mint exists if lead is providing a reward for positions being filled
-            let create_reward_settings = if let Some(policy) = reward_policy {
-
-                // A reward will need to be created so ensure our configured mint exists
-                let mint_id = Self::mint();
-
-                // Make sure valid parameters are selected for next payment at block number
-                ensure!(policy.next_payment_at_block > <frame_system::Module<T>>::block_number(),
-                    Error::<T, I>::FillOpeningInvalidNextPaymentBlock);
+            //
+            // == MUTATION SAFE ==
+            //
 
-                // The verified reward settings to use
-                Some((mint_id, policy))
-            } else {
-                None
-            };
+            <OpeningReviewers<T, I>>::insert(opening_id, reviewer_ids);
 
-            // Make iterator over successful worker application
-            let successful_iter = successful_application_ids
-                                    .iter()
-                                    // recover worker application from id
-                                    .map(|application_id| { Self::ensure_application_exists(application_id)})
-                                    // remove Err cases, i.e. non-existing applications
-                                    .filter_map(|result| result.ok());
+            // Trigger event
+            Self::deposit_event(RawEvent::OpeningReviewersSet(opening_id));
+        }
 
-            // Count number of successful workers provided
-            let num_provided_successful_application_ids = successful_application_ids.len();
+        /// Score the active application during the review period of the opening. Overwrites the
+        /// previous score of the reviewer. Require the lead or the opening reviewer origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn score_application(
+            origin,
+            reviewer_id: WorkerId<T>,
+            application_id: ApplicationId<T>,
+            score: u32,
+            comment: Vec<u8>
+        ) {
+            // Ensure there is a signer which matches role account of worker corresponding to provided id.
+            Self::ensure_worker_signed(origin, &reviewer_id)?;
 
-            // Ensure all worker applications exist
-            let number_of_successful_applications = successful_iter
-                                                    .clone()
-                                                    .count();
+            // Ensuring worker application actually exists
+            let (application, _, opening) = Self::ensure_application_exists(&application_id)?;
 
             ensure!(
-                number_of_successful_applications == num_provided_successful_application_ids,
-                Error::<T, I>::SuccessfulWorkerApplicationDoesNotExist
+                Self::current_lead() == Some(reviewer_id)
+                    || Self::opening_reviewers(application.opening_id).contains(&reviewer_id),
+                Error::<T, I>::WorkerIsNotApplicationReviewer
             );
 
-            // Attempt to fill opening
-            let successful_application_ids = successful_iter
-                                            .clone()
-                                            .map(|(successful_application, _, _)| successful_application.hiring_application_id)
-                                            .collect::<BTreeSet<_>>();
+            let hiring_opening = hiring::OpeningById::<T>::get(opening.hiring_opening_id);
+            ensure!(
+                matches!(
+                    hiring_opening.stage,
+                    hiring::OpeningStage::Active {
+                        stage: hiring::ActiveOpeningStage::ReviewPeriod { .. },
+                        ..
+                    }
+                ),
+                Error::<T, I>::OpeningIsNotInReviewPeriod
+            );
 
-            // Check for a single application for a leader.
-            if matches!(opening.opening_type, OpeningType::Leader) {
-                ensure!(successful_application_ids.len() == 1, Error::<T, I>::CannotHireMultipleLeaders);
-            }
+            let hiring_application = hiring::ApplicationById::<T>::get(application.hiring_application_id);
+            ensure!(
+                hiring_application.stage == hiring::ApplicationStage::Active,
+                Error::<T, I>::ApplicationIsNotActive
+            );
 
-            // NB: Combined ensure check and mutation in hiring module
-            ensure_on_wrapped_error!(
-                hiring::Module::<T>::fill_opening(
-                    opening.hiring_opening_id,
-                    successful_application_ids,
-                    opening.policy_commitment.fill_opening_successful_applicant_application_stake_unstaking_period,
-                    opening.policy_commitment.fill_opening_failed_applicant_application_stake_unstaking_period,
-                    opening.policy_commitment.fill_opening_failed_applicant_role_stake_unstaking_period
-                )
-            )?;
+            Self::ensure_application_review_comment_text_is_valid(&comment)?;
 
             //
             // == MUTATION SAFE ==
             //
 
-            // Process successful applications
-            let application_id_to_worker_id = Self::fulfill_successful_applications(
-                &opening,
-                create_reward_settings,
-                successful_iter.collect()
+            <ApplicationScores<T, I>>::insert(
+                application_id,
+                reviewer_id,
+                ApplicationScore {
+                    score,
+                    comment: comment.clone(),
+                }
             );
 
             // Trigger event
-            Self::deposit_event(RawEvent::OpeningFilled(opening_id, application_id_to_worker_id));
+            Self::deposit_event(RawEvent::ApplicationScored(application_id, reviewer_id, score, comment));
         }
 
-        // ****************** Stakes **********************
-
-        /// Slashes the worker stake, demands a leader origin. No limits, no actions on zero stake.
-        /// If slashing balance greater than the existing stake - stake is slashed to zero.
-        /// Require signed leader origin or the root (to slash the leader stake).
+        /// Fill opening for worker/lead.
+        /// Require signed leader origin or the root (to fill opening for the leader position).
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn slash_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
-            // Ensure lead is set or it is the council terminating the leader.
-            Self::ensure_origin_for_leader(origin, worker_id)?;
-
-            // Ensuring worker actually exists.
-            let worker = Self::ensure_worker_exists(&worker_id)?;
-
-            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
-
-            let stake_profile = worker.role_stake_profile.ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+        pub fn fill_opening(
+            origin,
+            opening_id: OpeningId<T>,
+            successful_application_ids: ApplicationIdSet<T>,
+            reward_policy: Option<RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>>
+        ) {
+            // Ensure worker opening exists
+            let (opening, _) = Self::ensure_opening_exists(&opening_id)?;
 
-            //
-            // == MUTATION SAFE ==
-            //
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
 
-            // This external module call both checks and mutates the state.
-            ensure_on_wrapped_error!(
-                <stake::Module<T>>::slash_immediate(
-                    &stake_profile.stake_id,
-                    balance,
-                    false
-                )
+            Self::fill_opening_with_applications(
+                opening_id,
+                opening,
+                successful_application_ids,
+                reward_policy
             )?;
-
-            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
         }
 
-        /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
-        /// Can be decreased to zero, no actions on zero stake.
-        /// Require signed leader origin or the root (to decrease the leader stake).
+        /// Fill opening for worker/lead with the applications having the highest average score.
+        /// Require signed leader origin or the root (to fill opening for the leader position).
         #[weight = 10_000_000] // TODO: adjust weight
-        pub fn decrease_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
-            // Ensure lead is set or it is the council terminating the leader.
-            Self::ensure_origin_for_leader(origin, worker_id)?;
+        pub fn fill_opening_with_top_scored_applications(
+            origin,
+            opening_id: OpeningId<T>,
+            number_of_applications: u32,
+            reward_policy: Option<RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>>
+        ) {
+            // Ensure worker opening exists
+            let (opening, _) = Self::ensure_opening_exists(&opening_id)?;
 
-            let worker = Self::ensure_worker_exists(&worker_id)?;
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
 
-            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
+            ensure!(
+                number_of_applications > 0,
+                Error::<T, I>::NumberOfApplicationsToSelectCannotBeZero
+            );
 
-            let stake_profile = worker.role_stake_profile.ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+            let ranked_applications = Self::get_ranked_applications(&opening_id);
 
-            //
-            // == MUTATION SAFE ==
-            //
+            ensure!(
+                ranked_applications.len() >= number_of_applications as usize,
+                Error::<T, I>::NotEnoughScoredApplications
+            );
 
-            // This external module call both checks and mutates the state.
+            let successful_application_ids = ranked_applications
+                .into_iter()
+                .take(number_of_applications as usize)
+                .map(|(application_id, _)| application_id)
+                .collect::<ApplicationIdSet<T>>();
+
+            Self::fill_opening_with_applications(
+                opening_id,
+                opening,
+                successful_application_ids,
+                reward_policy
+            )?;
+        }
+
+        // ****************** Simplified hiring flow **********************
+
+        /// Add a simplified hiring mode opening for a worker/lead role.
+        /// Require signed leader origin or the root (to add opening for the leader position).
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn add_simple_opening(
+            origin,
+            description: Vec<u8>,
+            opening_type: OpeningType,
+            stake_policy: Option<StakePolicy<T::BlockNumber, BalanceOf<T>>>,
+            reward_per_block: Option<BalanceOfMint<T>>,
+        ) {
+            Self::ensure_origin_for_opening_type(origin, opening_type)?;
+
+            Self::ensure_opening_human_readable_text_is_valid(&description)?;
+
+            if let Some(ref stake_policy) = stake_policy {
+                ensure!(
+                    stake_policy.stake_amount != <BalanceOf<T>>::zero(),
+                    Error::<T, I>::StakeBalanceCannotBeZero
+                );
+
+                ensure!(
+                    stake_policy.stake_amount >= CurrencyOf::<T>::minimum_balance(),
+                    Error::<T, I>::AddWorkerOpeningRoleStakeLessThanMinimum
+                );
+
+                ensure!(
+                    stake_policy.unstaking_period != Zero::zero(),
+                    Error::<T, I>::StakePolicyUnstakingPeriodIsZero
+                );
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let new_opening_id = NextSimpleOpeningId::<I>::get();
+
+            let new_opening = SimpleOpening {
+                opening_type,
+                created: <frame_system::Module<T>>::block_number(),
+                description,
+                stake_policy,
+                reward_per_block,
+                applications: BTreeSet::new(),
+            };
+
+            SimpleOpeningById::<T, I>::insert(new_opening_id, new_opening);
+
+            NextSimpleOpeningId::<I>::mutate(|id| *id += 1);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::SimpleOpeningAdded(new_opening_id));
+        }
+
+        /// Apply on a simplified hiring mode opening. The stake is locked on the origin account.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn apply_on_simple_opening(
+            origin,
+            member_id: T::MemberId,
+            opening_id: SimpleOpeningId,
+            role_account_id: T::AccountId,
+            reward_account_id: T::AccountId,
+            description: Vec<u8>
+        ) {
+            // Ensure origin which will serve as the staking account is signed
+            let staking_account_id = ensure_signed(origin)?;
+
+            // Ensure the staking account is either the controller or root account of member with given id
+            ensure!(
+                membership::Module::<T>::ensure_member_controller_account(&staking_account_id, &member_id).is_ok() ||
+                membership::Module::<T>::ensure_member_root_account(&staking_account_id, &member_id).is_ok(),
+                Error::<T, I>::OriginIsNeitherMemberControllerOrRoot
+            );
+
+            let opening = Self::ensure_simple_opening_exists(&opening_id)?;
+
+            Self::ensure_application_text_is_valid(&description)?;
+
+            // Ensure member does not have an application to this opening
+            ensure!(
+                opening.applications.iter().all(|application_id| {
+                    SimpleApplicationById::<T, I>::get(application_id).member_id != member_id
+                }),
+                Error::<T, I>::MemberHasActiveApplicationOnOpening
+            );
+
+            // Ensure that there is sufficient balance to cover the stake
+            if let Some(ref stake_policy) = opening.stake_policy {
+                let total_stake = stake::Module::<T>::locked_balance(
+                    T::ApplicationLockId::get(),
+                    &staking_account_id,
+                )
+                .saturating_add(stake_policy.stake_amount);
+
+                ensure!(
+                    CurrencyOf::<T>::free_balance(&staking_account_id) >= total_stake,
+                    Error::<T, I>::InsufficientBalanceToApply
+                );
+
+                // Ensure the stake can be locked, so no stake is created in vain after the mutation
+                ensure_on_wrapped_error!(stake::Module::<T>::ensure_can_stake_with_lock(
+                    &staking_account_id,
+                    stake_policy.stake_amount,
+                    T::ApplicationLockId::get()
+                ))?;
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let stake_id = if let Some(ref stake_policy) = opening.stake_policy {
+                let stake_id = stake::Module::<T>::create_stake();
+
+                ensure_on_wrapped_error!(stake::Module::<T>::stake_with_lock(
+                    &stake_id,
+                    &staking_account_id,
+                    stake_policy.stake_amount,
+                    T::ApplicationLockId::get()
+                ))?;
+
+                Some(stake_id)
+            } else {
+                None
+            };
+
+            let new_application_id = NextSimpleApplicationId::<I>::get();
+
+            let new_application = SimpleApplication {
+                role_account_id,
+                reward_account_id,
+                staking_account_id,
+                stake_id,
+                member_id,
+                opening_id,
+                description,
+            };
+
+            SimpleApplicationById::<T, I>::insert(new_application_id, new_application);
+
+            SimpleOpeningById::<T, I>::mutate(opening_id, |opening| {
+                opening.applications.insert(new_application_id);
+            });
+
+            NextSimpleApplicationId::<I>::mutate(|id| *id += 1);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::AppliedOnSimpleOpening(opening_id, new_application_id));
+        }
+
+        /// Withdraw the simplified hiring mode application. Unlocks the stake immediately.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn withdraw_simple_application(
+            origin,
+            application_id: SimpleApplicationId
+        ) {
+            // Ensuring application actually exists
+            let application = Self::ensure_simple_application_exists(&application_id)?;
+
+            // Ensure that it is signed
+            let signer_account = ensure_signed(origin)?;
+
+            // Ensure that signer is applicant role account
+            ensure!(
+                signer_account == application.role_account_id,
+                Error::<T, I>::OriginIsNotApplicant
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            Self::remove_simple_application(&application_id);
+
+            SimpleOpeningById::<T, I>::mutate(application.opening_id, |opening| {
+                opening.applications.remove(&application_id);
+            });
+
+            // Trigger event
+            Self::deposit_event(RawEvent::SimpleApplicationWithdrawn(application_id));
+        }
+
+        /// Fill the simplified hiring mode opening for worker/lead. Stakes of the hired applicants
+        /// are moved to the worker stake lock, stakes of the unsuccessful applicants are unlocked
+        /// immediately, the opening and its applications are removed.
+        /// Require signed leader origin or the root (to fill opening for the leader position).
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn fill_simple_opening(
+            origin,
+            opening_id: SimpleOpeningId,
+            successful_application_ids: BTreeSet<SimpleApplicationId>
+        ) {
+            let opening = Self::ensure_simple_opening_exists(&opening_id)?;
+
+            Self::ensure_origin_for_opening_type(origin, opening.opening_type)?;
+
+            ensure!(
+                successful_application_ids.is_subset(&opening.applications),
+                Error::<T, I>::SuccessfulWorkerApplicationDoesNotExist
+            );
+
+            let potential_worker_number =
+                Self::active_worker_count() + (successful_application_ids.len() as u32);
+
+            ensure!(
+                potential_worker_number <= T::MaxWorkerNumberLimit::get(),
+                Error::<T, I>::MaxActiveWorkerNumberExceeded
+            );
+
+            // Check for a single application for a leader when no other leader exists.
+            if matches!(opening.opening_type, OpeningType::Leader) {
+                ensure!(!<CurrentLead<T,I>>::exists(), Error::<T, I>::CannotHireLeaderWhenLeaderExists);
+                ensure!(successful_application_ids.len() == 1, Error::<T, I>::CannotHireMultipleLeaders);
+            }
+
+            // Ensure the stakes of the hired applicants can be moved to the worker stake lock.
+            if let Some(ref stake_policy) = opening.stake_policy {
+                let mut worker_stakes = BTreeMap::new();
+
+                for application_id in successful_application_ids.iter() {
+                    let application = SimpleApplicationById::<T, I>::get(application_id);
+
+                    let total_stake: &mut BalanceOf<T> = worker_stakes
+                        .entry(application.staking_account_id)
+                        .or_insert_with(Zero::zero);
+                    *total_stake = total_stake.saturating_add(stake_policy.stake_amount);
+                }
+
+                for (staking_account_id, total_stake) in worker_stakes {
+                    ensure_on_wrapped_error!(stake::Module::<T>::ensure_can_stake_with_lock(
+                        &staking_account_id,
+                        total_stake,
+                        T::StakingLockId::get()
+                    ))?;
+                }
+            }
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let mut application_id_to_worker_id = BTreeMap::new();
+
+            for application_id in successful_application_ids.iter() {
+                let application = SimpleApplicationById::<T, I>::take(application_id);
+
+                let new_worker_id = Self::add_simple_worker(&opening, application);
+
+                application_id_to_worker_id.insert(*application_id, new_worker_id);
+            }
+
+            // Unlock the stakes of the unsuccessful applicants.
+            for application_id in opening.applications.difference(&successful_application_ids) {
+                Self::remove_simple_application(application_id);
+            }
+
+            SimpleOpeningById::<T, I>::remove(opening_id);
+
+            // Trigger event
+            Self::deposit_event(RawEvent::SimpleOpeningFilled(opening_id, application_id_to_worker_id));
+        }
+
+        // ****************** Stakes **********************
+
+        /// Slashes the worker stake, demands a leader origin. No limits, no actions on zero stake.
+        /// If slashing balance greater than the existing stake - stake is slashed to zero.
+        /// Require signed leader origin or the root (to slash the leader stake).
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn slash_stake(
+            origin,
+            worker_id: WorkerId<T>,
+            balance: BalanceOf<T>,
+            review_id: Option<PerformanceReviewId>,
+        ) {
+            // Ensure lead is set or it is the council terminating the leader.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            // Ensuring worker actually exists.
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
+
+            // Workers hired in the simplified hiring mode have the stake locked on the staking account.
+            let simple_worker_stake_id =
+                Self::simple_worker_terms(worker_id).and_then(|terms| terms.stake_id);
+
+            ensure!(
+                worker.role_stake_profile.is_some() || simple_worker_stake_id.is_some(),
+                Error::<T, I>::NoWorkerStakeProfile
+            );
+
+            Self::ensure_performance_review_reference_is_valid(&worker_id, review_id)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            let stake_id = simple_worker_stake_id.or_else(|| {
+                worker
+                    .role_stake_profile
+                    .as_ref()
+                    .map(|stake_profile| stake_profile.stake_id)
+            });
+
+            let actually_slashed = if let Some(stake_id) = stake_id {
+                // This external module call both checks and mutates the state.
+                let slash_outcome = ensure_on_wrapped_error!(
+                    <stake::Module<T>>::slash_immediate(
+                        &stake_id,
+                        balance,
+                        false
+                    )
+                )?;
+
+                slash_outcome.actually_slashed
+            } else {
+                Zero::zero()
+            };
+
+            Self::add_performance_review_action(
+                &worker_id,
+                review_id,
+                ReviewAction::StakeSlashed(actually_slashed)
+            );
+
+            Self::deposit_event(RawEvent::StakeSlashed(worker_id));
+        }
+
+        /// Decreases the worker/lead stake and returns the remainder to the worker role_account_id.
+        /// Can be decreased to zero, no actions on zero stake.
+        /// Require signed leader origin or the root (to decrease the leader stake).
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn decrease_stake(origin, worker_id: WorkerId<T>, balance: BalanceOf<T>) {
+            // Ensure lead is set or it is the council terminating the leader.
+            Self::ensure_origin_for_leader(origin, worker_id)?;
+
+            let worker = Self::ensure_worker_exists(&worker_id)?;
+
+            ensure!(balance != <BalanceOf<T>>::zero(), Error::<T, I>::StakeBalanceCannotBeZero);
+
+            let stake_profile = worker.role_stake_profile.ok_or(Error::<T, I>::NoWorkerStakeProfile)?;
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            // This external module call both checks and mutates the state.
             ensure_on_wrapped_error!(
                 <stake::Module<T>>::decrease_stake_to_account(
                     &stake_profile.stake_id,
@@ -1017,7 +1693,8 @@ decl_module! {
             Self::deposit_event(RawEvent::StakeIncreased(worker_id));
         }
 
-        /// Sets the capacity to enable working group budget. Requires root origin.
+        /// Sets the capacity to enable working group budget. When the budget is set, the capacity
+        /// also becomes its spending ceiling, so the next budget periods keep it. Requires root origin.
         #[weight = 10_000_000] // TODO: adjust weight
         pub fn set_mint_capacity(
             origin,
@@ -1046,6 +1723,87 @@ decl_module! {
 
                 Self::deposit_event(RawEvent::MintCapacityChanged(mint_id, new_capacity));
             }
+
+            // The budget period start renews the mint capacity to the spending ceiling.
+            if let Some(mut budget) = Self::budget() {
+                budget.spending_ceiling = new_capacity;
+
+                <CurrentBudget<T, I>>::put(budget);
+            }
+        }
+
+        /// Sets the working group budget: the spending ceiling for the rewards and the discretionary
+        /// payments per period. Starts a new budget period. The mint capacity is set to the spending
+        /// ceiling at the start of each period. Requires root origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn set_budget(
+            origin,
+            spending_ceiling: minting::BalanceOf<T>,
+            period_length: T::BlockNumber,
+        ) {
+            ensure_root(origin)?;
+
+            ensure!(period_length != Zero::zero(), Error::<T, I>::BudgetPeriodLengthCannotBeZero);
+
+            // Technically this is a bug-check and should not be here.
+            ensure!(<minting::Mints<T>>::contains_key(Self::mint()), Error::<T, I>::CannotFindMint);
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ensure_on_wrapped_error!(
+                Self::start_budget_period(
+                    spending_ceiling,
+                    period_length,
+                    <frame_system::Module<T>>::block_number()
+                )
+            )?;
+
+            Self::deposit_event(RawEvent::BudgetSet(spending_ceiling, period_length));
+        }
+
+        /// Pays the amount from the working group budget to the recipient account (eg.: for the bounties
+        /// or one-off work). Require signed leader origin.
+        #[weight = 10_000_000] // TODO: adjust weight
+        pub fn make_discretionary_payment(
+            origin,
+            recipient_account_id: T::AccountId,
+            amount: minting::BalanceOf<T>,
+            rationale_text: Vec<u8>,
+        ) {
+            // Ensure lead is set and is origin signer.
+            Self::ensure_origin_is_active_leader(origin)?;
+
+            ensure!(amount != Zero::zero(), Error::<T, I>::DiscretionaryPaymentAmountCannotBeZero);
+
+            Self::ensure_discretionary_payment_rationale_text_is_valid(&rationale_text)?;
+
+            let budget = Self::budget().ok_or(Error::<T, I>::BudgetNotSet)?;
+
+            ensure!(
+                Self::remaining_budget(&budget) >= amount,
+                Error::<T, I>::InsufficientBudget
+            );
+
+            //
+            // == MUTATION SAFE ==
+            //
+
+            ensure_on_wrapped_error!(
+                <minting::Module<T>>::transfer_tokens(Self::mint(), amount, &recipient_account_id)
+            )?;
+
+            <CurrentBudget<T, I>>::put(Budget {
+                discretionary_spending: budget.discretionary_spending + amount,
+                ..budget
+            });
+
+            Self::deposit_event(RawEvent::DiscretionaryPaymentMade(
+                recipient_account_id,
+                amount,
+                rationale_text
+            ));
         }
     }
 }
@@ -1136,14 +1894,11 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             Error::<T, I>::ApplicationStakingPolicyReviewPeriodUnstakingPeriodIsZero,
         )?;
 
-        if let Some(application_rationing_policy) =
-            policy_commitment.application_rationing_policy.clone()
-        {
-            ensure!(
-                application_rationing_policy.max_active_applicants > 0,
-                Error::<T, I>::ApplicationRationingPolicyMaxActiveApplicantsIsZero
-            );
-        }
+        // Applications are ranked by the review scores instead of being rationed by the stake.
+        ensure!(
+            policy_commitment.application_rationing_policy.is_none(),
+            Error::<T, I>::ApplicationRationingPolicyIsNotSupported
+        );
 
         Ok(())
     }
@@ -1235,6 +1990,28 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         Ok((opening, hiring_opening))
     }
 
+    fn ensure_simple_opening_exists(
+        opening_id: &SimpleOpeningId,
+    ) -> Result<SimpleOpeningOf<T>, Error<T, I>> {
+        ensure!(
+            SimpleOpeningById::<T, I>::contains_key(opening_id),
+            Error::<T, I>::SimpleOpeningDoesNotExist
+        );
+
+        Ok(SimpleOpeningById::<T, I>::get(opening_id))
+    }
+
+    fn ensure_simple_application_exists(
+        application_id: &SimpleApplicationId,
+    ) -> Result<SimpleApplicationOf<T>, Error<T, I>> {
+        ensure!(
+            SimpleApplicationById::<T, I>::contains_key(application_id),
+            Error::<T, I>::SimpleApplicationDoesNotExist
+        );
+
+        Ok(SimpleApplicationById::<T, I>::get(application_id))
+    }
+
     fn ensure_member_has_no_active_application_on_opening(
         applications: ApplicationIdSet<T>,
         member_id: T::MemberId,
@@ -1364,6 +2141,40 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         }
     }
 
+    fn ensure_performance_review_reference_is_valid(
+        worker_id: &WorkerId<T>,
+        review_id: Option<PerformanceReviewId>,
+    ) -> Result<(), Error<T, I>> {
+        if let Some(review_id) = review_id {
+            ensure!(
+                PerformanceReviews::<T, I>::contains_key(worker_id, review_id),
+                Error::<T, I>::PerformanceReviewDoesNotExist
+            );
+        }
+
+        Ok(())
+    }
+
+    fn ensure_discretionary_payment_rationale_text_is_valid(text: &[u8]) -> DispatchResult {
+        Self::discretionary_payment_rationale_text()
+            .ensure_valid(
+                text.len(),
+                Error::<T, I>::DiscretionaryPaymentRationaleTextTooShort.into(),
+                Error::<T, I>::DiscretionaryPaymentRationaleTextTooLong.into(),
+            )
+            .map_err(|e| DispatchError::Other(e))
+    }
+
+    fn ensure_application_review_comment_text_is_valid(text: &[u8]) -> DispatchResult {
+        Self::application_review_comment_text()
+            .ensure_valid(
+                text.len(),
+                Error::<T, I>::ApplicationReviewCommentTextTooShort.into(),
+                Error::<T, I>::ApplicationReviewCommentTextTooLong.into(),
+            )
+            .map_err(|e| DispatchError::Other(e))
+    }
+
     fn ensure_worker_exit_rationale_text_is_valid(text: &[u8]) -> DispatchResult {
         Self::worker_exit_rationale_text()
             .ensure_valid(
@@ -1394,6 +2205,19 @@ pub fn default_storage_size_constraint() -> u16 {
 }
 
 impl<T: Trait<I>, I: Instance> Module<T, I> {
+    /// Callback from StakingEventsHandler. Removes the unstaked stake of the former worker hired in
+    /// the simplified hiring mode.
+    pub fn remove_unstaked_simple_stake(stake_id: StakeId<T>) {
+        if !<UnstakingSimpleStakes<T, I>>::contains_key(stake_id) {
+            // Stake is not related to the simplified hiring mode.
+            return;
+        }
+
+        <UnstakingSimpleStakes<T, I>>::remove(stake_id);
+
+        Self::remove_simple_stake(&stake_id);
+    }
+
     /// Callback from StakingEventsHandler. Refunds unstaked imbalance back to the source account.
     pub fn refund_working_group_stake(
         stake_id: StakeId<T>,
@@ -1433,6 +2257,136 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         <NegativeImbalance<T>>::zero()
     }
 
+    /// Returns the spending breakdown of the current budget period: the reward spending and
+    /// the discretionary spending. Returns None if the budget is not set.
+    pub fn get_budget_spending() -> Option<(BalanceOfMint<T>, BalanceOfMint<T>)> {
+        Self::budget().map(|budget| {
+            let reward_spending =
+                Self::period_spending(&budget).saturating_sub(budget.discretionary_spending);
+
+            (reward_spending, budget.discretionary_spending)
+        })
+    }
+
+    // Total amount minted by the working group mint in the current budget period.
+    fn period_spending(budget: &BudgetOf<T>) -> BalanceOfMint<T> {
+        <minting::Module<T>>::mints(Self::mint())
+            .total_minted()
+            .saturating_sub(budget.minted_before_period)
+    }
+
+    // Remaining spending in the current budget period.
+    fn remaining_budget(budget: &BudgetOf<T>) -> BalanceOfMint<T> {
+        budget
+            .spending_ceiling
+            .saturating_sub(Self::period_spending(budget))
+    }
+
+    // Resets the period spending and sets the mint capacity to the spending ceiling.
+    fn start_budget_period(
+        spending_ceiling: BalanceOfMint<T>,
+        period_length: T::BlockNumber,
+        now: T::BlockNumber,
+    ) -> Result<(), minting::GeneralError> {
+        let mint_id = Self::mint();
+
+        <minting::Module<T>>::set_mint_capacity(mint_id, spending_ceiling)?;
+
+        <CurrentBudget<T, I>>::put(Budget {
+            spending_ceiling,
+            period_length,
+            period_started_at: now,
+            minted_before_period: <minting::Module<T>>::mints(mint_id).total_minted(),
+            discretionary_spending: Zero::zero(),
+        });
+
+        Ok(())
+    }
+
+    // Reports the spending of the ended budget period and starts the next one.
+    fn maybe_start_new_budget_period(now: T::BlockNumber) {
+        if let Some(budget) = Self::budget() {
+            if now < budget.period_started_at + budget.period_length {
+                return;
+            }
+
+            // The spending is read before the next period resets it.
+            let budget_spending = Self::get_budget_spending();
+
+            if Self::start_budget_period(budget.spending_ceiling, budget.period_length, now)
+                .is_err()
+            {
+                print("Working group broken invariant: cannot find the mint.");
+            }
+
+            if let Some((reward_spending, discretionary_spending)) = budget_spending {
+                Self::deposit_event(RawEvent::BudgetPeriodEnded(
+                    reward_spending,
+                    discretionary_spending,
+                ));
+            }
+        }
+    }
+
+    /// Returns the performance reviews of the worker with the referencing actions, ordered by
+    /// the filing time.
+    pub fn get_worker_performance_history(
+        worker_id: &WorkerId<T>,
+    ) -> Vec<(PerformanceReviewId, PerformanceReviewOf<T>)> {
+        let mut reviews = <PerformanceReviews<T, I>>::iter_prefix(worker_id).collect::<Vec<_>>();
+
+        reviews.sort_by_key(|(review_id, _)| *review_id);
+
+        reviews
+    }
+
+    // Records the action in the referenced performance review.
+    fn add_performance_review_action(
+        worker_id: &WorkerId<T>,
+        review_id: Option<PerformanceReviewId>,
+        action: ReviewAction<BalanceOf<T>, BalanceOfMint<T>>,
+    ) {
+        if let Some(review_id) = review_id {
+            <PerformanceReviews<T, I>>::mutate(worker_id, review_id, |review| {
+                review.actions.push(action)
+            });
+        }
+    }
+
+    /// Returns the active scored applications on the opening with their average scores, ordered
+    /// by the average score (the highest first) and then by the application id.
+    pub fn get_ranked_applications(opening_id: &OpeningId<T>) -> Vec<(ApplicationId<T>, u32)> {
+        let mut ranked_applications = <OpeningById<T, I>>::get(opening_id)
+            .applications
+            .into_iter()
+            .filter(|application_id| {
+                let application = <ApplicationById<T, I>>::get(application_id);
+                let hiring_application =
+                    hiring::ApplicationById::<T>::get(application.hiring_application_id);
+
+                hiring_application.stage == hiring::ApplicationStage::Active
+            })
+            .filter_map(|application_id| {
+                let scores = <ApplicationScores<T, I>>::iter_prefix(application_id)
+                    .map(|(_, application_score)| application_score.score as u64)
+                    .collect::<Vec<_>>();
+
+                if scores.is_empty() {
+                    return None;
+                }
+
+                let average_score = scores.iter().sum::<u64>() / scores.len() as u64;
+
+                Some((application_id, average_score as u32))
+            })
+            .collect::<Vec<_>>();
+
+        ranked_applications
+            .sort_by(|(id1, score1), (id2, score2)| score2.cmp(score1).then_with(|| id1.cmp(id2)));
+
+        ranked_applications
+    }
+
     /// Returns all existing worker id list excluding the current leader worker id.
     pub fn get_regular_worker_ids() -> Vec<WorkerId<T>> {
         let lead_worker_id = Self::current_lead();
@@ -1480,6 +2434,41 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         }
     }
 
+    // Deactivates the workers whose exit notice period ends at the given block.
+    fn process_scheduled_worker_exits(now: T::BlockNumber) {
+        for worker_id in <WorkerExitSchedule<T, I>>::take(now) {
+            // The notice is removed on the worker deactivation before the exit block.
+            if let Some((_, rationale_text)) = <WorkerExitNotices<T, I>>::take(worker_id) {
+                let worker = Self::worker_by_id(worker_id);
+
+                if Self::deactivate_worker(
+                    &worker_id,
+                    &worker,
+                    &ExitInitiationOrigin::Worker,
+                    &rationale_text,
+                )
+                .is_err()
+                {
+                    print("Working group broken invariant: cannot deactivate the leaving worker.");
+                }
+            }
+        }
+    }
+
+    // Removes the scheduled exit of the worker, if any.
+    fn remove_worker_exit_notice(worker_id: &WorkerId<T>) {
+        if let Some((exit_at, _)) = <WorkerExitNotices<T, I>>::take(worker_id) {
+            let mut worker_ids = <WorkerExitSchedule<T, I>>::get(exit_at);
+            worker_ids.retain(|id| id != worker_id);
+
+            if worker_ids.is_empty() {
+                <WorkerExitSchedule<T, I>>::remove(exit_at);
+            } else {
+                <WorkerExitSchedule<T, I>>::insert(exit_at, worker_ids);
+            }
+        }
+    }
+
     fn deactivate_worker(
         worker_id: &WorkerId<T>,
         worker: &WorkerOf<T>,
@@ -1489,6 +2478,14 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         // Stop any possible recurring rewards
 
         if let Some(reward_relationship_id) = worker.reward_relationship {
+            // Pay the missed rewards. The unpaid rest stays claimable from the reward account with
+            // the recurring reward module claim_owed_reward(), as the relationship is only deactivated.
+            if let Ok(paid_amount) =
+                recurringrewards::Module::<T>::pay_owed_reward(reward_relationship_id)
+            {
+                Self::deposit_event(RawEvent::MissedRewardsClaimed(*worker_id, paid_amount));
+            }
+
             // Attempt to deactivate
             recurringrewards::Module::<T>::try_to_deactivate_relationship(reward_relationship_id)
                 .map_err(|_| Error::<T, I>::RelationshipMustExist)?;
@@ -1510,6 +2507,23 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
             ))?;
         }
 
+        if let Some(mut terms) = <SimpleWorkerTermsById<T, I>>::take(worker_id) {
+            // Pay the reward for the worked blocks and the missed reward, they cannot be paid
+            // after the exit.
+            Self::pay_simple_reward(worker_id, &mut terms);
+
+            // Unlock the simplified hiring mode stake after the unstaking period. The stake is
+            // removed by the StakingEventsHandler callback once unstaked.
+            if let Some(ref stake_id) = terms.stake_id {
+                ensure_on_wrapped_error!(stake::Module::<T>::initiate_unstaking(
+                    stake_id,
+                    Some(terms.unstaking_period)
+                ))?;
+
+                <UnstakingSimpleStakes<T, I>>::insert(stake_id, ());
+            }
+        }
+
         // Unset lead if the leader is leaving.
         let leader_worker_id = <CurrentLead<T, I>>::get();
         if let Some(leader_worker_id) = leader_worker_id {
@@ -1520,6 +2534,7 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
 
         // Remove the worker from the storage.
         WorkerById::<T, I>::remove(worker_id);
+        Self::remove_worker_exit_notice(worker_id);
         Self::decrease_active_worker_counter();
 
         // Trigger the event
@@ -1590,6 +2605,114 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         }
     }
 
+    // Fills the opening with the provided applications. Origin must be checked by the caller.
+    fn fill_opening_with_applications(
+        opening_id: OpeningId<T>,
+        opening: OpeningOf<T>,
+        successful_application_ids: ApplicationIdSet<T>,
+        reward_policy: Option<RewardPolicy<minting::BalanceOf<T>, T::BlockNumber>>,
+    ) -> DispatchResult {
+        let potential_worker_number =
+            Self::active_worker_count() + (successful_application_ids.len() as u32);
+
+        ensure!(
+            potential_worker_number <= T::MaxWorkerNumberLimit::get(),
+            Error::<T, I>::MaxActiveWorkerNumberExceeded
+        );
+
+        // Cannot hire a lead when another leader exists.
+        if matches!(opening.opening_type, OpeningType::Leader) {
+            ensure!(
+                !<CurrentLead<T, I>>::exists(),
+                Error::<T, I>::CannotHireLeaderWhenLeaderExists
+            );
+        }
+
+        // Ensure a mint exists if lead is providing a reward for positions being filled
+        let create_reward_settings = if let Some(policy) = reward_policy {
+            // A reward will need to be created so ensure our configured mint exists
+            let mint_id = Self::mint();
+
+            // Make sure valid parameters are selected for next payment at block number
+            ensure!(
+                policy.next_payment_at_block > <frame_system::Module<T>>::block_number(),
+                Error::<T, I>::FillOpeningInvalidNextPaymentBlock
+            );
+
+            // The verified reward settings to use
+            Some((mint_id, policy))
+        } else {
+            None
+        };
+
+        // Make iterator over successful worker application
+        let successful_iter = successful_application_ids
+            .iter()
+            // recover worker application from id
+            .map(|application_id| Self::ensure_application_exists(application_id))
+            // remove Err cases, i.e. non-existing applications
+            .filter_map(|result| result.ok());
+
+        // Count number of successful workers provided
+        let num_provided_successful_application_ids = successful_application_ids.len();
+
+        // Ensure all worker applications exist
+        let number_of_successful_applications = successful_iter.clone().count();
+
+        ensure!(
+            number_of_successful_applications == num_provided_successful_application_ids,
+            Error::<T, I>::SuccessfulWorkerApplicationDoesNotExist
+        );
+
+        // Attempt to fill opening
+        let successful_application_ids = successful_iter
+            .clone()
+            .map(|(successful_application, _, _)| successful_application.hiring_application_id)
+            .collect::<BTreeSet<_>>();
+
+        // Check for a single application for a leader.
+        if matches!(opening.opening_type, OpeningType::Leader) {
+            ensure!(
+                successful_application_ids.len() == 1,
+                Error::<T, I>::CannotHireMultipleLeaders
+            );
+        }
+
+        // NB: Combined ensure check and mutation in hiring module
+        ensure_on_wrapped_error!(hiring::Module::<T>::fill_opening(
+            opening.hiring_opening_id,
+            successful_application_ids,
+            opening
+                .policy_commitment
+                .fill_opening_successful_applicant_application_stake_unstaking_period,
+            opening
+                .policy_commitment
+                .fill_opening_failed_applicant_application_stake_unstaking_period,
+            opening
+                .policy_commitment
+                .fill_opening_failed_applicant_role_stake_unstaking_period
+        ))?;
+
+        //
+        // == MUTATION SAFE ==
+        //
+
+        // Process successful applications
+        let application_id_to_worker_id = Self::fulfill_successful_applications(
+            &opening,
+            create_reward_settings,
+            successful_iter.collect(),
+        );
+
+        // Trigger event
+        Self::deposit_event(RawEvent::OpeningFilled(
+            opening_id,
+            application_id_to_worker_id,
+        ));
+
+        Ok(())
+    }
+
     // Processes successful application during the fill_opening().
     fn fulfill_successful_applications(
         opening: &OpeningOf<T>,
@@ -1675,6 +2798,134 @@ impl<T: Trait<I>, I: Instance> Module<T, I> {
         application_id_to_worker_id
     }
 
+    // Creates the worker from the successful simplified hiring mode application.
+    fn add_simple_worker(
+        opening: &SimpleOpeningOf<T>,
+        application: SimpleApplicationOf<T>,
+    ) -> WorkerId<T> {
+        let new_worker_id = <NextWorkerId<T, I>>::get();
+
+        let worker = Worker::new(
+            &application.member_id,
+            &application.role_account_id,
+            &None,
+            &None,
+        );
+
+        <WorkerById<T, I>>::insert(new_worker_id, worker);
+        Self::increase_active_worker_counter();
+
+        <NextWorkerId<T, I>>::mutate(|id| *id += <WorkerId<T> as One>::one());
+
+        // The application stake becomes the worker stake.
+        if let Some(ref stake_id) = application.stake_id {
+            if stake::Module::<T>::change_stake_lock(stake_id, T::StakingLockId::get()).is_err() {
+                print("Working group broken invariant: cannot move the application stake lock.");
+            }
+        }
+
+        let unstaking_period = opening
+            .stake_policy
+            .as_ref()
+            .map_or(Zero::zero(), |stake_policy| stake_policy.unstaking_period);
+
+        <SimpleWorkerTermsById<T, I>>::insert(
+            new_worker_id,
+            SimpleWorkerTerms {
+                staking_account_id: application.staking_account_id,
+                stake_id: application.stake_id,
+                unstaking_period,
+                reward_account_id: application.reward_account_id,
+                reward_per_block: opening.reward_per_block,
+                last_reward_at: <frame_system::Module<T>>::block_number(),
+                missed_reward: Zero::zero(),
+            },
+        );
+
+        // Sets a leader on successful opening when opening is for leader.
+        if matches!(opening.opening_type, OpeningType::Leader) {
+            Self::set_lead(new_worker_id);
+        }
+
+        new_worker_id
+    }
+
+    // Removes the simplified hiring mode application, unlocks and removes its stake immediately.
+    fn remove_simple_application(application_id: &SimpleApplicationId) {
+        let application = SimpleApplicationById::<T, I>::take(application_id);
+
+        if let Some(ref stake_id) = application.stake_id {
+            if stake::Module::<T>::initiate_unstaking(stake_id, None).is_err() {
+                print("Working group broken invariant: cannot unstake the application stake.");
+                return;
+            }
+
+            Self::remove_simple_stake(stake_id);
+        }
+    }
+
+    // Removes the unstaked simplified hiring mode stake from the stake module.
+    fn remove_simple_stake(stake_id: &StakeId<T>) {
+        if stake::Module::<T>::remove_stake(stake_id).is_err() {
+            print("Working group broken invariant: cannot remove the unstaked stake.");
+        }
+    }
+
+    // Pays the rewards of the simplified hiring mode workers from the working group mint at the
+    // end of the reward period. Unpaid rewards are added to the missed reward of the worker.
+    fn maybe_pay_simple_rewards(now: T::BlockNumber) {
+        let reward_period: T::BlockNumber = T::RewardPeriod::get().into();
+
+        if reward_period.is_zero() || !(now % reward_period).is_zero() {
+            return;
+        }
+
+        let workers_terms = <SimpleWorkerTermsById<T, I>>::iter().collect::<Vec<_>>();
+
+        for (worker_id, mut terms) in workers_terms {
+            if terms.reward_per_block.is_some() {
+                Self::pay_simple_reward(&worker_id, &mut terms);
+
+                <SimpleWorkerTermsById<T, I>>::insert(worker_id, terms);
+            }
+        }
+    }
+
+    // Pays the reward for the blocks worked since the last payment and the missed reward of the
+    // simplified hiring mode worker. Unpaid rewards are added to the missed reward of the worker.
+    fn pay_simple_reward(worker_id: &WorkerId<T>, terms: &mut SimpleWorkerTermsOf<T>) {
+        if let Some(reward_per_block) = terms.reward_per_block {
+            let now = <frame_system::Module<T>>::block_number();
+
+            // Workers hired during the period are paid for the worked blocks only.
+            let worked_blocks: u32 = now
+                .saturating_sub(terms.last_reward_at)
+                .unique_saturated_into();
+            let reward = reward_per_block.saturating_mul(worked_blocks.into());
+            let owed_reward = reward.saturating_add(terms.missed_reward);
+
+            if owed_reward.is_zero() {
+                return;
+            }
+
+            if <minting::Module<T>>::transfer_tokens(
+                Self::mint(),
+                owed_reward,
+                &terms.reward_account_id,
+            )
+            .is_ok()
+            {
+                terms.missed_reward = Zero::zero();
+
+                Self::deposit_event(RawEvent::RewardPaid(*worker_id, owed_reward));
+            } else {
+                terms.missed_reward = owed_reward;
+            }
+
+            terms.last_reward_at = now;
+        }
+    }
+
     // Increases active worker counter (saturating).
     fn increase_active_worker_counter() {
         let next_active_worker_count_value = Self::active_worker_count().saturating_add(1);
@@ -1,6 +1,7 @@
 use frame_support::dispatch::{DispatchError, DispatchResult};
-use frame_support::storage::{StorageMap, StorageValue};
+use frame_support::storage::{StorageDoubleMap, StorageMap, StorageValue};
 use frame_system::{EventRecord, Phase, RawOrigin};
+use sp_core::H256;
 use std::collections::BTreeSet;
 
 use super::mock::{
@@ -8,8 +9,9 @@ use super::mock::{
 };
 use crate::tests::fill_worker_position;
 use crate::types::{
-    Application, Opening, OpeningPolicyCommitment, OpeningType, RewardPolicy, RoleStakeProfile,
-    Worker,
+    Application, ApplicationScore, Opening, OpeningPolicyCommitment, OpeningType,
+    PerformanceRating, PerformanceReview, ReviewAction, RewardPolicy, RoleStakeProfile,
+    SimpleOpening, StakePolicy, Worker,
 };
 use crate::RawEvent;
 use common::constraints::InputValidationLengthConstraint;
@@ -160,10 +162,55 @@ impl LeaveWorkerRoleFixture {
     }
 }
 
+pub(crate) struct LeaveWorkerRoleWithNoticeFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+    exit_at: u64,
+}
+
+impl LeaveWorkerRoleWithNoticeFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(1),
+            exit_at: 10,
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_exit_at(self, exit_at: u64) -> Self {
+        Self { exit_at, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let rationale_text = b"rationale_text".to_vec();
+        let actual_result = TestWorkingGroup::leave_role_with_notice(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.exit_at,
+            rationale_text.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert!(
+                <crate::WorkerById<Test, TestWorkingGroupInstance>>::contains_key(self.worker_id)
+            );
+            assert_eq!(
+                TestWorkingGroup::worker_exit_notice(self.worker_id),
+                Some((self.exit_at, rationale_text))
+            );
+        }
+    }
+}
+
 pub struct UpdateWorkerRewardAmountFixture {
     worker_id: u64,
     amount: u64,
     origin: RawOrigin<u64>,
+    review_id: Option<u64>,
 }
 
 impl UpdateWorkerRewardAmountFixture {
@@ -174,17 +221,26 @@ impl UpdateWorkerRewardAmountFixture {
             worker_id,
             amount: 120,
             origin: RawOrigin::Signed(lead_account_id),
+            review_id: None,
         }
     }
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
         Self { origin, ..self }
     }
 
+    pub fn with_review_id(self, review_id: u64) -> Self {
+        Self {
+            review_id: Some(review_id),
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let actual_result = TestWorkingGroup::update_reward_amount(
             self.origin.clone().into(),
             self.worker_id,
             self.amount,
+            self.review_id,
         );
 
         assert_eq!(actual_result.clone(), expected_result);
@@ -196,6 +252,83 @@ impl UpdateWorkerRewardAmountFixture {
             let relationship = recurringrewards::RewardRelationships::<Test>::get(relationship_id);
 
             assert_eq!(relationship.amount_per_payout, self.amount);
+
+            if let Some(review_id) = self.review_id {
+                let review = TestWorkingGroup::performance_review(self.worker_id, review_id);
+
+                assert_eq!(
+                    review.actions.last(),
+                    Some(&ReviewAction::RewardAmountUpdated(self.amount))
+                );
+            }
+        }
+    }
+}
+
+pub struct AddPerformanceReviewFixture {
+    worker_id: u64,
+    rating: PerformanceRating,
+    rationale_hash: H256,
+    origin: RawOrigin<u64>,
+}
+
+impl AddPerformanceReviewFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            worker_id,
+            rating: PerformanceRating::MeetsExpectations,
+            rationale_hash: H256::repeat_byte(1),
+            origin: RawOrigin::Signed(lead_account_id),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_rating(self, rating: PerformanceRating) -> Self {
+        Self { rating, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) -> Option<u64> {
+        let review_id = TestWorkingGroup::next_performance_review_id();
+
+        let actual_result = TestWorkingGroup::add_performance_review(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.rating,
+            self.rationale_hash,
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::next_performance_review_id(),
+                review_id + 1
+            );
+
+            assert_eq!(
+                TestWorkingGroup::performance_review(self.worker_id, review_id),
+                PerformanceReview {
+                    rating: self.rating,
+                    rationale_hash: self.rationale_hash,
+                    reviewed_at: System::block_number(),
+                    actions: Vec::new(),
+                }
+            );
+
+            Some(review_id)
+        } else {
+            assert!(
+                !<crate::PerformanceReviews<Test, TestWorkingGroupInstance>>::contains_key(
+                    self.worker_id,
+                    review_id
+                )
+            );
+
+            None
         }
     }
 }
@@ -237,6 +370,104 @@ impl UpdateWorkerRewardAccountFixture {
     }
 }
 
+pub struct ClaimMissedRewardsFixture {
+    worker_id: u64,
+    origin: RawOrigin<u64>,
+}
+
+impl ClaimMissedRewardsFixture {
+    pub fn default_for_worker_id(worker_id: u64) -> Self {
+        Self {
+            worker_id,
+            origin: RawOrigin::Signed(1),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let relationship_id = TestWorkingGroup::worker_by_id(self.worker_id).reward_relationship;
+        let owed_reward = relationship_id
+            .map(|id| recurringrewards::OwedRewards::<Test>::get(id))
+            .unwrap_or_default();
+        let reward_account_id = relationship_id
+            .map(|id| recurringrewards::RewardRelationships::<Test>::get(id).account)
+            .unwrap_or_default();
+        let old_balance = get_balance(reward_account_id);
+
+        let actual_result =
+            TestWorkingGroup::claim_missed_rewards(self.origin.clone().into(), self.worker_id);
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        if actual_result.is_ok() {
+            assert!(!recurringrewards::OwedRewards::<Test>::contains_key(
+                relationship_id.unwrap()
+            ));
+            assert_eq!(get_balance(reward_account_id), old_balance + owed_reward);
+        }
+    }
+}
+
+pub struct MakeDiscretionaryPaymentFixture {
+    origin: RawOrigin<u64>,
+    recipient_account_id: u64,
+    amount: u64,
+    rationale_text: Vec<u8>,
+}
+
+impl Default for MakeDiscretionaryPaymentFixture {
+    fn default() -> Self {
+        let lead_account_id = get_current_lead_account_id();
+
+        Self {
+            origin: RawOrigin::Signed(lead_account_id),
+            recipient_account_id: 5,
+            amount: 300,
+            rationale_text: b"bounty".to_vec(),
+        }
+    }
+}
+
+impl MakeDiscretionaryPaymentFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_amount(self, amount: u64) -> Self {
+        Self { amount, ..self }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let old_balance = get_balance(self.recipient_account_id);
+        let old_budget = TestWorkingGroup::budget();
+
+        let actual_result = TestWorkingGroup::make_discretionary_payment(
+            self.origin.clone().into(),
+            self.recipient_account_id,
+            self.amount,
+            self.rationale_text.clone(),
+        );
+
+        assert_eq!(actual_result.clone(), expected_result);
+
+        let new_balance = get_balance(self.recipient_account_id);
+        let new_budget = TestWorkingGroup::budget();
+
+        if actual_result.is_ok() {
+            assert_eq!(new_balance, old_balance + self.amount);
+            assert_eq!(
+                new_budget.unwrap().discretionary_spending,
+                old_budget.unwrap().discretionary_spending + self.amount
+            );
+        } else {
+            assert_eq!(new_balance, old_balance);
+            assert_eq!(new_budget, old_budget);
+        }
+    }
+}
+
 pub struct UpdateWorkerRoleAccountFixture {
     worker_id: u64,
     new_role_account_id: u64,
@@ -403,6 +634,237 @@ impl FillWorkerOpeningFixture {
     }
 }
 
+pub struct ScoreApplicationFixture {
+    origin: RawOrigin<u64>,
+    reviewer_id: u64,
+    application_id: u64,
+    score: u32,
+    comment: Vec<u8>,
+}
+
+impl ScoreApplicationFixture {
+    pub fn default_for_ids(reviewer_id: u64, application_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            reviewer_id,
+            application_id,
+            score: 10,
+            comment: b"comment".to_vec(),
+        }
+    }
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+    pub fn with_score(self, score: u32) -> Self {
+        Self { score, ..self }
+    }
+    pub fn call_and_assert(&self, expected_result: DispatchResult) {
+        let actual_result = TestWorkingGroup::score_application(
+            self.origin.clone().into(),
+            self.reviewer_id,
+            self.application_id,
+            self.score,
+            self.comment.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::application_score(self.application_id, self.reviewer_id),
+                Some(ApplicationScore {
+                    score: self.score,
+                    comment: self.comment.clone(),
+                })
+            );
+        }
+    }
+}
+
+pub struct AddSimpleOpeningFixture {
+    origin: RawOrigin<u64>,
+    opening_type: OpeningType,
+    stake_policy: Option<StakePolicy<u64, u64>>,
+    reward_per_block: Option<u64>,
+}
+
+impl Default for AddSimpleOpeningFixture {
+    fn default() -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            opening_type: OpeningType::Worker,
+            stake_policy: None,
+            reward_per_block: None,
+        }
+    }
+}
+
+impl AddSimpleOpeningFixture {
+    pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn with_stake_policy(self, stake_policy: StakePolicy<u64, u64>) -> Self {
+        Self {
+            stake_policy: Some(stake_policy),
+            ..self
+        }
+    }
+
+    pub fn with_reward_per_block(self, reward_per_block: u64) -> Self {
+        Self {
+            reward_per_block: Some(reward_per_block),
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) -> u64 {
+        let saved_opening_next_id = TestWorkingGroup::next_simple_opening_id();
+        let description = b"description".to_vec();
+        let actual_result = TestWorkingGroup::add_simple_opening(
+            self.origin.clone().into(),
+            description.clone(),
+            self.opening_type,
+            self.stake_policy.clone(),
+            self.reward_per_block,
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::next_simple_opening_id(),
+                saved_opening_next_id + 1
+            );
+
+            let expected_opening = SimpleOpening {
+                opening_type: self.opening_type,
+                created: System::block_number(),
+                description,
+                stake_policy: self.stake_policy.clone(),
+                reward_per_block: self.reward_per_block,
+                applications: BTreeSet::new(),
+            };
+
+            assert_eq!(
+                TestWorkingGroup::simple_opening_by_id(saved_opening_next_id),
+                expected_opening
+            );
+        }
+
+        saved_opening_next_id
+    }
+}
+
+pub struct ApplyOnSimpleOpeningFixture {
+    origin: RawOrigin<u64>,
+    member_id: u64,
+    opening_id: u64,
+    role_account_id: u64,
+    reward_account_id: u64,
+}
+
+impl ApplyOnSimpleOpeningFixture {
+    pub fn default_for_opening_id(opening_id: u64) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            member_id: 1,
+            opening_id,
+            role_account_id: 1,
+            reward_account_id: 1,
+        }
+    }
+
+    pub fn with_origin(self, origin: RawOrigin<u64>, member_id: u64) -> Self {
+        Self {
+            origin,
+            member_id,
+            ..self
+        }
+    }
+
+    pub fn with_role_account_id(self, role_account_id: u64) -> Self {
+        Self {
+            role_account_id,
+            ..self
+        }
+    }
+
+    pub fn with_reward_account_id(self, reward_account_id: u64) -> Self {
+        Self {
+            reward_account_id,
+            ..self
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) -> u64 {
+        let saved_application_next_id = TestWorkingGroup::next_simple_application_id();
+        let actual_result = TestWorkingGroup::apply_on_simple_opening(
+            self.origin.clone().into(),
+            self.member_id,
+            self.opening_id,
+            self.role_account_id,
+            self.reward_account_id,
+            b"description".to_vec(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::next_simple_application_id(),
+                saved_application_next_id + 1
+            );
+
+            let application = TestWorkingGroup::simple_application_by_id(saved_application_next_id);
+            assert_eq!(application.member_id, self.member_id);
+            assert_eq!(application.opening_id, self.opening_id);
+
+            let opening = TestWorkingGroup::simple_opening_by_id(self.opening_id);
+            assert!(opening.applications.contains(&saved_application_next_id));
+        }
+
+        saved_application_next_id
+    }
+}
+
+pub struct FillSimpleOpeningFixture {
+    origin: RawOrigin<u64>,
+    opening_id: u64,
+    successful_application_ids: BTreeSet<u64>,
+}
+
+impl FillSimpleOpeningFixture {
+    pub fn default_for_ids(opening_id: u64, application_ids: Vec<u64>) -> Self {
+        Self {
+            origin: RawOrigin::Signed(1),
+            opening_id,
+            successful_application_ids: application_ids.into_iter().collect(),
+        }
+    }
+
+    pub fn call_and_assert(&self, expected_result: DispatchResult) -> u64 {
+        let saved_worker_next_id = TestWorkingGroup::next_worker_id();
+        let actual_result = TestWorkingGroup::fill_simple_opening(
+            self.origin.clone().into(),
+            self.opening_id,
+            self.successful_application_ids.clone(),
+        );
+        assert_eq!(actual_result, expected_result);
+
+        if actual_result.is_ok() {
+            assert_eq!(
+                TestWorkingGroup::next_worker_id(),
+                saved_worker_next_id + self.successful_application_ids.len() as u64
+            );
+            assert!(
+                !<crate::SimpleOpeningById<Test, TestWorkingGroupInstance>>::contains_key(
+                    self.opening_id
+                )
+            );
+        }
+
+        saved_worker_next_id
+    }
+}
+
 pub struct BeginReviewWorkerApplicationsFixture {
     origin: RawOrigin<u64>,
     opening_id: u64,
@@ -917,6 +1379,7 @@ pub struct SlashWorkerStakeFixture {
     worker_id: u64,
     balance: u64,
     account_id: u64,
+    review_id: Option<u64>,
 }
 
 impl SlashWorkerStakeFixture {
@@ -930,6 +1393,7 @@ impl SlashWorkerStakeFixture {
             worker_id,
             balance: 10,
             account_id,
+            review_id: None,
         }
     }
     pub fn with_origin(self, origin: RawOrigin<u64>) -> Self {
@@ -940,12 +1404,23 @@ impl SlashWorkerStakeFixture {
         Self { balance, ..self }
     }
 
+    pub fn with_review_id(self, review_id: u64) -> Self {
+        Self {
+            review_id: Some(review_id),
+            ..self
+        }
+    }
+
     pub fn call_and_assert(&self, expected_result: DispatchResult) {
         let stake_id = 0;
         let old_balance = Balances::free_balance(&self.account_id);
         let old_stake = <stake::Module<Test>>::stakes(stake_id);
-        let actual_result =
-            TestWorkingGroup::slash_stake(self.origin.clone().into(), self.worker_id, self.balance);
+        let actual_result = TestWorkingGroup::slash_stake(
+            self.origin.clone().into(),
+            self.worker_id,
+            self.balance,
+            self.review_id,
+        );
 
         assert_eq!(actual_result, expected_result);
 
@@ -962,6 +1437,15 @@ impl SlashWorkerStakeFixture {
 
             // worker balance unchanged
             assert_eq!(new_balance, old_balance,);
+
+            if let Some(review_id) = self.review_id {
+                let review = TestWorkingGroup::performance_review(self.worker_id, review_id);
+
+                assert_eq!(
+                    review.actions.last(),
+                    Some(&ReviewAction::StakeSlashed(self.balance))
+                );
+            }
         }
     }
 }
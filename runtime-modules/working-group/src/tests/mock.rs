@@ -31,6 +31,8 @@ impl_outer_event! {
         balances<T>,
         working_group TestWorkingGroupInstance <T>,
         membership_mod<T>,
+        stake<T>,
+        minting<T>,
         frame_system<T>,
     }
 }
@@ -84,12 +86,19 @@ impl hiring::Trait for Test {
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MaxTransferLogLength: u32 = 10;
+}
+
 impl minting::Trait for Test {
+    type Event = TestEvent;
     type Currency = Balances;
     type MintId = u64;
+    type MaxTransferLogLength = MaxTransferLogLength;
 }
 
 impl stake::Trait for Test {
+    type Event = TestEvent;
     type Currency = Balances;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = StakingEventsHandler<Test>;
@@ -108,6 +117,13 @@ impl membership::Trait for Test {
     type SubscriptionId = u64;
     type ActorId = u64;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator = ();
+}
+
+impl common::origin::ActorOriginValidator<Origin, u64, u64> for () {
+    fn ensure_actor_origin(_: Origin, _: u64) -> Result<u64, &'static str> {
+        Err("Actor origin validation is not supported")
+    }
 }
 
 impl common::currency::GovernanceCurrency for Test {
@@ -131,10 +147,15 @@ impl balances::Trait for Test {
     type MaxLocks = ();
 }
 
+parameter_types! {
+    pub const CatchUpPayoutLimit: u64 = 1000;
+}
+
 impl recurringrewards::Trait for Test {
     type PayoutStatusHandler = ();
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type CatchUpPayoutLimit = CatchUpPayoutLimit;
 }
 
 pub type Balances = balances::Module<Test>;
@@ -142,11 +163,17 @@ pub type System = frame_system::Module<Test>;
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 3;
+    pub const StakingLockId: [u8; 8] = *b"wg-stake";
+    pub const ApplicationLockId: [u8; 8] = *b"wg-apply";
+    pub const RewardPeriod: u32 = 10;
 }
 
 impl Trait<TestWorkingGroupInstance> for Test {
     type Event = TestEvent;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = StakingLockId;
+    type ApplicationLockId = ApplicationLockId;
+    type RewardPeriod = RewardPeriod;
 }
 
 pub type Membership = membership::Module<Test>;
@@ -199,6 +226,9 @@ impl<T: stake::Trait + crate::Trait<TestWorkingGroupInstance>> stake::StakingEve
         _unstaked_amount: BalanceOf<T>,
         remaining_imbalance: NegativeImbalance<T>,
     ) -> NegativeImbalance<T> {
+        // Stake of the former worker hired in the simplified hiring mode.
+        <crate::Module<T, TestWorkingGroupInstance>>::remove_unstaked_simple_stake(*stake_id);
+
         // Stake not related to a staked role managed by the hiring module.
         if !hiring::ApplicationIdByStakingId::<T>::contains_key(*stake_id) {
             return remaining_imbalance;
@@ -4,17 +4,22 @@ mod mock;
 
 use frame_support::dispatch::DispatchError;
 use frame_support::storage::{StorageMap, StorageValue};
+use frame_support::traits::{Get, OnFinalize};
 use frame_system::RawOrigin;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::default_storage_size_constraint;
 use crate::tests::hiring_workflow::HiringWorkflow;
-use crate::types::{OpeningPolicyCommitment, OpeningType, RewardPolicy};
+use crate::types::{
+    Budget, OpeningPolicyCommitment, OpeningType, PerformanceRating, ReviewAction, RewardPolicy,
+    StakePolicy,
+};
 use crate::{Error, RawEvent, Worker, WorkerId};
 use common::constraints::InputValidationLengthConstraint;
 use fixtures::*;
 use mock::{
-    build_test_externalities, run_to_block, Test, TestWorkingGroup, TestWorkingGroupInstance,
+    build_test_externalities, run_to_block, ApplicationLockId, Balances, RewardPeriod,
+    StakingLockId, System, Test, TestWorkingGroup, TestWorkingGroupInstance,
     WORKING_GROUP_CONSTRAINT_DIFF, WORKING_GROUP_CONSTRAINT_MIN, WORKING_GROUP_MINT_CAPACITY,
 };
 
@@ -174,12 +179,12 @@ fn add_opening_fails_with_incorrect_unstaking_periods() {
         let add_opening_fixture =
             AddWorkerOpeningFixture::default().with_policy_commitment(OpeningPolicyCommitment {
                 application_rationing_policy: Some(hiring::ApplicationRationingPolicy {
-                    max_active_applicants: 0,
+                    max_active_applicants: 1,
                 }),
                 ..OpeningPolicyCommitment::default()
             });
         add_opening_fixture.call_and_assert(Err(
-            Error::<Test, TestWorkingGroupInstance>::ApplicationRationingPolicyMaxActiveApplicantsIsZero.into(),
+            Error::<Test, TestWorkingGroupInstance>::ApplicationRationingPolicyIsNotSupported.into(),
         ));
     });
 }
@@ -939,6 +944,205 @@ fn fill_opening_succeeds() {
     });
 }
 
+// Adds the worker opening with applications from the members 1, 2 and 3 and begins the review.
+fn setup_opening_in_review_with_applications() -> (u64, Vec<u64>) {
+    let opening_id = AddWorkerOpeningFixture::default().call_and_assert(Ok(()));
+
+    let application_ids = (1..=3)
+        .map(|member_id| {
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id)
+                .with_origin(RawOrigin::Signed(member_id), member_id)
+                .call_and_assert(Ok(()))
+        })
+        .collect::<Vec<_>>();
+
+    BeginReviewWorkerApplicationsFixture::default_for_opening_id(opening_id)
+        .call_and_assert(Ok(()));
+
+    (opening_id, application_ids)
+}
+
+#[test]
+fn score_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let lead_id = HireLeadFixture::default().hire_lead();
+        let (_, application_ids) = setup_opening_in_review_with_applications();
+
+        let score = 7;
+        ScoreApplicationFixture::default_for_ids(lead_id, application_ids[0])
+            .with_score(score)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::ApplicationScored(
+            application_ids[0],
+            lead_id,
+            score,
+            b"comment".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn score_application_succeeds_with_opening_reviewers() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        let reviewer_id = HiringWorkflow::default()
+            .disable_setup_environment()
+            .add_application_with_origin(b"worker_handle".to_vec(), RawOrigin::Signed(2), 2)
+            .execute()
+            .unwrap();
+        let reviewer_account_id = TestWorkingGroup::worker_by_id(reviewer_id).role_account_id;
+
+        let (opening_id, application_ids) = setup_opening_in_review_with_applications();
+
+        let mut reviewer_ids = BTreeSet::new();
+        reviewer_ids.insert(reviewer_id);
+        assert_eq!(
+            TestWorkingGroup::set_opening_reviewers(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                reviewer_ids.clone()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            TestWorkingGroup::opening_reviewers(opening_id),
+            reviewer_ids
+        );
+
+        ScoreApplicationFixture::default_for_ids(lead_id, application_ids[0])
+            .with_score(4)
+            .call_and_assert(Ok(()));
+
+        ScoreApplicationFixture::default_for_ids(reviewer_id, application_ids[0])
+            .with_origin(RawOrigin::Signed(reviewer_account_id))
+            .with_score(9)
+            .call_and_assert(Ok(()));
+
+        // Average score is rounded down.
+        assert_eq!(
+            TestWorkingGroup::get_ranked_applications(&opening_id),
+            vec![(application_ids[0], 6)]
+        );
+    });
+}
+
+#[test]
+fn score_application_fails_with_not_reviewer_worker() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        let worker_id = HiringWorkflow::default()
+            .disable_setup_environment()
+            .add_application_with_origin(b"worker_handle".to_vec(), RawOrigin::Signed(2), 2)
+            .execute()
+            .unwrap();
+        let worker_account_id = TestWorkingGroup::worker_by_id(worker_id).role_account_id;
+
+        let (_, application_ids) = setup_opening_in_review_with_applications();
+
+        ScoreApplicationFixture::default_for_ids(worker_id, application_ids[0])
+            .with_origin(RawOrigin::Signed(worker_account_id))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::WorkerIsNotApplicationReviewer.into(),
+            ));
+    });
+}
+
+#[test]
+fn score_application_fails_with_opening_not_in_review_period() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+
+        let opening_id = AddWorkerOpeningFixture::default().call_and_assert(Ok(()));
+        let application_id =
+            ApplyOnWorkerOpeningFixture::default_for_opening_id(opening_id).call_and_assert(Ok(()));
+
+        ScoreApplicationFixture::default_for_ids(lead_id, application_id).call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::OpeningIsNotInReviewPeriod.into(),
+        ));
+    });
+}
+
+#[test]
+fn fill_opening_with_top_scored_applications_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let lead_id = HireLeadFixture::default().hire_lead();
+        let (opening_id, application_ids) = setup_opening_in_review_with_applications();
+
+        for (application_id, score) in application_ids.iter().zip(vec![5, 9, 7]) {
+            ScoreApplicationFixture::default_for_ids(lead_id, *application_id)
+                .with_score(score)
+                .call_and_assert(Ok(()));
+        }
+
+        assert_eq!(
+            TestWorkingGroup::get_ranked_applications(&opening_id),
+            vec![
+                (application_ids[1], 9),
+                (application_ids[2], 7),
+                (application_ids[0], 5)
+            ]
+        );
+
+        let next_worker_id = TestWorkingGroup::next_worker_id();
+        assert_eq!(
+            TestWorkingGroup::fill_opening_with_top_scored_applications(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                2,
+                None
+            ),
+            Ok(())
+        );
+
+        let mut worker_application_dictionary = BTreeMap::new();
+        worker_application_dictionary.insert(application_ids[1], next_worker_id);
+        worker_application_dictionary.insert(application_ids[2], next_worker_id + 1);
+
+        EventFixture::assert_last_crate_event(RawEvent::OpeningFilled(
+            opening_id,
+            worker_application_dictionary,
+        ));
+    });
+}
+
+#[test]
+fn fill_opening_with_top_scored_applications_fails_with_not_enough_scored_applications() {
+    build_test_externalities().execute_with(|| {
+        let lead_id = HireLeadFixture::default().hire_lead();
+        let (opening_id, application_ids) = setup_opening_in_review_with_applications();
+
+        ScoreApplicationFixture::default_for_ids(lead_id, application_ids[0])
+            .call_and_assert(Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::fill_opening_with_top_scored_applications(
+                RawOrigin::Signed(1).into(),
+                opening_id,
+                2,
+                None
+            ),
+            Err(Error::<Test, TestWorkingGroupInstance>::NotEnoughScoredApplications.into())
+        );
+    });
+}
+
 #[test]
 fn fill_opening_fails_with_invalid_origin_for_opening_type() {
     build_test_externalities().execute_with(|| {
@@ -1374,6 +1578,84 @@ fn update_worker_reward_account_fails_with_no_recurring_reward() {
     });
 }
 
+#[test]
+fn claim_missed_rewards_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let worker_id = fill_default_worker_position();
+        let relationship_id = get_worker_by_id(worker_id).reward_relationship.unwrap();
+
+        // The default payout exceeds the mint capacity.
+        <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(20);
+
+        let owed_reward = recurringrewards::OwedRewards::<Test>::get(relationship_id);
+        assert_eq!(owed_reward, 1000);
+
+        assert_eq!(
+            TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), 10000),
+            Ok(())
+        );
+
+        ClaimMissedRewardsFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::MissedRewardsClaimed(
+            worker_id,
+            owed_reward,
+        ));
+    });
+}
+
+#[test]
+fn claim_missed_rewards_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        ClaimMissedRewardsFixture::default_for_worker_id(1)
+            .with_origin(RawOrigin::None)
+            .call_and_assert(Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn claim_missed_rewards_fails_with_no_recurring_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_no_reward();
+
+        ClaimMissedRewardsFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::WorkerHasNoReward.into(),
+        ));
+    });
+}
+
+#[test]
+fn claim_missed_rewards_fails_with_no_owed_reward() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        ClaimMissedRewardsFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::RecurringRewardsNoOwedReward.into(),
+        ));
+    });
+}
+
+#[test]
+fn claim_missed_rewards_fails_with_insufficient_mint_capacity() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(20);
+
+        ClaimMissedRewardsFixture::default_for_worker_id(worker_id).call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::RecurringRewardsInsufficientRewardSourceCapacity
+                .into(),
+        ));
+    });
+}
+
 #[test]
 fn update_worker_reward_amount_succeeds() {
     build_test_externalities().execute_with(|| {
@@ -1486,6 +1768,145 @@ fn update_worker_reward_amount_fails_with_no_recurring_reward() {
     });
 }
 
+#[test]
+fn update_worker_reward_amount_succeeds_with_review() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let review_id = AddPerformanceReviewFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let update_worker_amount_fixture =
+            UpdateWorkerRewardAmountFixture::default_for_worker_id(worker_id)
+                .with_review_id(review_id);
+
+        update_worker_amount_fixture.call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn update_worker_reward_amount_fails_with_invalid_review_id() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        let invalid_review_id = 11;
+        let update_worker_amount_fixture =
+            UpdateWorkerRewardAmountFixture::default_for_worker_id(worker_id)
+                .with_review_id(invalid_review_id);
+
+        update_worker_amount_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::PerformanceReviewDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn add_performance_review_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let worker_id = fill_default_worker_position();
+
+        let review_id = AddPerformanceReviewFixture::default_for_worker_id(worker_id)
+            .with_rating(PerformanceRating::Excellent)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        EventFixture::assert_last_crate_event(RawEvent::PerformanceReviewAdded(
+            worker_id, review_id,
+        ));
+    });
+}
+
+#[test]
+fn add_performance_review_succeeds_for_leader() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        AddPerformanceReviewFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Root)
+            .call_and_assert(Ok(()));
+    });
+}
+
+#[test]
+fn add_performance_review_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        AddPerformanceReviewFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn add_performance_review_fails_with_invalid_worker_id() {
+    build_test_externalities().execute_with(|| {
+        fill_default_worker_position();
+
+        let invalid_worker_id = 12;
+        AddPerformanceReviewFixture::default_for_worker_id(invalid_worker_id).call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::WorkerDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn get_worker_performance_history_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let first_review_block = System::block_number();
+        let first_review_id = AddPerformanceReviewFixture::default_for_worker_id(worker_id)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        let second_review_block = first_review_block + 5;
+        run_to_block(second_review_block);
+
+        let second_review_id = AddPerformanceReviewFixture::default_for_worker_id(worker_id)
+            .with_rating(PerformanceRating::Poor)
+            .call_and_assert(Ok(()))
+            .unwrap();
+
+        SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_review_id(second_review_id)
+            .call_and_assert(Ok(()));
+
+        let history = TestWorkingGroup::get_worker_performance_history(&worker_id);
+
+        assert_eq!(
+            history
+                .iter()
+                .map(|(review_id, review)| (*review_id, review.rating, review.reviewed_at))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    first_review_id,
+                    PerformanceRating::MeetsExpectations,
+                    first_review_block
+                ),
+                (
+                    second_review_id,
+                    PerformanceRating::Poor,
+                    second_review_block
+                ),
+            ]
+        );
+        assert!(history[0].1.actions.is_empty());
+        assert_eq!(history[1].1.actions, vec![ReviewAction::StakeSlashed(10)]);
+    });
+}
+
 fn fill_default_worker_position() -> u64 {
     fill_worker_position(
         Some(RewardPolicy {
@@ -1564,20 +1985,92 @@ fn leave_worker_role_succeeds() {
 }
 
 #[test]
-fn leave_worker_role_by_leader_succeeds() {
+fn leave_worker_role_pays_missed_rewards() {
     build_test_externalities().execute_with(|| {
-        // Ensure that lead is default
-        assert_eq!(TestWorkingGroup::current_lead(), None);
-        let worker_id = HireLeadFixture::default().hire_lead();
+        run_to_block(1);
 
-        assert!(TestWorkingGroup::current_lead().is_some());
+        let worker_id = fill_default_worker_position();
+        let relationship_id = get_worker_by_id(worker_id).reward_relationship.unwrap();
+        let reward_account_id =
+            recurringrewards::RewardRelationships::<Test>::get(relationship_id).account;
 
-        let leave_worker_role_fixture = LeaveWorkerRoleFixture::default_for_worker_id(worker_id);
+        // The default payout exceeds the mint capacity.
+        <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(20);
 
-        leave_worker_role_fixture.call_and_assert(Ok(()));
+        let owed_reward = recurringrewards::OwedRewards::<Test>::get(relationship_id);
+        assert_eq!(owed_reward, 1000);
 
-        assert_eq!(TestWorkingGroup::current_lead(), None);
-    });
+        assert_eq!(
+            TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), 10000),
+            Ok(())
+        );
+
+        let old_balance = get_balance(reward_account_id);
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert!(!recurringrewards::OwedRewards::<Test>::contains_key(
+            relationship_id
+        ));
+        assert_eq!(get_balance(reward_account_id), old_balance + owed_reward);
+    });
+}
+
+#[test]
+fn leave_worker_role_keeps_unpaid_missed_rewards_claimable() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let worker_id = fill_default_worker_position();
+        let relationship_id = get_worker_by_id(worker_id).reward_relationship.unwrap();
+        let reward_account_id =
+            recurringrewards::RewardRelationships::<Test>::get(relationship_id).account;
+
+        // The default payout exceeds the mint capacity.
+        <recurringrewards::Module<Test> as OnFinalize<u64>>::on_finalize(20);
+
+        let owed_reward = recurringrewards::OwedRewards::<Test>::get(relationship_id);
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(
+            recurringrewards::OwedRewards::<Test>::get(relationship_id),
+            owed_reward
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), 10000),
+            Ok(())
+        );
+
+        let old_balance = get_balance(reward_account_id);
+
+        assert_eq!(
+            recurringrewards::Module::<Test>::claim_owed_reward(
+                RawOrigin::Signed(reward_account_id).into(),
+                relationship_id
+            ),
+            Ok(())
+        );
+        assert_eq!(get_balance(reward_account_id), old_balance + owed_reward);
+    });
+}
+
+#[test]
+fn leave_worker_role_by_leader_succeeds() {
+    build_test_externalities().execute_with(|| {
+        // Ensure that lead is default
+        assert_eq!(TestWorkingGroup::current_lead(), None);
+        let worker_id = HireLeadFixture::default().hire_lead();
+
+        assert!(TestWorkingGroup::current_lead().is_some());
+
+        let leave_worker_role_fixture = LeaveWorkerRoleFixture::default_for_worker_id(worker_id);
+
+        leave_worker_role_fixture.call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::current_lead(), None);
+    });
 }
 
 #[test]
@@ -1660,6 +2153,119 @@ fn leave_worker_role_succeeds_with_stakes() {
     });
 }
 
+#[test]
+fn leave_worker_role_with_notice_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let worker_id = fill_default_worker_position();
+        let exit_at = 10;
+
+        LeaveWorkerRoleWithNoticeFixture::default_for_worker_id(worker_id)
+            .with_exit_at(exit_at)
+            .call_and_assert(Ok(()));
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerExitScheduled(worker_id, exit_at));
+
+        // The worker keeps the role during the notice period.
+        run_to_block(exit_at - 1);
+        assert!(<crate::WorkerById<Test, TestWorkingGroupInstance>>::contains_key(worker_id));
+
+        run_to_block(exit_at);
+        assert!(!<crate::WorkerById<Test, TestWorkingGroupInstance>>::contains_key(worker_id));
+        assert_eq!(TestWorkingGroup::worker_exit_notice(worker_id), None);
+        assert!(TestWorkingGroup::worker_exit_schedule(exit_at).is_empty());
+
+        EventFixture::assert_last_crate_event(RawEvent::WorkerExited(
+            worker_id,
+            b"rationale_text".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn leave_worker_role_with_notice_by_leader_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = HireLeadFixture::default().hire_lead();
+        let exit_at = 10;
+
+        LeaveWorkerRoleWithNoticeFixture::default_for_worker_id(worker_id)
+            .with_exit_at(exit_at)
+            .call_and_assert(Ok(()));
+
+        assert!(TestWorkingGroup::current_lead().is_some());
+
+        run_to_block(exit_at);
+
+        assert_eq!(TestWorkingGroup::current_lead(), None);
+    });
+}
+
+#[test]
+fn leave_worker_role_with_notice_fails_with_invalid_origin_signed_account() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        LeaveWorkerRoleWithNoticeFixture::default_for_worker_id(worker_id)
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::SignerIsNotWorkerRoleAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn leave_worker_role_with_notice_fails_with_exit_block_in_the_past() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(5);
+
+        let worker_id = fill_default_worker_position();
+
+        LeaveWorkerRoleWithNoticeFixture::default_for_worker_id(worker_id)
+            .with_exit_at(5)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::WorkerExitBlockMustBeInFuture.into(),
+            ));
+    });
+}
+
+#[test]
+fn leave_worker_role_with_notice_fails_with_already_scheduled_exit() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+
+        LeaveWorkerRoleWithNoticeFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        LeaveWorkerRoleWithNoticeFixture::default_for_worker_id(worker_id)
+            .with_exit_at(20)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::WorkerExitAlreadyScheduled.into(),
+            ));
+    });
+}
+
+#[test]
+fn terminate_worker_role_removes_scheduled_exit() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_default_worker_position();
+        let exit_at = 10;
+
+        LeaveWorkerRoleWithNoticeFixture::default_for_worker_id(worker_id)
+            .with_exit_at(exit_at)
+            .call_and_assert(Ok(()));
+
+        TerminateWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::worker_exit_notice(worker_id), None);
+        assert!(TestWorkingGroup::worker_exit_schedule(exit_at).is_empty());
+    });
+}
+
 #[test]
 fn terminate_worker_role_succeeds_with_stakes() {
     build_test_externalities().execute_with(|| {
@@ -2247,6 +2853,21 @@ fn slash_worker_stake_fails_with_no_stake_profile() {
     });
 }
 
+#[test]
+fn slash_worker_stake_fails_with_invalid_review_id() {
+    build_test_externalities().execute_with(|| {
+        let worker_id = fill_worker_position_with_stake(100);
+
+        let invalid_review_id = 11;
+        let slash_stake_fixture = SlashWorkerStakeFixture::default_for_worker_id(worker_id)
+            .with_review_id(invalid_review_id);
+
+        slash_stake_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::PerformanceReviewDoesNotExist.into(),
+        ));
+    });
+}
+
 #[test]
 fn slash_worker_stake_fails_with_not_set_lead() {
     build_test_externalities().execute_with(|| {
@@ -2351,6 +2972,229 @@ fn set_working_group_mint_capacity_fails_with_invalid_origin() {
     });
 }
 
+#[test]
+fn set_working_group_budget_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let spending_ceiling = 1000;
+        let period_length = 10;
+        let result =
+            TestWorkingGroup::set_budget(RawOrigin::Root.into(), spending_ceiling, period_length);
+
+        assert_eq!(result, Ok(()));
+
+        assert_eq!(
+            TestWorkingGroup::budget(),
+            Some(Budget {
+                spending_ceiling,
+                period_length,
+                period_started_at: 1,
+                minted_before_period: 0,
+                discretionary_spending: 0,
+            })
+        );
+
+        let mint = <minting::Module<Test>>::mints(TestWorkingGroup::mint());
+        assert_eq!(mint.capacity(), spending_ceiling);
+
+        EventFixture::assert_last_crate_event(RawEvent::BudgetSet(spending_ceiling, period_length));
+    });
+}
+
+#[test]
+fn set_working_group_budget_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        let result = TestWorkingGroup::set_budget(RawOrigin::None.into(), 1000, 10);
+
+        assert_eq!(result, Err(DispatchError::BadOrigin));
+    });
+}
+
+#[test]
+fn set_working_group_budget_fails_with_zero_period_length() {
+    build_test_externalities().execute_with(|| {
+        let result = TestWorkingGroup::set_budget(RawOrigin::Root.into(), 1000, 0);
+
+        assert_eq!(
+            result,
+            Err(Error::<Test, TestWorkingGroupInstance>::BudgetPeriodLengthCannotBeZero.into())
+        );
+    });
+}
+
+#[test]
+fn make_discretionary_payment_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        HireLeadFixture::default().hire_lead();
+        assert_eq!(
+            TestWorkingGroup::set_budget(RawOrigin::Root.into(), 1000, 10),
+            Ok(())
+        );
+
+        MakeDiscretionaryPaymentFixture::default().call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::get_budget_spending(), Some((0, 300)));
+
+        EventFixture::assert_last_crate_event(RawEvent::DiscretionaryPaymentMade(
+            5,
+            300,
+            b"bounty".to_vec(),
+        ));
+    });
+}
+
+#[test]
+fn make_discretionary_payment_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        assert_eq!(
+            TestWorkingGroup::set_budget(RawOrigin::Root.into(), 1000, 10),
+            Ok(())
+        );
+
+        MakeDiscretionaryPaymentFixture::default()
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn make_discretionary_payment_fails_with_zero_amount() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        assert_eq!(
+            TestWorkingGroup::set_budget(RawOrigin::Root.into(), 1000, 10),
+            Ok(())
+        );
+
+        MakeDiscretionaryPaymentFixture::default()
+            .with_amount(0)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::DiscretionaryPaymentAmountCannotBeZero
+                    .into(),
+            ));
+    });
+}
+
+#[test]
+fn make_discretionary_payment_fails_with_budget_not_set() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        MakeDiscretionaryPaymentFixture::default().call_and_assert(Err(Error::<
+            Test,
+            TestWorkingGroupInstance,
+        >::BudgetNotSet
+            .into()));
+    });
+}
+
+#[test]
+fn make_discretionary_payment_fails_with_insufficient_budget() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+        assert_eq!(
+            TestWorkingGroup::set_budget(RawOrigin::Root.into(), 1000, 10),
+            Ok(())
+        );
+
+        // reward spending
+        assert_eq!(
+            <minting::Module<Test>>::transfer_tokens(TestWorkingGroup::mint(), 800, &6),
+            Ok(())
+        );
+        assert_eq!(TestWorkingGroup::get_budget_spending(), Some((800, 0)));
+
+        MakeDiscretionaryPaymentFixture::default().call_and_assert(Err(Error::<
+            Test,
+            TestWorkingGroupInstance,
+        >::InsufficientBudget
+            .into()));
+    });
+}
+
+#[test]
+fn budget_period_ends_and_next_period_starts() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        HireLeadFixture::default().hire_lead();
+        assert_eq!(
+            TestWorkingGroup::set_budget(RawOrigin::Root.into(), 1000, 10),
+            Ok(())
+        );
+
+        // reward spending
+        assert_eq!(
+            <minting::Module<Test>>::transfer_tokens(TestWorkingGroup::mint(), 200, &6),
+            Ok(())
+        );
+        MakeDiscretionaryPaymentFixture::default().call_and_assert(Ok(()));
+
+        run_to_block(10);
+        assert_eq!(TestWorkingGroup::get_budget_spending(), Some((200, 300)));
+
+        run_to_block(11);
+
+        EventFixture::assert_last_crate_event(RawEvent::BudgetPeriodEnded(200, 300));
+
+        assert_eq!(
+            TestWorkingGroup::budget(),
+            Some(Budget {
+                spending_ceiling: 1000,
+                period_length: 10,
+                period_started_at: 11,
+                minted_before_period: 500,
+                discretionary_spending: 0,
+            })
+        );
+        assert_eq!(TestWorkingGroup::get_budget_spending(), Some((0, 0)));
+
+        let mint = <minting::Module<Test>>::mints(TestWorkingGroup::mint());
+        assert_eq!(mint.capacity(), 1000);
+    });
+}
+
+#[test]
+fn set_mint_capacity_updates_budget_spending_ceiling() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        assert_eq!(
+            TestWorkingGroup::set_budget(RawOrigin::Root.into(), 1000, 10),
+            Ok(())
+        );
+
+        assert_eq!(
+            TestWorkingGroup::set_mint_capacity(RawOrigin::Root.into(), 2000),
+            Ok(())
+        );
+
+        assert_eq!(TestWorkingGroup::budget().unwrap().spending_ceiling, 2000);
+
+        // The next budget period keeps the new capacity.
+        run_to_block(11);
+
+        let mint = <minting::Module<Test>>::mints(TestWorkingGroup::mint());
+        assert_eq!(mint.capacity(), 2000);
+    });
+}
+
 #[test]
 fn ensure_setting_genesis_working_group_mint_succeeds() {
     build_test_externalities().execute_with(|| {
@@ -2456,3 +3300,358 @@ fn fill_opening_cannot_hire_more_workers_using_several_applicationst_han_allows_
         hiring_workflow.execute()
     });
 }
+
+// Hires the lead and adds the simplified opening with the stake and the reward.
+fn setup_simple_opening(stake_amount: u64, unstaking_period: u64, reward_per_block: u64) -> u64 {
+    HireLeadFixture::default().hire_lead();
+
+    AddSimpleOpeningFixture::default()
+        .with_stake_policy(StakePolicy {
+            stake_amount,
+            unstaking_period,
+        })
+        .with_reward_per_block(reward_per_block)
+        .call_and_assert(Ok(()))
+}
+
+fn locked_simple_stake(account_id: u64) -> u64 {
+    stake::Module::<Test>::locked_balance(StakingLockId::get(), account_id)
+}
+
+fn locked_application_stake(account_id: u64) -> u64 {
+    stake::Module::<Test>::locked_balance(ApplicationLockId::get(), account_id)
+}
+
+#[test]
+fn add_simple_opening_fails_with_zero_unstaking_period() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        AddSimpleOpeningFixture::default()
+            .with_stake_policy(StakePolicy {
+                stake_amount: 100,
+                unstaking_period: 0,
+            })
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::StakePolicyUnstakingPeriodIsZero.into(),
+            ));
+    });
+}
+
+#[test]
+fn add_simple_opening_fails_with_invalid_origin() {
+    build_test_externalities().execute_with(|| {
+        HireLeadFixture::default().hire_lead();
+
+        AddSimpleOpeningFixture::default()
+            .with_origin(RawOrigin::Signed(2))
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::IsNotLeadAccount.into(),
+            ));
+    });
+}
+
+#[test]
+fn fill_simple_opening_succeeds() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let stake_amount = 100;
+        let opening_id = setup_simple_opening(stake_amount, 5, 1);
+
+        increase_total_balance_issuance_using_account_id(2, 1000);
+        increase_total_balance_issuance_using_account_id(3, 1000);
+
+        let successful_application_id =
+            ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+                .with_origin(RawOrigin::Signed(2), 2)
+                .call_and_assert(Ok(()));
+
+        let failed_application_id = ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(3), 3)
+            .call_and_assert(Ok(()));
+
+        assert_eq!(locked_application_stake(2), stake_amount);
+        assert_eq!(locked_application_stake(3), stake_amount);
+        assert_eq!(locked_simple_stake(2), 0);
+        assert_eq!(Balances::usable_balance(&2), 1000 - stake_amount);
+
+        let failed_stake_id = TestWorkingGroup::simple_application_by_id(failed_application_id)
+            .stake_id
+            .unwrap();
+
+        let worker_id =
+            FillSimpleOpeningFixture::default_for_ids(opening_id, vec![successful_application_id])
+                .call_and_assert(Ok(()));
+
+        let mut application_worker_dictionary = BTreeMap::new();
+        application_worker_dictionary.insert(successful_application_id, worker_id);
+
+        EventFixture::assert_last_crate_event(RawEvent::SimpleOpeningFilled(
+            opening_id,
+            application_worker_dictionary,
+        ));
+
+        // The stake of the hired applicant becomes the worker stake.
+        assert_eq!(locked_application_stake(2), 0);
+        assert_eq!(locked_simple_stake(2), stake_amount);
+        assert_eq!(Balances::usable_balance(&2), 1000 - stake_amount);
+
+        // The stake of the unsuccessful applicant is unlocked and removed.
+        assert_eq!(locked_application_stake(3), 0);
+        assert_eq!(Balances::usable_balance(&3), 1000);
+        assert!(!<stake::Stakes<Test>>::contains_key(failed_stake_id));
+        assert!(!<crate::SimpleApplicationById<
+            Test,
+            TestWorkingGroupInstance,
+        >>::contains_key(failed_application_id));
+
+        let terms = TestWorkingGroup::simple_worker_terms(worker_id).unwrap();
+        assert_eq!(terms.staking_account_id, 2);
+        assert_eq!(
+            get_stake_balance(stake::Module::<Test>::stakes(terms.stake_id.unwrap())),
+            stake_amount
+        );
+        assert_eq!(TestWorkingGroup::worker_by_id(worker_id).member_id, 2);
+    });
+}
+
+#[test]
+fn apply_on_simple_opening_fails_with_insufficient_balance() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_simple_opening(100, 5, 1);
+
+        increase_total_balance_issuance_using_account_id(2, 99);
+
+        ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Err(
+                Error::<Test, TestWorkingGroupInstance>::InsufficientBalanceToApply.into(),
+            ));
+    });
+}
+
+#[test]
+fn apply_on_simple_opening_fails_with_existing_member_application() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_simple_opening(100, 5, 1);
+
+        increase_total_balance_issuance_using_account_id(2, 1000);
+
+        let apply_on_opening_fixture =
+            ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+                .with_origin(RawOrigin::Signed(2), 2);
+
+        apply_on_opening_fixture.call_and_assert(Ok(()));
+        apply_on_opening_fixture.call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::MemberHasActiveApplicationOnOpening.into(),
+        ));
+    });
+}
+
+#[test]
+fn withdraw_simple_application_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_simple_opening(100, 5, 1);
+
+        increase_total_balance_issuance_using_account_id(2, 1000);
+
+        let application_id = ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .with_role_account_id(2)
+            .call_and_assert(Ok(()));
+
+        let stake_id = TestWorkingGroup::simple_application_by_id(application_id)
+            .stake_id
+            .unwrap();
+
+        assert_eq!(
+            TestWorkingGroup::withdraw_simple_application(
+                RawOrigin::Signed(2).into(),
+                application_id
+            ),
+            Ok(())
+        );
+
+        assert_eq!(locked_application_stake(2), 0);
+        assert!(!<stake::Stakes<Test>>::contains_key(stake_id));
+        assert!(TestWorkingGroup::simple_opening_by_id(opening_id)
+            .applications
+            .is_empty());
+    });
+}
+
+#[test]
+fn fill_simple_opening_fails_with_invalid_application_id() {
+    build_test_externalities().execute_with(|| {
+        let opening_id = setup_simple_opening(100, 5, 1);
+
+        FillSimpleOpeningFixture::default_for_ids(opening_id, vec![1]).call_and_assert(Err(
+            Error::<Test, TestWorkingGroupInstance>::SuccessfulWorkerApplicationDoesNotExist.into(),
+        ));
+    });
+}
+
+#[test]
+fn simple_worker_reward_is_paid_from_mint() {
+    build_test_externalities().execute_with(|| {
+        /*
+           Events are not emitted on block 0.
+           So any dispatchable calls made during genesis block formation will have no events emitted.
+           https://substrate.dev/recipes/2-appetizers/4-events.html
+        */
+        run_to_block(1);
+
+        let reward_per_block = 5;
+        let opening_id = setup_simple_opening(100, 5, reward_per_block);
+
+        let reward_account_id = 10;
+        increase_total_balance_issuance_using_account_id(2, 1000);
+
+        let application_id = ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .with_reward_account_id(reward_account_id)
+            .call_and_assert(Ok(()));
+
+        let worker_id = FillSimpleOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        let reward_period = RewardPeriod::get();
+        run_to_block(reward_period.into());
+
+        // The worker was hired on the first block.
+        let reward = reward_per_block * (reward_period as u64 - 1);
+        assert_eq!(get_balance(reward_account_id), reward);
+
+        EventFixture::assert_last_crate_event(RawEvent::RewardPaid(worker_id, reward));
+    });
+}
+
+#[test]
+fn simple_worker_stake_is_unlocked_after_unstaking_period() {
+    build_test_externalities().execute_with(|| {
+        let stake_amount = 100;
+        let unstaking_period = 5;
+        let opening_id = setup_simple_opening(stake_amount, unstaking_period, 1);
+
+        increase_total_balance_issuance_using_account_id(2, 1000);
+
+        let application_id = ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Ok(()));
+
+        let worker_id = FillSimpleOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        let stake_id = TestWorkingGroup::simple_worker_terms(worker_id)
+            .unwrap()
+            .stake_id
+            .unwrap();
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        assert_eq!(TestWorkingGroup::simple_worker_terms(worker_id), None);
+        assert_eq!(locked_simple_stake(2), stake_amount);
+        assert!(
+            <crate::UnstakingSimpleStakes<Test, TestWorkingGroupInstance>>::contains_key(stake_id)
+        );
+
+        // The worker left on the block 0, the unstaking ends on the last block of the period.
+        <stake::Module<Test> as OnFinalize<u64>>::on_finalize(unstaking_period - 1);
+
+        assert_eq!(locked_simple_stake(2), 0);
+        assert_eq!(Balances::usable_balance(&2), 1000);
+
+        // The unstaked stake is removed.
+        assert!(!<stake::Stakes<Test>>::contains_key(stake_id));
+        assert!(!<crate::UnstakingSimpleStakes<
+            Test,
+            TestWorkingGroupInstance,
+        >>::contains_key(stake_id));
+    });
+}
+
+#[test]
+fn slash_simple_worker_stake_succeeds() {
+    build_test_externalities().execute_with(|| {
+        let stake_amount = 100;
+        let opening_id = setup_simple_opening(stake_amount, 5, 1);
+
+        increase_total_balance_issuance_using_account_id(2, 1000);
+
+        let application_id = ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .call_and_assert(Ok(()));
+
+        let worker_id = FillSimpleOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        let slash_amount = 40;
+        assert_eq!(
+            TestWorkingGroup::slash_stake(
+                RawOrigin::Signed(1).into(),
+                worker_id,
+                slash_amount,
+                None
+            ),
+            Ok(())
+        );
+
+        assert_eq!(get_balance(2), 1000 - slash_amount);
+        assert_eq!(locked_simple_stake(2), stake_amount - slash_amount);
+
+        let stake_id = TestWorkingGroup::simple_worker_terms(worker_id)
+            .unwrap()
+            .stake_id
+            .unwrap();
+        assert_eq!(
+            get_stake_balance(stake::Module::<Test>::stakes(stake_id)),
+            stake_amount - slash_amount
+        );
+    });
+}
+
+#[test]
+fn leave_simple_worker_role_pays_worked_blocks_and_missed_reward() {
+    build_test_externalities().execute_with(|| {
+        run_to_block(1);
+
+        let reward_per_block = 5;
+        let opening_id = setup_simple_opening(100, 5, reward_per_block);
+
+        let reward_account_id = 10;
+        increase_total_balance_issuance_using_account_id(2, 1000);
+
+        let application_id = ApplyOnSimpleOpeningFixture::default_for_opening_id(opening_id)
+            .with_origin(RawOrigin::Signed(2), 2)
+            .with_reward_account_id(reward_account_id)
+            .call_and_assert(Ok(()));
+
+        let worker_id = FillSimpleOpeningFixture::default_for_ids(opening_id, vec![application_id])
+            .call_and_assert(Ok(()));
+
+        let missed_reward = 7;
+        <crate::SimpleWorkerTermsById<Test, TestWorkingGroupInstance>>::mutate(
+            worker_id,
+            |terms| {
+                if let Some(terms) = terms {
+                    terms.missed_reward = missed_reward;
+                }
+            },
+        );
+
+        run_to_block(4);
+
+        LeaveWorkerRoleFixture::default_for_worker_id(worker_id).call_and_assert(Ok(()));
+
+        // The worker was hired on the first block.
+        let reward = reward_per_block * 3 + missed_reward;
+        assert_eq!(get_balance(reward_account_id), reward);
+        assert_eq!(TestWorkingGroup::simple_worker_terms(worker_id), None);
+    });
+}
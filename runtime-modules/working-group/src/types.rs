@@ -2,6 +2,7 @@
 
 use codec::{Decode, Encode};
 use sp_std::collections::btree_set::BTreeSet;
+use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -236,6 +237,180 @@ pub enum ExitInitiationOrigin {
     Sudo,
 }
 
+/// Performance rating of the worker/lead given in the performance review.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceRating {
+    /// Performance is poor.
+    Poor,
+
+    /// Performance is below expectations.
+    BelowExpectations,
+
+    /// Performance meets expectations.
+    MeetsExpectations,
+
+    /// Performance is above expectations.
+    AboveExpectations,
+
+    /// Performance is excellent.
+    Excellent,
+}
+
+/// Must be default constructable because it indirectly is a value in a storage map.
+/// ***SHOULD NEVER ACTUALLY GET CALLED, IS REQUIRED TO DUE BAD STORAGE MODEL IN SUBSTRATE***
+impl Default for PerformanceRating {
+    fn default() -> Self {
+        Self::MeetsExpectations
+    }
+}
+
+/// Action on the worker/lead referencing the performance review.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub enum ReviewAction<Balance, MintBalance> {
+    /// Reward amount was updated to the new amount.
+    RewardAmountUpdated(MintBalance),
+
+    /// Stake was slashed by the balance.
+    StakeSlashed(Balance),
+}
+
+/// Periodic performance review of the worker/lead filed by the lead (or the council for the lead).
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct PerformanceReview<BlockNumber, Hash, Balance, MintBalance> {
+    /// Performance rating.
+    pub rating: PerformanceRating,
+
+    /// Hash of the review rationale text stored off-chain.
+    pub rationale_hash: Hash,
+
+    /// Block of the review filing.
+    pub reviewed_at: BlockNumber,
+
+    /// Reward updates and slashes referencing the review.
+    pub actions: Vec<ReviewAction<Balance, MintBalance>>,
+}
+
+/// Score and comment given to the application by the reviewer during the opening review period.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct ApplicationScore {
+    /// Score value: the higher the better.
+    pub score: u32,
+
+    /// Reviewer comment explaining the score.
+    pub comment: Vec<u8>,
+}
+
+/// Working group budget with the per-period spending ceiling.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct Budget<BlockNumber, Balance> {
+    /// Maximum spending on the rewards and the discretionary payments in the period.
+    pub spending_ceiling: Balance,
+
+    /// Length of the budget period (in blocks).
+    pub period_length: BlockNumber,
+
+    /// Start block of the current budget period.
+    pub period_started_at: BlockNumber,
+
+    /// Total amount minted by the working group mint before the current period.
+    pub minted_before_period: Balance,
+
+    /// Discretionary payments made in the current period.
+    pub discretionary_spending: Balance,
+}
+
+/// Stake policy of the simplified hiring mode opening.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct StakePolicy<BlockNumber, Balance> {
+    /// Stake amount locked on the applicant staking account.
+    pub stake_amount: Balance,
+
+    /// Unstaking period of the stake after leaving or losing the role (in blocks).
+    pub unstaking_period: BlockNumber,
+}
+
+/// Opening of the simplified hiring mode. Applications are filled directly, without the
+/// hiring stages.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SimpleOpening<BlockNumber, Balance, MintBalance, ApplicationId: Ord> {
+    /// Defines opening type: Leader or worker.
+    pub opening_type: OpeningType,
+
+    /// Block of the opening creation.
+    pub created: BlockNumber,
+
+    /// Opening description.
+    pub description: Vec<u8>,
+
+    /// Stake required to apply on the opening.
+    pub stake_policy: Option<StakePolicy<BlockNumber, Balance>>,
+
+    /// Reward per block paid directly from the working group mint.
+    pub reward_per_block: Option<MintBalance>,
+
+    /// Set of identifiers for the pending applications.
+    pub applications: BTreeSet<ApplicationId>,
+}
+
+/// Application on the simplified hiring mode opening.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SimpleApplication<AccountId, MemberId, OpeningId, StakeId> {
+    /// Account used to authenticate in this role.
+    pub role_account_id: AccountId,
+
+    /// Account receiving the reward.
+    pub reward_account_id: AccountId,
+
+    /// Account with the locked stake.
+    pub staking_account_id: AccountId,
+
+    /// Stake module identifier of the application stake, if the opening requires a stake.
+    pub stake_id: Option<StakeId>,
+
+    /// Member applying.
+    pub member_id: MemberId,
+
+    /// Opening on which this application applies.
+    pub opening_id: OpeningId,
+
+    /// Application description.
+    pub description: Vec<u8>,
+}
+
+/// Stake and reward terms of the worker/lead hired in the simplified hiring mode.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SimpleWorkerTerms<AccountId, BlockNumber, StakeId, MintBalance> {
+    /// Account with the locked stake.
+    pub staking_account_id: AccountId,
+
+    /// Stake module identifier of the worker stake, if the opening required a stake.
+    pub stake_id: Option<StakeId>,
+
+    /// Unstaking period of the stake after leaving or losing the role (in blocks).
+    pub unstaking_period: BlockNumber,
+
+    /// Account receiving the reward.
+    pub reward_account_id: AccountId,
+
+    /// Reward per block paid directly from the working group mint.
+    pub reward_per_block: Option<MintBalance>,
+
+    /// Block of the last reward payment or of the hiring.
+    pub last_reward_at: BlockNumber,
+
+    /// Reward not paid because of the insufficient mint capacity.
+    pub missed_reward: MintBalance,
+}
+
 /// The recurring reward if any to be assigned to an actor when filling in the position.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Debug)]
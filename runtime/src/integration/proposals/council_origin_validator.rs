@@ -28,11 +28,20 @@ impl<T: governance::council::Trait + membership::Trait>
     ) -> Result<<T as frame_system::Trait>::AccountId, &'static str> {
         let account_id = <MembershipOriginValidator<T>>::ensure_actor_origin(origin, actor_id)?;
 
-        if <governance::council::Module<T>>::is_councilor(&account_id) {
+        if <governance::council::Module<T>>::is_councilor(&actor_id) {
             return Ok(account_id);
         }
 
-        Err("Council validation failed: account id doesn't belong to a council member")
+        Err("Council validation failed: member id doesn't belong to a council member")
+    }
+}
+
+impl<T: governance::council::Trait + membership::Trait>
+    proposals_discussion::CouncilMembership<MemberId<T>> for CouncilManager<T>
+{
+    /// Implement is_council_member() as active council membership check
+    fn is_council_member(member_id: &MemberId<T>) -> bool {
+        <governance::council::Module<T>>::is_councilor(member_id)
     }
 }
 
@@ -81,16 +90,6 @@ mod tests {
     #[test]
     fn council_origin_validator_succeeds() {
         initial_test_ext().execute_with(|| {
-            let councilor1 = AccountId32::default();
-            let councilor2: [u8; 32] = [2; 32];
-            let councilor3: [u8; 32] = [3; 32];
-
-            assert!(Council::set_council(
-                frame_system::RawOrigin::Root.into(),
-                vec![councilor1, councilor2.into(), councilor3.into()]
-            )
-            .is_ok());
-
             let account_id = AccountId32::default();
             let origin = RawOrigin::Signed(account_id.clone());
             let authority_account_id = AccountId32::default();
@@ -111,6 +110,10 @@ mod tests {
             .unwrap();
             let member_id = 0; // newly created member_id
 
+            assert!(
+                Council::set_council(frame_system::RawOrigin::Root.into(), vec![member_id]).is_ok()
+            );
+
             let validation_result =
                 CouncilManager::<Runtime>::ensure_actor_origin(origin.into(), member_id);
 
@@ -157,7 +160,7 @@ mod tests {
         initial_test_ext().execute_with(|| {
             let account_id = AccountId32::default();
             let origin = RawOrigin::Signed(account_id.clone());
-            let error = "Council validation failed: account id doesn't belong to a council member";
+            let error = "Council validation failed: member id doesn't belong to a council member";
             let authority_account_id = AccountId32::default();
             Membership::set_screening_authority(
                 RawOrigin::Root.into(),
@@ -186,20 +189,28 @@ mod tests {
     #[test]
     fn council_size_calculation_aka_total_voters_count_succeeds() {
         initial_test_ext().execute_with(|| {
-            let councilor1 = AccountId32::default();
-            let councilor2: [u8; 32] = [2; 32];
-            let councilor3: [u8; 32] = [3; 32];
-            let councilor4: [u8; 32] = [4; 32];
-            assert!(Council::set_council(
-                frame_system::RawOrigin::Root.into(),
-                vec![
-                    councilor1,
-                    councilor2.into(),
-                    councilor3.into(),
-                    councilor4.into()
-                ]
+            let authority_account_id = AccountId32::default();
+            Membership::set_screening_authority(
+                RawOrigin::Root.into(),
+                authority_account_id.clone(),
             )
-            .is_ok());
+            .unwrap();
+
+            for i in 1..=4u8 {
+                let councilor: [u8; 32] = [i; 32];
+                Membership::add_screened_member(
+                    RawOrigin::Signed(authority_account_id.clone()).into(),
+                    councilor.into(),
+                    Some(format!("councilor{}", i).into_bytes()),
+                    None,
+                    None,
+                    None,
+                )
+                .unwrap();
+            }
+            let councilors = vec![0, 1, 2, 3]; // newly created member ids
+
+            assert!(Council::set_council(frame_system::RawOrigin::Root.into(), councilors).is_ok());
 
             assert_eq!(CouncilManager::<Runtime>::total_voters_count(), 4)
         });
@@ -0,0 +1,40 @@
+#![warn(missing_docs)]
+
+use sp_std::marker::PhantomData;
+
+use common::origin::ActorOriginValidator;
+
+use super::{MemberId, MembershipOriginValidator};
+
+/// Proposal discussion moderator origin validator. Councilors and the member owning the forum sudo
+/// account are allowed to moderate discussion posts.
+pub struct DiscussionModeratorOriginValidator<T> {
+    marker: PhantomData<T>,
+}
+
+impl<T: governance::council::Trait + forum::Trait + membership::Trait>
+    ActorOriginValidator<
+        <T as frame_system::Trait>::Origin,
+        MemberId<T>,
+        <T as frame_system::Trait>::AccountId,
+    > for DiscussionModeratorOriginValidator<T>
+{
+    /// Check for valid combination of origin and actor_id. Actor_id should be a councilor or
+    /// a member controlling the forum sudo account.
+    fn ensure_actor_origin(
+        origin: <T as frame_system::Trait>::Origin,
+        actor_id: MemberId<T>,
+    ) -> Result<<T as frame_system::Trait>::AccountId, &'static str> {
+        let account_id = <MembershipOriginValidator<T>>::ensure_actor_origin(origin, actor_id)?;
+
+        if <governance::council::Module<T>>::is_councilor(&actor_id) {
+            return Ok(account_id);
+        }
+
+        if <forum::Module<T>>::forum_sudo() == Some(account_id.clone()) {
+            return Ok(account_id);
+        }
+
+        Err("Moderator validation failed: member id doesn't belong to a councilor or the forum sudo")
+    }
+}
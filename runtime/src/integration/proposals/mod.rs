@@ -2,12 +2,14 @@
 
 mod council_elected_handler;
 mod council_origin_validator;
+mod discussion_moderator_origin_validator;
 mod membership_origin_validator;
 mod proposal_encoder;
 mod staking_events_handler;
 
 pub use council_elected_handler::CouncilElectedHandler;
 pub use council_origin_validator::CouncilManager;
+pub use discussion_moderator_origin_validator::DiscussionModeratorOriginValidator;
 pub use membership_origin_validator::{MemberId, MembershipOriginValidator};
 pub use proposal_encoder::ExtrinsicProposalEncoder;
 pub use staking_events_handler::StakingEventsHandler;
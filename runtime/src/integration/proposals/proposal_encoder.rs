@@ -1,5 +1,6 @@
-use crate::{Call, Runtime};
+use crate::{data_directory, data_object_type_registry, Call, Runtime};
 use common::working_group::WorkingGroup;
+use governance::council::SpendingPurpose;
 use proposals_codex::{ProposalDetails, ProposalDetailsOf, ProposalEncoder};
 use working_group::OpeningType;
 
@@ -7,6 +8,7 @@ use codec::Encode;
 use frame_support::print;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::marker::PhantomData;
+use sp_std::vec;
 use sp_std::vec::Vec;
 
 // The macro binds working group outer-level Call with the provided inner-level working group
@@ -32,7 +34,7 @@ macro_rules! wrap_working_group_call {
 /// using Runtime Call and parity codec.
 pub struct ExtrinsicProposalEncoder;
 impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
-    fn encode_proposal(proposal_details: ProposalDetailsOf<Runtime>) -> Vec<u8> {
+    fn encode_proposal(proposal_id: u32, proposal_details: ProposalDetailsOf<Runtime>) -> Vec<u8> {
         let call = match proposal_details {
             ProposalDetails::Text(text) => {
                 Call::ProposalsCodex(proposals_codex::Call::execute_text_proposal(text))
@@ -40,9 +42,13 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
             ProposalDetails::SetElectionParameters(election_parameters) => Call::CouncilElection(
                 governance::election::Call::set_election_parameters(election_parameters),
             ),
-            ProposalDetails::Spending(balance, destination) => Call::Council(
-                governance::council::Call::spend_from_council_mint(balance, destination),
-            ),
+            ProposalDetails::Spending(balance, destination) => {
+                Call::Council(governance::council::Call::spend_from_council_mint(
+                    balance,
+                    destination,
+                    SpendingPurpose::Proposal(proposal_id),
+                ))
+            }
             ProposalDetails::SetValidatorCount(new_validator_count) => Call::Staking(
                 pallet_staking::Call::set_validator_count(new_validator_count),
             ),
@@ -128,6 +134,68 @@ impl ProposalEncoder<Runtime> for ExtrinsicProposalEncoder {
                     Wg::terminate_role_call(terminate_role_params)
                 )
             }
+            ProposalDetails::SetMembershipInvitationBudget(invitation_budget) => {
+                Call::Members(membership::Call::set_invitation_budget(invitation_budget))
+            }
+            ProposalDetails::SetReferralCut(referral_cut) => {
+                Call::Members(membership::Call::set_referral_cut(referral_cut))
+            }
+            ProposalDetails::VoteOfNoConfidence(no_confidence_params) => Call::Council(
+                governance::council::Call::remove_councilor_by_no_confidence(
+                    no_confidence_params.councilor_id,
+                    no_confidence_params.slash_stake,
+                    no_confidence_params.start_by_election,
+                ),
+            ),
+            ProposalDetails::Batch(proposals) => {
+                Call::ProposalsCodex(proposals_codex::Call::execute_batch_proposal(
+                    proposals
+                        .into_iter()
+                        .map(|details| Self::encode_proposal(proposal_id, details))
+                        .collect(),
+                ))
+            }
+            ProposalDetails::SetGlobalStorageVoucherLimits(size_limit, objects_limit) => {
+                Call::ProposalsCodex(proposals_codex::Call::execute_batch_proposal(vec![
+                    Call::DataDirectory(data_directory::Call::set_global_voucher_size_limit(
+                        size_limit,
+                    ))
+                    .encode(),
+                    Call::DataDirectory(data_directory::Call::set_global_voucher_objects_limit(
+                        objects_limit,
+                    ))
+                    .encode(),
+                ]))
+            }
+            ProposalDetails::SetStorageVoucherLimitUpperBounds(
+                size_limit_upper_bound,
+                objects_limit_upper_bound,
+            ) => Call::ProposalsCodex(proposals_codex::Call::execute_batch_proposal(vec![
+                Call::DataDirectory(data_directory::Call::set_voucher_size_limit_upper_bound(
+                    size_limit_upper_bound,
+                ))
+                .encode(),
+                Call::DataDirectory(data_directory::Call::set_voucher_objects_limit_upper_bound(
+                    objects_limit_upper_bound,
+                ))
+                .encode(),
+            ])),
+            ProposalDetails::SetDataObjectTypeStatus(data_object_type_id, is_active) => {
+                let call = if is_active {
+                    data_object_type_registry::Call::activate_data_object_type(data_object_type_id)
+                } else {
+                    data_object_type_registry::Call::deactivate_data_object_type(
+                        data_object_type_id,
+                    )
+                };
+
+                Call::DataObjectTypeRegistry(call)
+            }
+            ProposalDetails::SetMaxNumberOfCuratorsPerGroup(max_number_of_curators_per_group) => {
+                Call::Content(content::Call::set_max_number_of_curators_per_group(
+                    max_number_of_curators_per_group,
+                ))
+            }
         };
 
         call.encode()
@@ -206,7 +274,7 @@ where
         worker_id: working_group::WorkerId<T>,
         slashing_stake: working_group::BalanceOf<T>,
     ) -> working_group::Call<T, I> {
-        working_group::Call::<T, I>::slash_stake(worker_id, slashing_stake)
+        working_group::Call::<T, I>::slash_stake(worker_id, slashing_stake, None)
     }
 
     // Generic call constructor for the working group 'update reward amount'.
@@ -214,7 +282,7 @@ where
         worker_id: working_group::WorkerId<T>,
         reward_amount: working_group::BalanceOfMint<T>,
     ) -> working_group::Call<T, I> {
-        working_group::Call::<T, I>::update_reward_amount(worker_id, reward_amount)
+        working_group::Call::<T, I>::update_reward_amount(worker_id, reward_amount, None)
     }
 
     // Generic call constructor for the working group 'terminate role'.
@@ -2,7 +2,29 @@ use frame_support::StorageMap;
 use sp_std::marker::PhantomData;
 
 use crate::{ContentDirectoryWorkingGroupInstance, StorageWorkingGroupInstance};
+use common::origin::ActorOriginValidator;
 use stake::{BalanceOf, NegativeImbalance};
+use working_group::{Instance, WorkerId};
+
+/// Validates that the origin is signed by the role account of the provided worker.
+pub struct WorkerOriginValidator<T, I> {
+    marker: PhantomData<(T, I)>,
+}
+
+impl<T: working_group::Trait<I>, I: Instance>
+    ActorOriginValidator<<T as frame_system::Trait>::Origin, WorkerId<T>, T::AccountId>
+    for WorkerOriginValidator<T, I>
+{
+    fn ensure_actor_origin(
+        origin: <T as frame_system::Trait>::Origin,
+        worker_id: WorkerId<T>,
+    ) -> Result<T::AccountId, &'static str> {
+        let worker = <working_group::Module<T, I>>::ensure_worker_signed(origin, &worker_id)
+            .map_err(|_| "Worker validation failed: not a worker role account")?;
+
+        Ok(worker.role_account_id)
+    }
+}
 
 // Will be removed in the next releases.
 #[allow(clippy::upper_case_acronyms)]
@@ -19,6 +41,11 @@ impl<T: stake::Trait + working_group::Trait<ContentDirectoryWorkingGroupInstance
         _unstaked_amount: BalanceOf<T>,
         remaining_imbalance: NegativeImbalance<T>,
     ) -> NegativeImbalance<T> {
+        // Stake of the former worker hired in the simplified hiring mode.
+        <working_group::Module<T, ContentDirectoryWorkingGroupInstance>>::remove_unstaked_simple_stake(
+            *stake_id,
+        );
+
         // Stake not related to a staked role managed by the hiring module.
         if !hiring::ApplicationIdByStakingId::<T>::contains_key(*stake_id) {
             return remaining_imbalance;
@@ -63,6 +90,11 @@ impl<T: stake::Trait + working_group::Trait<StorageWorkingGroupInstance>>
         _unstaked_amount: BalanceOf<T>,
         remaining_imbalance: NegativeImbalance<T>,
     ) -> NegativeImbalance<T> {
+        // Stake of the former worker hired in the simplified hiring mode.
+        <working_group::Module<T, StorageWorkingGroupInstance>>::remove_unstaked_simple_stake(
+            *stake_id,
+        );
+
         // Stake not related to a staked role managed by the hiring module.
         if !hiring::ApplicationIdByStakingId::<T>::contains_key(*stake_id) {
             return remaining_imbalance;
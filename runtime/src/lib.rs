@@ -33,7 +33,7 @@ mod runtime_api;
 mod tests; // Runtime integration tests
 mod weights;
 
-use frame_support::traits::{Currency, KeyOwnerProofSystem, OnUnbalanced};
+use frame_support::traits::{Currency, KeyOwnerProofSystem, LockIdentifier, OnUnbalanced};
 use frame_support::weights::{
     constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight},
     Weight,
@@ -67,7 +67,7 @@ use storage::data_object_storage_registry;
 // Node dependencies
 pub use common;
 pub use forum;
-pub use governance::election_params::ElectionParameters;
+pub use governance::election_params::{ElectionParameters, ElectionTallyMode};
 pub use membership;
 #[cfg(any(feature = "std", test))]
 pub use pallet_balances::Call as BalancesCall;
@@ -85,10 +85,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("joystream-node"),
     impl_name: create_runtime_str!("joystream-node"),
     authoring_version: 9,
-    spec_version: 7,
+    spec_version: 8,
     impl_version: 0,
     apis: crate::runtime_api::EXPORTED_RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
 };
 
 /// The version information used to identify this runtime when compiled natively.
@@ -454,22 +454,42 @@ impl hiring::Trait for Runtime {
     type StakeHandlerProvider = hiring::Module<Self>;
 }
 
+parameter_types! {
+    pub const MaxMintTransferLogLength: u32 = 100;
+}
+
 impl minting::Trait for Runtime {
+    type Event = Event;
     type Currency = <Self as common::currency::GovernanceCurrency>::Currency;
     type MintId = u64;
+    type MaxTransferLogLength = MaxMintTransferLogLength;
+}
+
+parameter_types! {
+    pub const RewardCatchUpPayoutLimit: Balance = 100_000;
 }
 
 impl recurring_rewards::Trait for Runtime {
     type PayoutStatusHandler = (); // TODO - deal with successful and failed payouts
     type RecipientId = u64;
     type RewardRelationshipId = u64;
+    type CatchUpPayoutLimit = RewardCatchUpPayoutLimit;
 }
 
 parameter_types! {
     pub const StakePoolId: [u8; 8] = *b"joystake";
+    // Balance lock ids of the stakes locked on the staker accounts per purpose: the proposal
+    // stakes, the council candidacy stakes and the working group simplified hiring mode worker
+    // and application stakes. The council voting stakes stay reserved and the hiring module
+    // stakes stay in the stake pool.
+    pub const ProposalsLockId: LockIdentifier = *b"proposal";
+    pub const CouncilLockId: LockIdentifier = *b"council ";
+    pub const WorkingGroupRoleLockId: LockIdentifier = *b"wg-role ";
+    pub const WorkingGroupApplicationLockId: LockIdentifier = *b"wg-apply";
 }
 
 impl stake::Trait for Runtime {
+    type Event = Event;
     type Currency = <Self as common::currency::GovernanceCurrency>::Currency;
     type StakePoolId = StakePoolId;
     type StakingEventsHandler = (
@@ -502,11 +522,14 @@ impl common::StorageOwnership for Runtime {
 impl governance::election::Trait for Runtime {
     type Event = Event;
     type CouncilElected = (Council, integration::proposals::CouncilElectedHandler);
+    type MembershipOriginValidator = MembershipOriginValidator<Self>;
 }
 
 impl governance::council::Trait for Runtime {
     type Event = Event;
     type CouncilTermEnded = (CouncilElection,);
+    type CouncilSeatVacated = (CouncilElection,);
+    type CouncilLockId = CouncilLockId;
 }
 
 impl memo::Trait for Runtime {
@@ -544,6 +567,8 @@ impl membership::Trait for Runtime {
     type SubscriptionId = u64;
     type ActorId = ActorId;
     type ScreenedMemberMaxInitialBalance = ScreenedMemberMaxInitialBalance;
+    type IdentityVerifierOriginValidator =
+        integration::working_group::WorkerOriginValidator<Self, OperationsWorkingGroupInstance>;
 }
 
 impl forum::Trait for Runtime {
@@ -567,26 +592,39 @@ pub type GatewayWorkingGroupInstance = working_group::Instance5;
 
 parameter_types! {
     pub const MaxWorkerNumberLimit: u32 = 100;
+    pub const WorkingGroupRewardPeriod: u32 = 14400;
 }
 
 impl working_group::Trait<StorageWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = WorkingGroupRoleLockId;
+    type ApplicationLockId = WorkingGroupApplicationLockId;
+    type RewardPeriod = WorkingGroupRewardPeriod;
 }
 
 impl working_group::Trait<ContentDirectoryWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = WorkingGroupRoleLockId;
+    type ApplicationLockId = WorkingGroupApplicationLockId;
+    type RewardPeriod = WorkingGroupRewardPeriod;
 }
 
 impl working_group::Trait<OperationsWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = WorkingGroupRoleLockId;
+    type ApplicationLockId = WorkingGroupApplicationLockId;
+    type RewardPeriod = WorkingGroupRewardPeriod;
 }
 
 impl working_group::Trait<GatewayWorkingGroupInstance> for Runtime {
     type Event = Event;
     type MaxWorkerNumberLimit = MaxWorkerNumberLimit;
+    type StakingLockId = WorkingGroupRoleLockId;
+    type ApplicationLockId = WorkingGroupApplicationLockId;
+    type RewardPeriod = WorkingGroupRewardPeriod;
 }
 
 parameter_types! {
@@ -594,6 +632,7 @@ parameter_types! {
     pub const ProposalRejectionFee: u64 = 5000;
     pub const ProposalTitleMaxLength: u32 = 40;
     pub const ProposalDescriptionMaxLength: u32 = 3000;
+    pub const ProposalVoteRationaleMaxLength: u32 = 3000;
     pub const ProposalMaxActiveProposalLimit: u32 = 20;
 }
 
@@ -603,11 +642,12 @@ impl proposals_engine::Trait for Runtime {
     type VoterOriginValidator = CouncilManager<Self>;
     type TotalVotersCounter = CouncilManager<Self>;
     type ProposalId = u32;
-    type StakeHandlerProvider = proposals_engine::DefaultStakeHandlerProvider;
+    type StakeHandlerProvider = proposals_engine::LockStakeHandlerProvider<ProposalsLockId>;
     type CancellationFee = ProposalCancellationFee;
     type RejectionFee = ProposalRejectionFee;
     type TitleMaxLength = ProposalTitleMaxLength;
     type DescriptionMaxLength = ProposalDescriptionMaxLength;
+    type VoteRationaleMaxLength = ProposalVoteRationaleMaxLength;
     type MaxActiveProposalLimit = ProposalMaxActiveProposalLimit;
     type DispatchableCallCode = Call;
 }
@@ -622,6 +662,7 @@ parameter_types! {
     pub const ProposalMaxThreadInARowNumber: u32 = 100_000; // will not be used
     pub const ProposalThreadTitleLengthLimit: u32 = 40;
     pub const ProposalPostLengthLimit: u32 = 1000;
+    pub const ProposalMaxWhiteListSize: u32 = 20;
 }
 
 impl proposals_discussion::Trait for Runtime {
@@ -633,6 +674,10 @@ impl proposals_discussion::Trait for Runtime {
     type ThreadTitleLengthLimit = ProposalThreadTitleLengthLimit;
     type PostLengthLimit = ProposalPostLengthLimit;
     type MaxThreadInARowNumber = ProposalMaxThreadInARowNumber;
+    type MaxWhiteListSize = ProposalMaxWhiteListSize;
+    type CouncilMembership = CouncilManager<Self>;
+    type ModeratorOriginValidator =
+        integration::proposals::DiscussionModeratorOriginValidator<Self>;
 }
 
 parameter_types! {
@@ -701,8 +746,8 @@ construct_runtime!(
         Memo: memo::{Module, Call, Storage, Event<T>},
         Members: membership::{Module, Call, Storage, Event<T>, Config<T>},
         Forum: forum::{Module, Call, Storage, Event<T>, Config<T>},
-        Stake: stake::{Module, Call, Storage},
-        Minting: minting::{Module, Call, Storage},
+        Stake: stake::{Module, Call, Storage, Event<T>},
+        Minting: minting::{Module, Call, Storage, Event<T>},
         RecurringRewards: recurring_rewards::{Module, Call, Storage},
         Hiring: hiring::{Module, Call, Storage},
         Content: content::{Module, Call, Storage, Event<T>, Config<T>},
@@ -125,6 +125,26 @@ impl OnRuntimeUpgrade for CustomOnRuntimeUpgrade {
             default_storage_size_constraint,
         );
 
+        // Initialize the voting and grace periods of the new proposal types
+        proposals_codex::Module::<Runtime>::migrate_config_values(
+            proposals_codex::ProposalsConfigParameters::default(),
+        );
+
+        // Existing members get the initial invitations
+        membership::Module::<Runtime>::migrate_member_invites();
+
+        // Council seats are now held by members
+        governance::council::Module::<Runtime>::migrate_active_council();
+
+        // Council elections are now member id based, the running election is stopped
+        governance::election::Module::<Runtime>::migrate_election_in_progress();
+
+        // Recurring rewards are now paid out using the payout schedule
+        recurring_rewards::Module::<Runtime>::migrate_payout_schedule();
+
+        // Slashes and unstakings are now finalized using the schedules
+        stake::Module::<Runtime>::migrate_slashing_and_unstaking_schedules();
+
         10_000_000 // TODO: adjust weight
     }
 }
@@ -298,6 +318,28 @@ impl_runtime_apis! {
         }
     }
 
+    impl governance::runtime_api::CouncilBudgetApi<Block, Balance> for Runtime {
+        fn remaining_budget() -> Option<Balance> {
+            governance::council::Module::<Runtime>::current_remaining_budget()
+        }
+
+        fn budget_per_term() -> Option<Balance> {
+            governance::council::Module::<Runtime>::budget_per_term()
+        }
+    }
+
+    impl minting::runtime_api::MintApi<Block, u64, AccountId, Balance, BlockNumber> for Runtime {
+        fn mint(mint_id: u64) -> Option<minting::Mint<Balance, BlockNumber>> {
+            minting::Module::<Runtime>::get_mint(mint_id).ok()
+        }
+
+        fn recent_transfers(
+            mint_id: u64,
+        ) -> Vec<minting::MintTransfer<AccountId, Balance, BlockNumber>> {
+            minting::Module::<Runtime>::mint_transfers(mint_id)
+        }
+    }
+
     impl sp_session::SessionKeys<Block> for Runtime {
         fn generate_session_keys(seed: Option<Vec<u8>>) -> Vec<u8> {
             SessionKeys::generate(seed)
@@ -6,8 +6,9 @@ mod working_group_proposals;
 
 use crate::{BlockNumber, ProposalCancellationFee, Runtime};
 use codec::Encode;
-use governance::election_params::ElectionParameters;
+use governance::election_params::{ElectionParameters, ElectionTallyMode};
 use membership;
+use proposals_codex::ProposalDetails;
 use proposals_engine::{
     ActiveStake, ApprovedProposalStatus, BalanceOf, FinalizationData, Proposal,
     ProposalDecisionStatus, ProposalParameters, ProposalStatus, VoteKind, VotersParameters,
@@ -31,6 +32,8 @@ pub type ProposalsEngine = proposals_engine::Module<Runtime>;
 pub type Council = governance::council::Module<Runtime>;
 pub type Election = governance::election::Module<Runtime>;
 pub type ProposalCodex = proposals_codex::Module<Runtime>;
+pub type DataDirectory = storage::data_directory::Module<Runtime>;
+pub type Content = content::Module<Runtime>;
 
 fn setup_members(count: u8) {
     let authority_account_id = <Runtime as frame_system::Trait>::AccountId::default();
@@ -52,24 +55,9 @@ fn setup_members(count: u8) {
 }
 
 fn setup_council() {
-    let councilor0 = AccountId32::default();
-    let councilor1: [u8; 32] = [1; 32];
-    let councilor2: [u8; 32] = [2; 32];
-    let councilor3: [u8; 32] = [3; 32];
-    let councilor4: [u8; 32] = [4; 32];
-    let councilor5: [u8; 32] = [5; 32];
-    assert!(Council::set_council(
-        frame_system::RawOrigin::Root.into(),
-        vec![
-            councilor0,
-            councilor1.into(),
-            councilor2.into(),
-            councilor3.into(),
-            councilor4.into(),
-            councilor5.into()
-        ]
-    )
-    .is_ok());
+    // member ids created by setup_members()
+    let councilors = vec![0, 1, 2, 3, 4, 5];
+    assert!(Council::set_council(frame_system::RawOrigin::Root.into(), councilors).is_ok());
 }
 
 pub(crate) fn increase_total_balance_issuance_using_account_id(
@@ -137,6 +125,7 @@ impl VoteGenerator {
             self.current_voter_id,
             self.proposal_id,
             vote_kind,
+            None,
         )
     }
 }
@@ -221,6 +210,7 @@ impl DummyProposalFixture {
             self.description,
             self.stake_balance,
             self.proposal_code,
+            None,
         );
         assert_eq!(proposal_id_result, result);
 
@@ -302,8 +292,14 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
         );
 
         let proposal_id = dummy_proposal.create_proposal_and_assert(Ok(1)).unwrap();
+
+        // the stake is locked on the proposer account
         assert_eq!(
             <Runtime as stake::Trait>::Currency::total_balance(&account_id),
+            account_starting_balance + account_top_up
+        );
+        assert_eq!(
+            Balances::usable_balance(&account_id),
             account_starting_balance + account_top_up - stake_amount
         );
 
@@ -351,7 +347,7 @@ fn proposal_cancellation_with_slashes_with_balance_checks_succeeds() {
 #[test]
 fn proposal_reset_succeeds() {
     initial_test_ext().execute_with(|| {
-        setup_members(4);
+        setup_members(6);
         setup_council();
         // create proposal
         let dummy_proposal = DummyProposalFixture::default().with_voting_period(100);
@@ -427,6 +423,7 @@ fn elect_single_councilor() {
             new_term_duration: 2000000,
             min_council_stake: 0,
             min_voting_stake: 0,
+            tally_mode: ElectionTallyMode::TopStaked,
         },
     );
     assert_eq!(res, Ok(()));
@@ -437,7 +434,12 @@ fn elect_single_councilor() {
     let councilor1: [u8; 32] = [1; 32];
     increase_total_balance_issuance_using_account_id(councilor1.clone().into(), 1200000000);
 
-    let res = Election::apply(RawOrigin::Signed(councilor1.into()).into(), 0);
+    let councilor1_member_id = 1;
+    let res = Election::apply(
+        RawOrigin::Signed(councilor1.into()).into(),
+        councilor1_member_id,
+        0,
+    );
     assert_eq!(res, Ok(()));
 
     run_to_block(5);
@@ -564,6 +566,7 @@ fn text_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(25000u32)),
                 b"text".to_vec(),
+                None,
             )
         })
         .with_member_id(member_id as u64);
@@ -595,6 +598,7 @@ fn spending_proposal_execution_succeeds() {
                 Some(<BalanceOf<Runtime>>::from(25_000_u32)),
                 new_balance,
                 target_account_id.clone().into(),
+                None,
             )
         })
         .with_member_id(member_id as u64);
@@ -624,6 +628,7 @@ fn set_election_parameters_proposal_execution_succeeds() {
             new_term_duration: 14400,
             min_council_stake: 1,
             min_voting_stake: 1,
+            tally_mode: ElectionTallyMode::SequentialPhragmen,
         };
         assert_eq!(Election::announcing_period(), 0);
 
@@ -635,11 +640,16 @@ fn set_election_parameters_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(1_000_000_u32)),
                 election_parameters,
+                None,
             )
         });
         codex_extrinsic_test_fixture.call_extrinsic_and_assert();
 
         assert_eq!(Election::announcing_period(), 14400);
+        assert_eq!(
+            Election::tally_mode(),
+            ElectionTallyMode::SequentialPhragmen
+        );
     });
 }
 
@@ -660,6 +670,7 @@ fn set_validator_count_proposal_execution_succeeds() {
                 b"body".to_vec(),
                 Some(<BalanceOf<Runtime>>::from(500_000_u32)),
                 new_validator_count,
+                None,
             )
         });
         codex_extrinsic_test_fixture.call_extrinsic_and_assert();
@@ -667,3 +678,304 @@ fn set_validator_count_proposal_execution_succeeds() {
         assert_eq!(<pallet_staking::ValidatorCount>::get(), new_validator_count);
     });
 }
+
+#[test]
+fn set_membership_invitation_budget_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let new_invitation_budget = 100;
+        assert_eq!(Membership::invitation_budget(), 0);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_membership_invitation_budget_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                new_invitation_budget,
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(Membership::invitation_budget(), new_invitation_budget);
+    });
+}
+
+#[test]
+fn set_referral_cut_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let new_referral_cut = 20;
+        assert_eq!(Membership::referral_cut(), 0);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_referral_cut_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                new_referral_cut,
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(Membership::referral_cut(), new_referral_cut);
+    });
+}
+
+#[test]
+fn batch_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        assert_eq!(Membership::invitation_budget(), 0);
+        assert_eq!(Membership::referral_cut(), 0);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                vec![
+                    ProposalDetails::SetMembershipInvitationBudget(100),
+                    ProposalDetails::SetReferralCut(20),
+                ],
+                None,
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(Membership::invitation_budget(), 100);
+        assert_eq!(Membership::referral_cut(), 20);
+    });
+}
+
+#[test]
+fn batch_proposal_execution_is_reverted_on_failure() {
+    initial_test_ext().execute_with(|| {
+        setup_members(NUMBER_OF_MEMBERS_TO_SETUP_IN_CODEX_PROPOSAL_FIXTURE);
+        setup_council();
+
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        increase_total_balance_issuance_using_account_id(account_id.clone().into(), 1_000_000);
+
+        // the council mint has no capacity, so the spending fails
+        assert_eq!(
+            ProposalCodex::create_batch_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                vec![
+                    ProposalDetails::SetReferralCut(20),
+                    ProposalDetails::Spending(100, account_id.clone().into()),
+                ],
+                None,
+                None,
+            ),
+            Ok(())
+        );
+
+        let proposal_id = 1;
+        let mut vote_generator = VoteGenerator::new(proposal_id);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+        vote_generator.vote_and_assert_ok(VoteKind::Approve);
+
+        run_to_block(2);
+
+        assert!(matches!(
+            ProposalsEngine::proposals(proposal_id).status,
+            ProposalStatus::Finalized(FinalizationData {
+                proposal_status: ProposalDecisionStatus::Approved(
+                    ApprovedProposalStatus::ExecutionFailed { .. }
+                ),
+                ..
+            })
+        ));
+        assert_eq!(Membership::referral_cut(), 0);
+    });
+}
+
+#[test]
+fn vote_of_no_confidence_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        // setup_council() makes the members 0 to 5 councilors, the fixture votes with 1 to 5
+        let councilor_id = 0;
+
+        // councilors can be removed during the council term only
+        <governance::council::TermEndsAt<Runtime>>::put(100);
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_vote_of_no_confidence_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(100_000_u32)),
+                proposals_codex::NoConfidenceParameters {
+                    councilor_id,
+                    slash_stake: false,
+                    start_by_election: false,
+                },
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert!(!Council::is_councilor(&councilor_id));
+        assert_eq!(CouncilManager::<Runtime>::total_voters_count(), 5);
+    });
+}
+
+#[test]
+fn vote_of_no_confidence_proposal_target_cannot_vote() {
+    initial_test_ext().execute_with(|| {
+        setup_members(6);
+        setup_council();
+
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+        increase_total_balance_issuance_using_account_id(account_id.clone().into(), 1_000_000);
+
+        let councilor_id = 2;
+        assert_eq!(
+            ProposalCodex::create_vote_of_no_confidence_proposal(
+                RawOrigin::Signed(account_id.into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(100_000_u32)),
+                proposals_codex::NoConfidenceParameters {
+                    councilor_id,
+                    slash_stake: false,
+                    start_by_election: false,
+                },
+                None,
+            ),
+            Ok(())
+        );
+
+        let councilor_account: [u8; 32] = [councilor_id as u8; 32];
+        assert_eq!(
+            ProposalsEngine::vote(
+                RawOrigin::Signed(councilor_account.into()).into(),
+                councilor_id,
+                1,
+                VoteKind::Reject,
+                None,
+            ),
+            Err(proposals_engine::Error::<Runtime>::VoterExcluded.into())
+        );
+    });
+}
+
+#[test]
+fn set_global_storage_voucher_limits_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let new_size_limit = 1_000_000;
+        let new_objects_limit = 1_000;
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_global_storage_voucher_limits_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                new_size_limit,
+                new_objects_limit,
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        let global_voucher = DataDirectory::global_voucher();
+        assert_eq!(global_voucher.get_size_limit(), new_size_limit);
+        assert_eq!(global_voucher.get_objects_limit(), new_objects_limit);
+    });
+}
+
+#[test]
+fn set_storage_voucher_limit_upper_bounds_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let new_size_limit_upper_bound = 2_000_000;
+        let new_objects_limit_upper_bound = 2_000;
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_storage_voucher_limit_upper_bounds_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                new_size_limit_upper_bound,
+                new_objects_limit_upper_bound,
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            DataDirectory::voucher_size_limit_upper_bound(),
+            new_size_limit_upper_bound
+        );
+        assert_eq!(
+            DataDirectory::voucher_objects_limit_upper_bound(),
+            new_objects_limit_upper_bound
+        );
+    });
+}
+
+#[test]
+fn set_max_number_of_curators_per_group_proposal_execution_succeeds() {
+    initial_test_ext().execute_with(|| {
+        let member_id = 1;
+        let account_id: [u8; 32] = [member_id; 32];
+
+        let new_max_number_of_curators_per_group = 5;
+
+        let codex_extrinsic_test_fixture = CodexProposalTestFixture::default_for_call(|| {
+            ProposalCodex::create_set_max_number_of_curators_per_group_proposal(
+                RawOrigin::Signed(account_id.clone().into()).into(),
+                member_id as u64,
+                b"title".to_vec(),
+                b"body".to_vec(),
+                Some(<BalanceOf<Runtime>>::from(25_000_u32)),
+                new_max_number_of_curators_per_group,
+                None,
+            )
+        });
+        codex_extrinsic_test_fixture.call_extrinsic_and_assert();
+
+        assert_eq!(
+            Content::max_number_of_curators_per_group(),
+            new_max_number_of_curators_per_group
+        );
+    });
+}
@@ -86,6 +86,7 @@ fn add_opening(
                 human_readable_text: Vec::new(),
                 working_group,
             },
+            None,
         )
     })
     .with_expected_proposal_id(expected_proposal_id)
@@ -115,6 +116,7 @@ fn begin_review_applications(
             Some(<BalanceOf<Runtime>>::from(25_000_u32)),
             opening_id,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -149,6 +151,7 @@ fn fill_opening(
                 reward_policy: reward_policy.clone(),
                 working_group,
             },
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -187,6 +190,7 @@ fn decrease_stake(
             leader_worker_id,
             stake_amount,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -217,6 +221,7 @@ fn slash_stake(
             leader_worker_id,
             stake_amount,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -247,6 +252,7 @@ fn set_reward(
             leader_worker_id,
             reward_amount,
             working_group,
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -288,6 +294,7 @@ fn set_mint_capacity<
             Some(<BalanceOf<Runtime>>::from(50_000_u32)),
             mint_capacity,
             working_group,
+            None,
         )
     })
     .with_setup_enviroment(setup_environment)
@@ -321,6 +328,7 @@ fn terminate_role(
                 slash,
                 working_group,
             },
+            None,
         )
     })
     .disable_setup_enviroment()
@@ -191,7 +191,7 @@ class IdentitiesApi {
    * using default policy 0, returns new member id
    */
   async registerMember(accountId, userInfo) {
-    const tx = this.base.api.tx.members.buyMembership(0, userInfo.handle, userInfo.avatarUri, userInfo.about)
+    const tx = this.base.api.tx.members.buyMembership(0, userInfo.handle, userInfo.avatarUri, userInfo.about, null)
 
     return this.base.signAndSendThenGetEventResult(accountId, tx, {
       module: 'members',
@@ -144,7 +144,13 @@ export class Api {
 
   public async buyMembership(account: string, paidTermsId: PaidTermId, name: string): Promise<ISubmittableResult> {
     return this.sender.signAndSend(
-      this.api.tx.members.buyMembership(paidTermsId, /* Handle: */ name, /* Avatar uri: */ '', /* About: */ ''),
+      this.api.tx.members.buyMembership(
+        paidTermsId,
+        /* Handle: */ name,
+        /* Avatar uri: */ '',
+        /* About: */ '',
+        /* Referrer id: */ null
+      ),
       account
     )
   }
@@ -187,32 +193,41 @@ export class Api {
 
   public estimateBuyMembershipFee(account: string, paidTermsId: PaidTermId, name: string): BN {
     return this.estimateTxFee(
-      this.api.tx.members.buyMembership(paidTermsId, /* Handle: */ name, /* Avatar uri: */ '', /* About: */ '')
+      this.api.tx.members.buyMembership(
+        paidTermsId,
+        /* Handle: */ name,
+        /* Avatar uri: */ '',
+        /* About: */ '',
+        /* Referrer id: */ null
+      )
     )
   }
 
   public estimateApplyForCouncilFee(amount: BN): BN {
-    return this.estimateTxFee(this.api.tx.councilElection.apply(amount))
+    return this.estimateTxFee(this.api.tx.councilElection.apply(this.api.createType('MemberId', 0), amount))
   }
 
-  public estimateVoteForCouncilFee(nominee: string, salt: string, stake: BN): BN {
-    const hashedVote: string = Utils.hashVote(nominee, salt)
+  public estimateVoteForCouncilFee(salt: string, stake: BN): BN {
+    const hashedVote: string = Utils.hashVote(this.api.createType('MemberId', 0), salt)
     return this.estimateTxFee(this.api.tx.councilElection.vote(hashedVote, stake))
   }
 
-  public estimateRevealVoteFee(nominee: string, salt: string): BN {
+  public estimateRevealVoteFee(salt: string): BN {
+    const nominee: MemberId = this.api.createType('MemberId', 0)
     const hashedVote: string = Utils.hashVote(nominee, salt)
     return this.estimateTxFee(this.api.tx.councilElection.reveal(hashedVote, nominee, salt))
   }
 
   public estimateProposeRuntimeUpgradeFee(stake: BN, name: string, description: string, runtime: Bytes | string): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(stake, name, description, stake, runtime)
+      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(stake, name, description, stake, runtime, null)
     )
   }
 
   public estimateProposeTextFee(stake: BN, name: string, description: string, text: string): BN {
-    return this.estimateTxFee(this.api.tx.proposalsCodex.createTextProposal(stake, name, description, stake, text))
+    return this.estimateTxFee(
+      this.api.tx.proposalsCodex.createTextProposal(stake, name, description, stake, text, null)
+    )
   }
 
   public estimateProposeSpendingFee(
@@ -223,13 +238,13 @@ export class Api {
     destination: string
   ): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createSpendingProposal(stake, title, description, stake, balance, destination)
+      this.api.tx.proposalsCodex.createSpendingProposal(stake, title, description, stake, balance, destination, null)
     )
   }
 
   public estimateProposeValidatorCountFee(title: string, description: string, stake: BN): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createSetValidatorCountProposal(stake, title, description, stake, stake)
+      this.api.tx.proposalsCodex.createSetValidatorCountProposal(stake, title, description, stake, stake, null)
     )
   }
 
@@ -247,16 +262,23 @@ export class Api {
     minVotingStake: BN
   ): BN {
     return this.estimateTxFee(
-      this.api.tx.proposalsCodex.createSetElectionParametersProposal(stake, title, description, stake, {
-        announcing_period: announcingPeriod,
-        voting_period: votingPeriod,
-        revealing_period: revealingPeriod,
-        council_size: councilSize,
-        candidacy_limit: candidacyLimit,
-        new_term_duration: newTermDuration,
-        min_council_stake: minCouncilStake,
-        min_voting_stake: minVotingStake,
-      })
+      this.api.tx.proposalsCodex.createSetElectionParametersProposal(
+        stake,
+        title,
+        description,
+        stake,
+        {
+          announcing_period: announcingPeriod,
+          voting_period: votingPeriod,
+          revealing_period: revealingPeriod,
+          council_size: councilSize,
+          candidacy_limit: candidacyLimit,
+          new_term_duration: newTermDuration,
+          min_council_stake: minCouncilStake,
+          min_voting_stake: minVotingStake,
+        },
+        null
+      )
     )
   }
 
@@ -265,16 +287,15 @@ export class Api {
       this.api.tx.proposalsEngine.vote(
         this.api.createType('MemberId', 0),
         this.api.createType('ProposalId', 0),
-        'Approve'
+        'Approve',
+        null
       )
     )
   }
 
   public estimateAddOpeningFee(module: WorkingGroups): BN {
     const commitment: OpeningPolicyCommitment = this.api.createType('OpeningPolicyCommitment', {
-      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', {
-        max_active_applicants: new BN(32) as u32,
-      }),
+      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', null),
       max_review_period_length: new BN(32) as u32,
       application_staking_policy: this.api.createType('Option<StakingPolicy>', {
         amount: new BN(1),
@@ -376,7 +397,7 @@ export class Api {
   }
 
   public estimateSlashStakeFee(module: WorkingGroups): BN {
-    return this.estimateTxFee(this.api.tx[module].slashStake(this.api.createType('WorkerId', 0), 0))
+    return this.estimateTxFee(this.api.tx[module].slashStake(this.api.createType('WorkerId', 0), 0, null))
   }
 
   public estimateTerminateRoleFee(module: WorkingGroups): BN {
@@ -391,9 +412,7 @@ export class Api {
 
   public estimateProposeCreateWorkingGroupLeaderOpeningFee(): BN {
     const commitment: OpeningPolicyCommitment = this.api.createType('OpeningPolicyCommitment', {
-      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', {
-        max_active_applicants: new BN(32) as u32,
-      }),
+      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', null),
       max_review_period_length: new BN(32) as u32,
       application_staking_policy: this.api.createType('Option<StakingPolicy>', {
         amount: new BN(1),
@@ -439,7 +458,8 @@ export class Api {
           commitment: commitment,
           human_readable_text: 'Opening readable text',
           working_group: 'Storage',
-        }
+        },
+        null
       )
     )
   }
@@ -452,7 +472,8 @@ export class Api {
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         null,
         this.api.createType('OpeningId', 0),
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -475,7 +496,8 @@ export class Api {
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         null,
-        fillOpeningParameters
+        fillOpeningParameters,
+        null
       )
     )
   }
@@ -492,7 +514,8 @@ export class Api {
           'rationale': 'Exceptionaly long and extraordinary descriptive rationale',
           'slash': true,
           'working_group': 'Storage',
-        }
+        },
+        null
       )
     )
   }
@@ -506,7 +529,8 @@ export class Api {
         null,
         this.api.createType('WorkerId', 0),
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -520,7 +544,8 @@ export class Api {
         null,
         this.api.createType('WorkerId', 0),
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -534,7 +559,8 @@ export class Api {
         null,
         this.api.createType('WorkerId', 0),
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
@@ -547,13 +573,15 @@ export class Api {
         'Some testing text used for estimation purposes which is longer than text expected during the test',
         null,
         0,
-        'Storage'
+        'Storage',
+        null
       )
     )
   }
 
-  private applyForCouncilElection(account: string, amount: BN): Promise<ISubmittableResult> {
-    return this.sender.signAndSend(this.api.tx.councilElection.apply(amount), account)
+  private async applyForCouncilElection(account: string, amount: BN): Promise<ISubmittableResult> {
+    const memberId: MemberId = (await this.getMemberIds(account))[0]
+    return this.sender.signAndSend(this.api.tx.councilElection.apply(memberId, amount), account)
   }
 
   public batchApplyForCouncilElection(accounts: string[], amount: BN): Promise<ISubmittableResult[]> {
@@ -561,11 +589,18 @@ export class Api {
   }
 
   public async getCouncilElectionStake(address: string): Promise<BN> {
-    return (((await this.api.query.councilElection.applicantStakes(address)) as unknown) as ElectionStake).new
+    const memberId: MemberId = (await this.getMemberIds(address))[0]
+    return (((await this.api.query.councilElection.applicantStakes(memberId)) as unknown) as ElectionStake).new
   }
 
-  private voteForCouncilMember(account: string, nominee: string, salt: string, stake: BN): Promise<ISubmittableResult> {
-    const hashedVote: string = Utils.hashVote(nominee, salt)
+  private async voteForCouncilMember(
+    account: string,
+    nominee: string,
+    salt: string,
+    stake: BN
+  ): Promise<ISubmittableResult> {
+    const nomineeId: MemberId = (await this.getMemberIds(nominee))[0]
+    const hashedVote: string = Utils.hashVote(nomineeId, salt)
     return this.sender.signAndSend(this.api.tx.councilElection.vote(hashedVote, stake), account)
   }
 
@@ -580,15 +615,21 @@ export class Api {
     )
   }
 
-  private revealVote(account: string, commitment: string, nominee: string, salt: string): Promise<ISubmittableResult> {
+  private revealVote(
+    account: string,
+    commitment: string,
+    nominee: MemberId,
+    salt: string
+  ): Promise<ISubmittableResult> {
     return this.sender.signAndSend(this.api.tx.councilElection.reveal(commitment, nominee, salt), account)
   }
 
   public batchRevealVote(accounts: string[], nominees: string[], salt: string[]): Promise<ISubmittableResult[]> {
     return Promise.all(
       accounts.map(async (account, index) => {
-        const commitment = Utils.hashVote(nominees[index], salt[index])
-        return this.revealVote(account, commitment, nominees[index], salt[index])
+        const nomineeId: MemberId = (await this.getMemberIds(nominees[index]))[0]
+        const commitment = Utils.hashVote(nomineeId, salt[index])
+        return this.revealVote(account, commitment, nomineeId, salt[index])
       })
     )
   }
@@ -621,7 +662,7 @@ export class Api {
 
   public async getCouncilAccounts(): Promise<string[]> {
     const council = await this.getCouncil()
-    return council.map((seat) => seat.member.toString())
+    return council.map((seat) => seat.account.toString())
   }
 
   public async proposeRuntime(
@@ -633,7 +674,7 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(memberId, name, description, stake, runtime),
+      this.api.tx.proposalsCodex.createRuntimeUpgradeProposal(memberId, name, description, stake, runtime, null),
       account
     )
   }
@@ -647,7 +688,7 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createTextProposal(memberId, name, description, stake, text),
+      this.api.tx.proposalsCodex.createTextProposal(memberId, name, description, stake, text, null),
       account
     )
   }
@@ -662,7 +703,15 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createSpendingProposal(memberId, title, description, stake, balance, destination),
+      this.api.tx.proposalsCodex.createSpendingProposal(
+        memberId,
+        title,
+        description,
+        stake,
+        balance,
+        destination,
+        null
+      ),
       account
     )
   }
@@ -676,7 +725,14 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createSetValidatorCountProposal(memberId, title, description, stake, validatorCount),
+      this.api.tx.proposalsCodex.createSetValidatorCountProposal(
+        memberId,
+        title,
+        description,
+        stake,
+        validatorCount,
+        null
+      ),
       account
     )
   }
@@ -697,16 +753,23 @@ export class Api {
   ): Promise<ISubmittableResult> {
     const memberId: MemberId = (await this.getMemberIds(account))[0]
     return this.sender.signAndSend(
-      this.api.tx.proposalsCodex.createSetElectionParametersProposal(memberId, title, description, stake, {
-        announcing_period: announcingPeriod,
-        voting_period: votingPeriod,
-        revealing_period: revealingPeriod,
-        council_size: councilSize,
-        candidacy_limit: candidacyLimit,
-        new_term_duration: newTermDuration,
-        min_council_stake: minCouncilStake,
-        min_voting_stake: minVotingStake,
-      }),
+      this.api.tx.proposalsCodex.createSetElectionParametersProposal(
+        memberId,
+        title,
+        description,
+        stake,
+        {
+          announcing_period: announcingPeriod,
+          voting_period: votingPeriod,
+          revealing_period: revealingPeriod,
+          council_size: councilSize,
+          candidacy_limit: candidacyLimit,
+          new_term_duration: newTermDuration,
+          min_council_stake: minCouncilStake,
+          min_voting_stake: minVotingStake,
+        },
+        null
+      ),
       account
     )
   }
@@ -727,14 +790,15 @@ export class Api {
         description,
         stake,
         openingId,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
   }
 
   public approveProposal(account: string, memberId: MemberId, proposal: ProposalId): Promise<ISubmittableResult> {
-    return this.sender.signAndSend(this.api.tx.proposalsEngine.vote(memberId, proposal, 'Approve'), account)
+    return this.sender.signAndSend(this.api.tx.proposalsEngine.vote(memberId, proposal, 'Approve', null), account)
   }
 
   public async batchApproveProposal(proposal: ProposalId): Promise<ISubmittableResult[]> {
@@ -896,7 +960,6 @@ export class Api {
     lead: string,
     openingParameters: {
       activationDelay: BN
-      maxActiveApplicants: BN
       maxReviewPeriodLength: BN
       applicationStakingPolicyAmount: BN
       applicationCrowdedOutUnstakingPeriodLength: BN
@@ -926,9 +989,7 @@ export class Api {
     )
 
     const commitment: OpeningPolicyCommitment = this.api.createType('OpeningPolicyCommitment', {
-      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', {
-        max_active_applicants: openingParameters.maxActiveApplicants as u32,
-      }),
+      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', null),
       max_review_period_length: openingParameters.maxReviewPeriodLength as u32,
       application_staking_policy: this.api.createType('Option<StakingPolicy>', {
         amount: openingParameters.applicationStakingPolicyAmount,
@@ -988,7 +1049,6 @@ export class Api {
   public async sudoAddOpening(
     openingParameters: {
       activationDelay: BN
-      maxActiveApplicants: BN
       maxReviewPeriodLength: BN
       applicationStakingPolicyAmount: BN
       applicationCrowdedOutUnstakingPeriodLength: BN
@@ -1018,9 +1078,7 @@ export class Api {
     )
 
     const commitment: OpeningPolicyCommitment = this.api.createType('OpeningPolicyCommitment', {
-      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', {
-        max_active_applicants: openingParameters.maxActiveApplicants as u32,
-      }),
+      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', null),
       max_review_period_length: openingParameters.maxReviewPeriodLength as u32,
       application_staking_policy: this.api.createType('Option<StakingPolicy>', {
         amount: openingParameters.applicationStakingPolicyAmount,
@@ -1082,7 +1140,6 @@ export class Api {
     description: string
     proposalStake: BN
     actiavteAt: string
-    maxActiveApplicants: BN
     maxReviewPeriodLength: BN
     applicationStakingPolicyAmount: BN
     applicationCrowdedOutUnstakingPeriodLength: BN
@@ -1103,9 +1160,7 @@ export class Api {
     workingGroup: string
   }): Promise<ISubmittableResult> {
     const commitment: OpeningPolicyCommitment = this.api.createType('OpeningPolicyCommitment', {
-      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', {
-        max_active_applicants: leaderOpening.maxActiveApplicants as u32,
-      }),
+      application_rationing_policy: this.api.createType('Option<ApplicationRationingPolicy>', null),
       max_review_period_length: leaderOpening.maxReviewPeriodLength as u32,
       application_staking_policy: this.api.createType('Option<StakingPolicy>', {
         amount: leaderOpening.applicationStakingPolicyAmount,
@@ -1168,7 +1223,8 @@ export class Api {
           commitment: commitment,
           human_readable_text: leaderOpening.text,
           working_group: leaderOpening.workingGroup,
-        }
+        },
+        null
       ),
       leaderOpening.account
     )
@@ -1205,7 +1261,8 @@ export class Api {
         fillOpening.title,
         fillOpening.description,
         fillOpening.proposalStake,
-        fillOpeningParameters
+        fillOpeningParameters,
+        null
       ),
       fillOpening.account
     )
@@ -1233,7 +1290,8 @@ export class Api {
           rationale,
           slash,
           'working_group': workingGroup,
-        }
+        },
+        null
       ),
       account
     )
@@ -1257,7 +1315,8 @@ export class Api {
         proposalStake,
         workerId,
         rewardAmount,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -1281,7 +1340,8 @@ export class Api {
         proposalStake,
         workerId,
         rewardAmount,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -1305,7 +1365,8 @@ export class Api {
         proposalStake,
         workerId,
         rewardAmount,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -1327,7 +1388,8 @@ export class Api {
         description,
         proposalStake,
         mintCapacity,
-        this.api.createType('WorkingGroup', workingGroup)
+        this.api.createType('WorkingGroup', workingGroup),
+        null
       ),
       account
     )
@@ -1454,7 +1516,7 @@ export class Api {
     stake: BN,
     module: WorkingGroups
   ): Promise<ISubmittableResult> {
-    return this.sender.signAndSend(this.api.tx[module].slashStake(workerId, stake), leader)
+    return this.sender.signAndSend(this.api.tx[module].slashStake(workerId, stake, null), leader)
   }
 
   public async updateRoleAccount(
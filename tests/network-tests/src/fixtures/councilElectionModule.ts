@@ -30,15 +30,11 @@ export class ElectCouncilFixture extends BaseFixture {
 
     let now = await this.api.getBestBlock()
     const applyForCouncilFee: BN = this.api.estimateApplyForCouncilFee(this.greaterStake)
-    const voteForCouncilFee: BN = this.api.estimateVoteForCouncilFee(
-      this.applicants[0],
-      this.applicants[0],
-      this.greaterStake
-    )
     const salt: string[] = this.voters.map(() => {
       return ''.concat(uuid().replace(/-/g, ''))
     })
-    const revealVoteFee: BN = this.api.estimateRevealVoteFee(this.applicants[0], salt[0])
+    const voteForCouncilFee: BN = this.api.estimateVoteForCouncilFee(salt[0], this.greaterStake)
+    const revealVoteFee: BN = this.api.estimateRevealVoteFee(salt[0])
 
     // Topping the balances
     this.api.treasuryTransferBalanceToAccounts(this.applicants, applyForCouncilFee.add(this.greaterStake))
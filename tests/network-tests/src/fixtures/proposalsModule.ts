@@ -46,7 +46,6 @@ export class CreateWorkingGroupLeaderOpeningFixture extends BaseFixture {
       description: description,
       proposalStake: proposalStake,
       actiavteAt: 'CurrentBlock',
-      maxActiveApplicants: new BN(10),
       maxReviewPeriodLength: new BN(32),
       applicationStakingPolicyAmount: this.applicationStake,
       applicationCrowdedOutUnstakingPeriodLength: new BN(1),
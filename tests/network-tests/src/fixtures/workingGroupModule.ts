@@ -52,7 +52,6 @@ export class AddWorkerOpeningFixture extends BaseFixture {
       lead.role_account_id.toString(),
       {
         activationDelay: this.activationDelay,
-        maxActiveApplicants: new BN(10),
         maxReviewPeriodLength: new BN(32),
         applicationStakingPolicyAmount: this.applicationStake,
         applicationCrowdedOutUnstakingPeriodLength: new BN(1),
@@ -104,7 +103,6 @@ export class SudoAddLeaderOpeningFixture extends BaseFixture {
     const result = await this.api.sudoAddOpening(
       {
         activationDelay: this.activationDelay,
-        maxActiveApplicants: new BN(10),
         maxReviewPeriodLength: new BN(32),
         applicationStakingPolicyAmount: this.applicationStake,
         applicationCrowdedOutUnstakingPeriodLength: new BN(1),
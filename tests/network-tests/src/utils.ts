@@ -3,8 +3,8 @@ import { compactToU8a, stringToU8a } from '@polkadot/util'
 import { blake2AsHex } from '@polkadot/util-crypto'
 import BN from 'bn.js'
 import fs from 'fs'
-import { decodeAddress } from '@polkadot/keyring'
 import { Seat } from '@joystream/types/council'
+import { MemberId } from '@joystream/types/members'
 
 export class Utils {
   private static LENGTH_ADDRESS = 32 + 1 // publicKey + prefix
@@ -23,13 +23,13 @@ export class Utils {
     )
   }
 
-  /** hash(accountId + salt) */
-  public static hashVote(accountId: string, salt: string): string {
-    const accountU8a = decodeAddress(accountId)
+  /** hash(memberId + salt) */
+  public static hashVote(memberId: MemberId, salt: string): string {
+    const memberIdU8a = memberId.toU8a()
     const saltU8a = stringToU8a(salt)
-    const voteU8a = new Uint8Array(accountU8a.length + saltU8a.length)
-    voteU8a.set(accountU8a)
-    voteU8a.set(saltU8a, accountU8a.length)
+    const voteU8a = new Uint8Array(memberIdU8a.length + saltU8a.length)
+    voteU8a.set(memberIdU8a)
+    voteU8a.set(saltU8a, memberIdU8a.length)
 
     const hash = blake2AsHex(voteU8a, 256)
     return hash
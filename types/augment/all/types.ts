@@ -1,7 +1,7 @@
 // Auto-generated via `yarn polkadot-types-from-defs`, do not edit
 /* eslint-disable */
 
-import type { BTreeMap, BTreeSet, Bytes, Enum, GenericAccountId, Null, Option, Struct, Text, U8aFixed, Vec, bool, u128, u16, u32, u64 } from '@polkadot/types';
+import type { BTreeMap, BTreeSet, Bytes, Enum, GenericAccountId, Null, Option, Struct, Text, U8aFixed, Vec, bool, u128, u16, u32, u64, u8 } from '@polkadot/types';
 import type { ITuple } from '@polkadot/types/types';
 import type { AccountId, Balance, Hash } from '@polkadot/types/interfaces/runtime';
 
@@ -122,6 +122,12 @@ export interface ApplicationRationingPolicy extends Struct {
   readonly max_active_applicants: u32;
 }
 
+/** @name ApplicationScore */
+export interface ApplicationScore extends Struct {
+  readonly score: u32;
+  readonly comment: Bytes;
+}
+
 /** @name ApplicationStage */
 export interface ApplicationStage extends Enum {
   readonly isActive: boolean;
@@ -157,6 +163,24 @@ export interface BlockAndTime extends Struct {
   readonly time: u64;
 }
 
+/** @name Budget */
+export interface Budget extends Struct {
+  readonly spending_ceiling: u128;
+  readonly period_length: u32;
+  readonly period_started_at: u32;
+  readonly minted_before_period: u128;
+  readonly discretionary_spending: u128;
+}
+
+/** @name BudgetOf */
+export interface BudgetOf extends Struct {
+  readonly spending_ceiling: u128;
+  readonly period_length: u32;
+  readonly period_started_at: u32;
+  readonly minted_before_period: u128;
+  readonly discretionary_spending: u128;
+}
+
 /** @name Category */
 export interface Category extends Struct {
   readonly id: CategoryId;
@@ -406,6 +430,7 @@ export interface ElectionParameters extends Struct {
   readonly new_term_duration: u32;
   readonly min_council_stake: u128;
   readonly min_voting_stake: u128;
+  readonly tally_mode: ElectionTallyMode;
 }
 
 /** @name ElectionStage */
@@ -424,6 +449,12 @@ export interface ElectionStake extends Struct {
   readonly transferred: u128;
 }
 
+/** @name ElectionTallyMode */
+export interface ElectionTallyMode extends Enum {
+  readonly isTopStaked: boolean;
+  readonly isSequentialPhragmen: boolean;
+}
+
 /** @name Entity */
 export interface Entity extends Null {}
 
@@ -452,6 +483,8 @@ export interface EntryMethod extends Enum {
   readonly isScreening: boolean;
   readonly asScreening: AccountId;
   readonly isGenesis: boolean;
+  readonly isInvited: boolean;
+  readonly asInvited: MemberId;
 }
 
 /** @name EpisodeParemters */
@@ -467,6 +500,19 @@ export interface ExecutionFailed extends Struct {
   readonly error: Text;
 }
 
+/** @name ExternalIdentityClaim */
+export interface ExternalIdentityClaim extends Struct {
+  readonly value: Bytes;
+  readonly verified_by: Option<GenericAccountId>;
+}
+
+/** @name ExternalIdentityKind */
+export interface ExternalIdentityKind extends Enum {
+  readonly isEmailHash: boolean;
+  readonly isSocialHandle: boolean;
+  readonly asSocialHandle: Bytes;
+}
+
 /** @name FailedAt */
 export interface FailedAt extends Null {}
 
@@ -596,6 +642,13 @@ export interface NextAdjustment extends Struct {
   readonly at_block: u32;
 }
 
+/** @name NoConfidenceParameters */
+export interface NoConfidenceParameters extends Struct {
+  readonly councilor_id: MemberId;
+  readonly slash_stake: bool;
+  readonly start_by_election: bool;
+}
+
 /** @name Nonce */
 export interface Nonce extends Null {}
 
@@ -700,6 +753,34 @@ export interface ParametrizedClassPropertyValue extends Null {}
 /** @name ParametrizedPropertyValue */
 export interface ParametrizedPropertyValue extends Null {}
 
+/** @name PerformanceRating */
+export interface PerformanceRating extends Enum {
+  readonly isPoor: boolean;
+  readonly isBelowExpectations: boolean;
+  readonly isMeetsExpectations: boolean;
+  readonly isAboveExpectations: boolean;
+  readonly isExcellent: boolean;
+}
+
+/** @name PerformanceReview */
+export interface PerformanceReview extends Struct {
+  readonly rating: PerformanceRating;
+  readonly rationale_hash: Hash;
+  readonly reviewed_at: u32;
+  readonly actions: Vec<ReviewAction>;
+}
+
+/** @name PerformanceReviewId */
+export interface PerformanceReviewId extends u64 {}
+
+/** @name PerformanceReviewOf */
+export interface PerformanceReviewOf extends Struct {
+  readonly rating: PerformanceRating;
+  readonly rationale_hash: Hash;
+  readonly reviewed_at: u32;
+  readonly actions: Vec<ReviewAction>;
+}
+
 /** @name Person */
 export interface Person extends Struct {
   readonly controlled_by: PersonController;
@@ -768,6 +849,13 @@ export interface Post extends Struct {
 /** @name PostId */
 export interface PostId extends u64 {}
 
+/** @name PostModeration */
+export interface PostModeration extends Struct {
+  readonly moderator_id: MemberId;
+  readonly hidden_at: u32;
+  readonly rationale: Bytes;
+}
+
 /** @name PostTextChange */
 export interface PostTextChange extends Struct {
   readonly expired_at: BlockAndTime;
@@ -845,6 +933,22 @@ export interface ProposalDetails extends Enum {
   readonly asSetWorkingGroupLeaderReward: ITuple<[WorkerId, Balance, WorkingGroup]>;
   readonly isTerminateWorkingGroupLeaderRole: boolean;
   readonly asTerminateWorkingGroupLeaderRole: TerminateRoleParameters;
+  readonly isSetMembershipInvitationBudget: boolean;
+  readonly asSetMembershipInvitationBudget: u32;
+  readonly isSetReferralCut: boolean;
+  readonly asSetReferralCut: u8;
+  readonly isVoteOfNoConfidence: boolean;
+  readonly asVoteOfNoConfidence: NoConfidenceParameters;
+  readonly isBatch: boolean;
+  readonly asBatch: Vec<ProposalDetails>;
+  readonly isSetGlobalStorageVoucherLimits: boolean;
+  readonly asSetGlobalStorageVoucherLimits: ITuple<[u64, u64]>;
+  readonly isSetStorageVoucherLimitUpperBounds: boolean;
+  readonly asSetStorageVoucherLimitUpperBounds: ITuple<[u64, u64]>;
+  readonly isSetDataObjectTypeStatus: boolean;
+  readonly asSetDataObjectTypeStatus: ITuple<[u64, bool]>;
+  readonly isSetMaxNumberOfCuratorsPerGroup: boolean;
+  readonly asSetMaxNumberOfCuratorsPerGroup: u32;
 }
 
 /** @name ProposalDetailsOf */
@@ -883,6 +987,22 @@ export interface ProposalDetailsOf extends Enum {
   readonly asSetWorkingGroupLeaderReward: ITuple<[WorkerId, Balance, WorkingGroup]>;
   readonly isTerminateWorkingGroupLeaderRole: boolean;
   readonly asTerminateWorkingGroupLeaderRole: TerminateRoleParameters;
+  readonly isSetMembershipInvitationBudget: boolean;
+  readonly asSetMembershipInvitationBudget: u32;
+  readonly isSetReferralCut: boolean;
+  readonly asSetReferralCut: u8;
+  readonly isVoteOfNoConfidence: boolean;
+  readonly asVoteOfNoConfidence: NoConfidenceParameters;
+  readonly isBatch: boolean;
+  readonly asBatch: Vec<ProposalDetails>;
+  readonly isSetGlobalStorageVoucherLimits: boolean;
+  readonly asSetGlobalStorageVoucherLimits: ITuple<[u64, u64]>;
+  readonly isSetStorageVoucherLimitUpperBounds: boolean;
+  readonly asSetStorageVoucherLimitUpperBounds: ITuple<[u64, u64]>;
+  readonly isSetDataObjectTypeStatus: boolean;
+  readonly asSetDataObjectTypeStatus: ITuple<[u64, bool]>;
+  readonly isSetMaxNumberOfCuratorsPerGroup: boolean;
+  readonly asSetMaxNumberOfCuratorsPerGroup: u32;
 }
 
 /** @name ProposalId */
@@ -947,6 +1067,14 @@ export interface Reply extends Struct {
 /** @name ReplyId */
 export interface ReplyId extends u64 {}
 
+/** @name ReviewAction */
+export interface ReviewAction extends Enum {
+  readonly isRewardAmountUpdated: boolean;
+  readonly asRewardAmountUpdated: u128;
+  readonly isStakeSlashed: boolean;
+  readonly asStakeSlashed: u128;
+}
+
 /** @name ReviewPeriod */
 export interface ReviewPeriod extends Struct {
   readonly started_accepting_applicants_at_block: u32;
@@ -1010,7 +1138,7 @@ export interface SealedVote extends Struct {
   readonly voter: GenericAccountId;
   readonly commitment: Hash;
   readonly stake: ElectionStake;
-  readonly vote: Option<GenericAccountId>;
+  readonly vote: Option<Vec<MemberId>>;
 }
 
 /** @name Season */
@@ -1027,7 +1155,8 @@ export interface SeasonParameters extends Struct {
 
 /** @name Seat */
 export interface Seat extends Struct {
-  readonly member: GenericAccountId;
+  readonly member: MemberId;
+  readonly account: GenericAccountId;
   readonly stake: u128;
   readonly backers: Backers;
 }
@@ -1063,6 +1192,82 @@ export interface SideEffect extends Null {}
 /** @name SideEffects */
 export interface SideEffects extends Null {}
 
+/** @name SimpleApplication */
+export interface SimpleApplication extends Struct {
+  readonly role_account_id: GenericAccountId;
+  readonly reward_account_id: GenericAccountId;
+  readonly staking_account_id: GenericAccountId;
+  readonly stake_id: Option<StakeId>;
+  readonly member_id: MemberId;
+  readonly opening_id: SimpleOpeningId;
+  readonly description: Bytes;
+}
+
+/** @name SimpleApplicationId */
+export interface SimpleApplicationId extends u64 {}
+
+/** @name SimpleApplicationIdSet */
+export interface SimpleApplicationIdSet extends BTreeSet<SimpleApplicationId> {}
+
+/** @name SimpleApplicationIdToWorkerIdMap */
+export interface SimpleApplicationIdToWorkerIdMap extends BTreeMap<SimpleApplicationId, WorkerId> {}
+
+/** @name SimpleApplicationOf */
+export interface SimpleApplicationOf extends Struct {
+  readonly role_account_id: GenericAccountId;
+  readonly reward_account_id: GenericAccountId;
+  readonly staking_account_id: GenericAccountId;
+  readonly stake_id: Option<StakeId>;
+  readonly member_id: MemberId;
+  readonly opening_id: SimpleOpeningId;
+  readonly description: Bytes;
+}
+
+/** @name SimpleOpening */
+export interface SimpleOpening extends Struct {
+  readonly opening_type: OpeningType;
+  readonly created: u32;
+  readonly description: Bytes;
+  readonly stake_policy: Option<StakePolicy>;
+  readonly reward_per_block: Option<u128>;
+  readonly applications: BTreeSet<SimpleApplicationId>;
+}
+
+/** @name SimpleOpeningId */
+export interface SimpleOpeningId extends u64 {}
+
+/** @name SimpleOpeningOf */
+export interface SimpleOpeningOf extends Struct {
+  readonly opening_type: OpeningType;
+  readonly created: u32;
+  readonly description: Bytes;
+  readonly stake_policy: Option<StakePolicy>;
+  readonly reward_per_block: Option<u128>;
+  readonly applications: BTreeSet<SimpleApplicationId>;
+}
+
+/** @name SimpleWorkerTerms */
+export interface SimpleWorkerTerms extends Struct {
+  readonly staking_account_id: GenericAccountId;
+  readonly stake_id: Option<StakeId>;
+  readonly unstaking_period: u32;
+  readonly reward_account_id: GenericAccountId;
+  readonly reward_per_block: Option<u128>;
+  readonly last_reward_at: u32;
+  readonly missed_reward: u128;
+}
+
+/** @name SimpleWorkerTermsOf */
+export interface SimpleWorkerTermsOf extends Struct {
+  readonly staking_account_id: GenericAccountId;
+  readonly stake_id: Option<StakeId>;
+  readonly unstaking_period: u32;
+  readonly reward_account_id: GenericAccountId;
+  readonly reward_per_block: Option<u128>;
+  readonly last_reward_at: u32;
+  readonly missed_reward: u128;
+}
+
 /** @name Slash */
 export interface Slash extends Struct {
   readonly started_at_block: u32;
@@ -1084,6 +1289,25 @@ export interface SlashingTerms extends Enum {
   readonly asSlashable: SlashableTerms;
 }
 
+/** @name SpendingPurpose */
+export interface SpendingPurpose extends Enum {
+  readonly isProposal: boolean;
+  readonly asProposal: u32;
+  readonly isText: boolean;
+  readonly asText: Text;
+}
+
+/** @name SpendingRecord */
+export interface SpendingRecord extends Struct {
+  readonly amount: u128;
+  readonly destination: GenericAccountId;
+  readonly spent_at: u32;
+  readonly purpose: SpendingPurpose;
+}
+
+/** @name SpendingRecordId */
+export interface SpendingRecordId extends u64 {}
+
 /** @name Stake */
 export interface Stake extends Struct {
   readonly created: u32;
@@ -1108,6 +1332,12 @@ export interface StakedStatus extends Enum {
 /** @name StakeId */
 export interface StakeId extends u64 {}
 
+/** @name StakePolicy */
+export interface StakePolicy extends Struct {
+  readonly stake_amount: u128;
+  readonly unstaking_period: u32;
+}
+
 /** @name StakingAmountLimitMode */
 export interface StakingAmountLimitMode extends Enum {
   readonly isAtLeast: boolean;
@@ -1190,6 +1420,13 @@ export interface ThreadCounter extends Struct {
 /** @name ThreadId */
 export interface ThreadId extends u64 {}
 
+/** @name ThreadMode */
+export interface ThreadMode extends Enum {
+  readonly isOpen: boolean;
+  readonly isClosed: boolean;
+  readonly asClosed: Vec<MemberId>;
+}
+
 /** @name TransferableStake */
 export interface TransferableStake extends Struct {
   readonly seat: u128;
@@ -1303,6 +1540,9 @@ export interface WaitingToBeingOpeningStageVariant extends Struct {
 /** @name WorkerId */
 export interface WorkerId extends u64 {}
 
+/** @name WorkerIdSet */
+export interface WorkerIdSet extends BTreeSet<WorkerId> {}
+
 /** @name WorkerOf */
 export interface WorkerOf extends Struct {
   readonly member_id: MemberId;
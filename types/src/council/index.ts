@@ -1,12 +1,13 @@
 import { Option } from '@polkadot/types/codec'
-import { Vec } from '@polkadot/types'
+import { Vec, Null, Text } from '@polkadot/types'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
-import { u32, u128 } from '@polkadot/types/primitive'
+import { u32, u64, u128 } from '@polkadot/types/primitive'
 import { RegistryTypes } from '@polkadot/types/types'
 import { JoyStructDecorated } from '../JoyStruct'
 import { JoyEnum } from '../JoyEnum'
 import { GenericAccountId as AccountId } from '@polkadot/types/generic/AccountId'
 import { Hash } from '../common'
+import { MemberId } from '../members'
 
 export type ITransferableStake = {
   seat: Balance
@@ -37,7 +38,8 @@ export class Backer extends JoyStructDecorated({
 
 export class Backers extends Vec.with(Backer) {}
 export class Seat extends JoyStructDecorated({
-  member: AccountId,
+  member: MemberId,
+  account: AccountId,
   stake: u128, // Balance
   backers: Backers,
 }) {}
@@ -48,14 +50,14 @@ export type ISealedVote = {
   voter: AccountId
   commitment: Hash
   stake: ElectionStake
-  vote: Option<AccountId>
+  vote: Option<Vec<MemberId>>
 }
 export class SealedVote
   extends JoyStructDecorated({
     voter: AccountId,
     commitment: Hash,
     stake: ElectionStake,
-    vote: Option.with(AccountId),
+    vote: Option.with(Vec.with(MemberId)),
   })
   implements ISealedVote {}
 
@@ -71,6 +73,11 @@ export class ElectionStage extends JoyEnum({
 
 export type AnyElectionStage = Announcing | Voting | Revealing
 
+export class ElectionTallyMode extends JoyEnum({
+  TopStaked: Null,
+  SequentialPhragmen: Null,
+} as const) {}
+
 export type IElectionParameters = {
   announcing_period: BlockNumber
   voting_period: BlockNumber
@@ -80,6 +87,7 @@ export type IElectionParameters = {
   new_term_duration: BlockNumber
   min_council_stake: Balance
   min_voting_stake: Balance
+  tally_mode: ElectionTallyMode
 }
 
 export class ElectionParameters
@@ -92,19 +100,38 @@ export class ElectionParameters
     new_term_duration: u32, // BlockNumber
     min_council_stake: u128, // Balance
     min_voting_stake: u128, // Balance
+    tally_mode: ElectionTallyMode,
   })
   implements IElectionParameters {}
 
+export class SpendingRecordId extends u64 {}
+
+export class SpendingPurpose extends JoyEnum({
+  Proposal: u32, // ProposalId
+  Text: Text,
+}) {}
+
+export class SpendingRecord extends JoyStructDecorated({
+  amount: u128, // Balance
+  destination: AccountId,
+  spent_at: u32, // BlockNumber
+  purpose: SpendingPurpose,
+}) {}
+
 export const councilTypes: RegistryTypes = {
   ElectionStage,
   ElectionStake,
   SealedVote,
   TransferableStake,
+  ElectionTallyMode,
   ElectionParameters,
   Seat,
   Seats,
   Backer,
   Backers,
+  SpendingRecordId,
+  SpendingPurpose,
+  SpendingRecord,
 }
 
 export default councilTypes
@@ -1,4 +1,4 @@
-import { Option, Null, bool, u32, u64, u128, Text } from '@polkadot/types'
+import { Option, Null, bool, u32, u64, u128, Text, Bytes } from '@polkadot/types'
 import { BlockNumber, Moment } from '@polkadot/types/interfaces'
 import { GenericAccountId as AccountId } from '@polkadot/types/generic/AccountId'
 import { RegistryTypes } from '@polkadot/types/types'
@@ -12,10 +12,12 @@ export class ActorId extends u64 {}
 export class Paid extends PaidTermId {}
 export class Screening extends AccountId {}
 export class Genesis extends Null {}
+export class Invited extends MemberId {}
 export class EntryMethod extends JoyEnum({
   Paid,
   Screening,
   Genesis,
+  Invited,
 }) {}
 
 export type IMembership = {
@@ -50,6 +52,18 @@ export class PaidMembershipTerms extends JoyStructDecorated({
   text: Text,
 }) {}
 
+export class ExternalIdentityKind extends JoyEnum({
+  EmailHash: Null,
+  /// Contains the platform name.
+  SocialHandle: Bytes,
+} as const) {}
+
+export class ExternalIdentityClaim extends JoyStructDecorated({
+  value: Bytes,
+  /// Account of the verifier. None if the claim isn't verified.
+  verified_by: Option.with(AccountId),
+}) {}
+
 export const membersTypes: RegistryTypes = {
   EntryMethod,
   MemberId,
@@ -58,6 +72,8 @@ export const membersTypes: RegistryTypes = {
   Membership,
   PaidMembershipTerms,
   ActorId,
+  ExternalIdentityKind,
+  ExternalIdentityClaim,
 }
 
 export default membersTypes
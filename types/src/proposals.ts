@@ -1,5 +1,5 @@
 import { Text, u32, Tuple, u8, Vec, Option, Null, Bytes } from '@polkadot/types'
-import { bool, u128 } from '@polkadot/types/primitive'
+import { bool, u64, u128 } from '@polkadot/types/primitive'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import { GenericAccountId as AccountId } from '@polkadot/types/generic/AccountId'
 import { ThreadId, WorkingGroup, JoyEnum, JoyStructDecorated } from './common'
@@ -202,6 +202,20 @@ export class DiscussionPost extends JoyStructDecorated({
   edition_number: u32,
 }) {}
 
+export class ThreadMode extends JoyEnum({
+  Open: Null,
+  /// Only councilors, the thread author and the listed members can post.
+  Closed: Vec.with(MemberId),
+} as const) {}
+
+export class PostModeration extends JoyStructDecorated({
+  /// Member who hid the post.
+  moderator_id: MemberId,
+  /// When the post was hidden.
+  hidden_at: u32, // BlockNumber
+  rationale: Bytes,
+}) {}
+
 export type IAddOpeningParameters = {
   activate_at: ActivateOpeningAt
   commitment: OpeningPolicyCommitment
@@ -250,6 +264,20 @@ export class TerminateRoleParameters
   })
   implements ITerminateRoleParameters {}
 
+export type INoConfidenceParameters = {
+  councilor_id: MemberId
+  slash_stake: bool
+  start_by_election: bool
+}
+
+export class NoConfidenceParameters
+  extends JoyStructDecorated({
+    councilor_id: MemberId,
+    slash_stake: bool,
+    start_by_election: bool,
+  })
+  implements INoConfidenceParameters {}
+
 export class ProposalDetails extends JoyEnum({
   Text: Text,
   RuntimeUpgrade: Bytes,
@@ -268,6 +296,14 @@ export class ProposalDetails extends JoyEnum({
   SlashWorkingGroupLeaderStake: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   SetWorkingGroupLeaderReward: Tuple.with([WorkerId, 'Balance', WorkingGroup]),
   TerminateWorkingGroupLeaderRole: TerminateRoleParameters,
+  SetMembershipInvitationBudget: u32,
+  SetReferralCut: u8,
+  VoteOfNoConfidence: NoConfidenceParameters,
+  Batch: 'Vec<ProposalDetails>',
+  SetGlobalStorageVoucherLimits: Tuple.with([u64, u64]),
+  SetStorageVoucherLimitUpperBounds: Tuple.with([u64, u64]),
+  SetDataObjectTypeStatus: Tuple.with([u64, bool]),
+  SetMaxNumberOfCuratorsPerGroup: u32,
 } as const) {}
 
 // export default proposalTypes;
@@ -283,9 +319,12 @@ export const proposalsTypes = {
   ThreadCounter,
   DiscussionThread,
   DiscussionPost,
+  ThreadMode,
+  PostModeration,
   AddOpeningParameters,
   FillOpeningParameters,
   TerminateRoleParameters,
+  NoConfidenceParameters,
   // Expose in registry for api.createType purposes:
   ActiveStake,
   Finalized,
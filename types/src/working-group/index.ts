@@ -1,12 +1,12 @@
-import { Bytes, BTreeMap, BTreeSet, Option } from '@polkadot/types'
-import { Null, u32, u128 } from '@polkadot/types/primitive'
+import { Bytes, BTreeMap, BTreeSet, Option, Vec } from '@polkadot/types'
+import { Null, u32, u64, u128 } from '@polkadot/types/primitive'
 import { GenericAccountId as AccountId } from '@polkadot/types/generic/AccountId'
 import { BlockNumber, Balance } from '@polkadot/types/interfaces'
 import { MemberId, ActorId } from '../members'
 import { RewardRelationshipId } from '../recurring-rewards'
 import { StakeId } from '../stake'
 import { ApplicationId, OpeningId, ApplicationRationingPolicy, StakingPolicy } from '../hiring'
-import { JoyEnum, JoyStructDecorated, SlashingTerms, JoyBTreeSet } from '../common'
+import { JoyEnum, JoyStructDecorated, SlashingTerms, JoyBTreeSet, Hash } from '../common'
 import { RegistryTypes } from '@polkadot/types/types'
 
 export class RationaleText extends Bytes {}
@@ -148,6 +148,154 @@ export class RewardPolicy
   })
   implements IRewardPolicy {}
 
+export class PerformanceReviewId extends u64 {}
+
+export class PerformanceRating extends JoyEnum({
+  Poor: Null,
+  BelowExpectations: Null,
+  MeetsExpectations: Null,
+  AboveExpectations: Null,
+  Excellent: Null,
+} as const) {}
+
+export class ReviewAction extends JoyEnum({
+  RewardAmountUpdated: u128, // MintBalance
+  StakeSlashed: u128, // Balance
+} as const) {}
+
+export type IPerformanceReview = {
+  rating: PerformanceRating
+  rationale_hash: Hash
+  reviewed_at: BlockNumber
+  actions: Vec<ReviewAction>
+}
+
+export class PerformanceReview
+  extends JoyStructDecorated({
+    rating: PerformanceRating,
+    rationale_hash: Hash,
+    reviewed_at: u32, // BlockNumber
+    actions: Vec.with(ReviewAction),
+  })
+  implements IPerformanceReview {}
+
+export type IApplicationScore = {
+  score: u32
+  comment: Bytes
+}
+
+export class ApplicationScore
+  extends JoyStructDecorated({
+    score: u32,
+    comment: Bytes,
+  })
+  implements IApplicationScore {}
+
+// Set of the workers allowed to score the applications on the opening
+export class WorkerIdSet extends JoyBTreeSet(WorkerId) {}
+
+export class SimpleOpeningId extends u64 {}
+
+export class SimpleApplicationId extends u64 {}
+
+export class SimpleApplicationIdSet extends JoyBTreeSet(SimpleApplicationId) {}
+
+export class SimpleApplicationIdToWorkerIdMap extends BTreeMap.with(SimpleApplicationId, WorkerId) {}
+
+export type IStakePolicy = {
+  stake_amount: Balance
+  unstaking_period: BlockNumber
+}
+
+export class StakePolicy
+  extends JoyStructDecorated({
+    stake_amount: u128, // Balance
+    unstaking_period: u32, // BlockNumber
+  })
+  implements IStakePolicy {}
+
+export type ISimpleOpening = {
+  opening_type: OpeningType
+  created: BlockNumber
+  description: Bytes
+  stake_policy: Option<StakePolicy>
+  reward_per_block: Option<Balance>
+  applications: BTreeSet<SimpleApplicationId>
+}
+
+export class SimpleOpening
+  extends JoyStructDecorated({
+    opening_type: OpeningType,
+    created: u32, // BlockNumber
+    description: Bytes,
+    stake_policy: Option.with(StakePolicy),
+    reward_per_block: Option.with(u128), // Option<MintBalance>
+    applications: JoyBTreeSet(SimpleApplicationId),
+  })
+  implements ISimpleOpening {}
+
+export type ISimpleApplication = {
+  role_account_id: AccountId
+  reward_account_id: AccountId
+  staking_account_id: AccountId
+  stake_id: Option<StakeId>
+  member_id: MemberId
+  opening_id: SimpleOpeningId
+  description: Bytes
+}
+
+export class SimpleApplication
+  extends JoyStructDecorated({
+    role_account_id: AccountId,
+    reward_account_id: AccountId,
+    staking_account_id: AccountId,
+    stake_id: Option.with(StakeId),
+    member_id: MemberId,
+    opening_id: SimpleOpeningId,
+    description: Bytes,
+  })
+  implements ISimpleApplication {}
+
+export type ISimpleWorkerTerms = {
+  staking_account_id: AccountId
+  stake_id: Option<StakeId>
+  unstaking_period: BlockNumber
+  reward_account_id: AccountId
+  reward_per_block: Option<Balance>
+  last_reward_at: BlockNumber
+  missed_reward: Balance
+}
+
+export class SimpleWorkerTerms
+  extends JoyStructDecorated({
+    staking_account_id: AccountId,
+    stake_id: Option.with(StakeId),
+    unstaking_period: u32, // BlockNumber
+    reward_account_id: AccountId,
+    reward_per_block: Option.with(u128), // Option<MintBalance>
+    last_reward_at: u32, // BlockNumber
+    missed_reward: u128, // MintBalance
+  })
+  implements ISimpleWorkerTerms {}
+
+export type IBudget = {
+  spending_ceiling: Balance
+  period_length: BlockNumber
+  period_started_at: BlockNumber
+  minted_before_period: Balance
+  discretionary_spending: Balance
+}
+
+export class Budget
+  extends JoyStructDecorated({
+    spending_ceiling: u128, // MintBalance
+    period_length: u32, // BlockNumber
+    period_started_at: u32, // BlockNumber
+    minted_before_period: u128, // MintBalance
+    discretionary_spending: u128, // MintBalance
+  })
+  implements IBudget {}
+
 // Needed for types augment tool
 export { OpeningId, ApplicationId }
 
@@ -167,6 +315,29 @@ export const workingGroupTypes: RegistryTypes = {
   // Expose in registry for api.createType purposes:
   OpeningPolicyCommitment,
   RoleStakeProfile,
+  Budget,
+  /// Alias used by the runtime working-group module
+  BudgetOf: Budget,
+  PerformanceReviewId,
+  PerformanceRating,
+  ReviewAction,
+  PerformanceReview,
+  /// Alias used by the runtime working-group module
+  PerformanceReviewOf: PerformanceReview,
+  ApplicationScore,
+  WorkerIdSet,
+  SimpleOpeningId,
+  SimpleApplicationId,
+  SimpleApplicationIdSet,
+  SimpleApplicationIdToWorkerIdMap,
+  StakePolicy,
+  SimpleOpening,
+  SimpleApplication,
+  SimpleWorkerTerms,
+  /// Aliases used by the runtime working-group module
+  SimpleOpeningOf: SimpleOpening,
+  SimpleApplicationOf: SimpleApplication,
+  SimpleWorkerTermsOf: SimpleWorkerTerms,
 }
 
 export default workingGroupTypes